@@ -0,0 +1,126 @@
+//! Parsing and formatting of hex color strings at runtime.
+//!
+//! For compile-time parsing, see the `hex_color!` macro (behind the `color-hex` feature).
+
+use crate::Color32;
+
+/// Error returned by [`Color32::from_hex`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseHexColorError {
+    /// The string did not have 3, 4, 6 or 8 hex digits.
+    InvalidLength,
+
+    /// The string contained a character that is not a hex digit.
+    InvalidDigit,
+}
+
+impl std::fmt::Display for ParseHexColorError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidLength => write!(f, "expected 3, 4, 6 or 8 hex digits"),
+            Self::InvalidDigit => write!(f, "invalid hex digit"),
+        }
+    }
+}
+
+impl std::error::Error for ParseHexColorError {}
+
+impl Color32 {
+    /// Parse a color from a hex string, with an optional leading `#`.
+    ///
+    /// Supports `RGB`, `RGBA`, `RRGGBB` and `RRGGBBAA`.
+    /// The alpha is interpreted as unmultiplied (like in CSS).
+    ///
+    /// ```
+    /// # use ecolor::Color32;
+    /// assert_eq!(Color32::from_hex("#202122"), Ok(Color32::from_rgb(0x20, 0x21, 0x22)));
+    /// assert_eq!(Color32::from_hex("fff"), Ok(Color32::WHITE));
+    /// assert!(Color32::from_hex("#12345").is_err());
+    /// ```
+    pub fn from_hex(hex: &str) -> Result<Self, ParseHexColorError> {
+        let hex = hex.trim();
+        let hex = hex.strip_prefix('#').unwrap_or(hex);
+
+        if !hex.is_ascii() {
+            return Err(ParseHexColorError::InvalidDigit);
+        }
+
+        let digit = |c: u8| -> Result<u8, ParseHexColorError> {
+            (c as char)
+                .to_digit(16)
+                .map(|d| d as u8)
+                .ok_or(ParseHexColorError::InvalidDigit)
+        };
+        let bytes = hex.as_bytes();
+
+        let mut rgba = [0_u8, 0, 0, 255];
+        match bytes.len() {
+            3 | 4 => {
+                for (i, &c) in bytes.iter().enumerate() {
+                    let d = digit(c)?;
+                    rgba[i] = d << 4 | d;
+                }
+            }
+            6 | 8 => {
+                for (i, pair) in bytes.chunks(2).enumerate() {
+                    rgba[i] = digit(pair[0])? << 4 | digit(pair[1])?;
+                }
+            }
+            _ => return Err(ParseHexColorError::InvalidLength),
+        }
+
+        let [r, g, b, a] = rgba;
+        Ok(Self::from_rgba_unmultiplied(r, g, b, a))
+    }
+
+    /// Format the color as a hex string, e.g. `#RRGGBB`, or `#RRGGBBAA` if not opaque.
+    ///
+    /// The alpha is unmultiplied (like in CSS).
+    ///
+    /// ```
+    /// # use ecolor::Color32;
+    /// assert_eq!(Color32::from_rgb(0x20, 0x21, 0x22).to_hex(), "#202122");
+    /// assert_eq!(Color32::TRANSPARENT.to_hex(), "#00000000");
+    /// ```
+    pub fn to_hex(&self) -> String {
+        let [r, g, b, a] = self.to_srgba_unmultiplied();
+        if a == 255 {
+            format!("#{r:02x}{g:02x}{b:02x}")
+        } else {
+            format!("#{r:02x}{g:02x}{b:02x}{a:02x}")
+        }
+    }
+}
+
+#[test]
+fn test_hex_round_trip() {
+    for color in [
+        Color32::BLACK,
+        Color32::WHITE,
+        Color32::from_rgb(0x12, 0x34, 0x56),
+        Color32::from_rgba_unmultiplied(0xab, 0xcd, 0xef, 0x80),
+    ] {
+        let hex = color.to_hex();
+        let parsed = Color32::from_hex(&hex).unwrap();
+        // Unmultiplied alpha can lose a little precision:
+        for i in 0..4 {
+            assert!((parsed[i] as i32 - color[i] as i32).abs() <= 1, "{hex}");
+        }
+    }
+}
+
+#[test]
+fn test_from_hex_errors() {
+    assert_eq!(
+        Color32::from_hex("#12"),
+        Err(ParseHexColorError::InvalidLength)
+    );
+    assert_eq!(
+        Color32::from_hex("#12345g"),
+        Err(ParseHexColorError::InvalidDigit)
+    );
+    assert_eq!(
+        Color32::from_hex("#ff00ff80"),
+        Ok(Color32::from_rgba_unmultiplied(255, 0, 255, 0x80))
+    );
+}
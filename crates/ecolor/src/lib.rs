@@ -8,6 +8,7 @@
 #![cfg_attr(feature = "document-features", doc = document_features::document_features!())]
 //!
 
+#![allow(clippy::manual_range_contains)]
 #![allow(clippy::wrong_self_convention)]
 
 #[cfg(feature = "cint")]
@@ -27,6 +28,9 @@ pub use hsva::*;
 #[cfg(feature = "color-hex")]
 mod hex_color_macro;
 
+mod hex_color_runtime;
+pub use hex_color_runtime::*;
+
 mod rgba;
 pub use rgba::*;
 
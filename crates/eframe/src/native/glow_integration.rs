@@ -557,8 +557,11 @@ impl GlowWinitRunning {
             platform_output,
             textures_delta,
             shapes,
+            shape_layers: _,
+            blur_behind: _,
             pixels_per_point,
             viewport_output,
+            unchanged: _,
         } = full_output;
 
         let GlutinWindowContext {
@@ -1319,8 +1322,11 @@ fn render_immediate_viewport(
         platform_output,
         textures_delta,
         shapes,
+        shape_layers: _,
+        blur_behind: _,
         pixels_per_point,
         viewport_output,
+        unchanged: _,
     } = egui_ctx.run(input, |ctx| {
         viewport_ui_cb(ctx);
     });
@@ -583,8 +583,11 @@ impl WgpuWinitRunning {
             platform_output,
             textures_delta,
             shapes,
+            shape_layers: _,
+            blur_behind: _,
             pixels_per_point,
             viewport_output,
+            unchanged: _,
         } = full_output;
 
         egui_winit.handle_platform_output(window, egui_ctx, platform_output);
@@ -908,8 +911,11 @@ fn render_immediate_viewport(
         platform_output,
         textures_delta,
         shapes,
+        shape_layers: _,
+        blur_behind: _,
         pixels_per_point,
         viewport_output,
+        unchanged: _,
     } = egui_ctx.run(input, |ctx| {
         viewport_ui_cb(ctx);
     });
@@ -206,8 +206,11 @@ impl AppRunner {
             platform_output,
             textures_delta,
             shapes,
+            shape_layers: _,
+            blur_behind: _,
             pixels_per_point,
             viewport_output,
+            unchanged: _,
         } = full_output;
 
         if viewport_output.len() > 1 {
@@ -259,6 +262,9 @@ impl AppRunner {
             events: _, // already handled
             mutable_text_under_cursor,
             text_cursor_pos,
+            is_pointer_over_area: _, // for the host application
+            wants_pointer_input: _,  // for the host application
+            wants_keyboard_input: _, // for the host application
             #[cfg(feature = "accesskit")]
                 accesskit_update: _, // not currently implemented
         } = platform_output;
@@ -710,6 +710,9 @@ impl State {
             events: _,                    // handled elsewhere
             mutable_text_under_cursor: _, // only used in eframe web
             text_cursor_pos,
+            is_pointer_over_area: _, // for the host application
+            wants_pointer_input: _,  // for the host application
+            wants_keyboard_input: _, // for the host application
             #[cfg(feature = "accesskit")]
             accesskit_update,
         } = platform_output;
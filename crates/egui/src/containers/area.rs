@@ -71,6 +71,9 @@ pub struct Area {
     pivot: Align2,
     anchor: Option<(Align2, Vec2)>,
     new_pos: Option<Pos2>,
+    canvas: bool,
+    parent: Option<(LayerId, Rect)>,
+    popup_parent: Option<LayerId>,
 }
 
 impl Area {
@@ -87,6 +90,9 @@ impl Area {
             new_pos: None,
             pivot: Align2::LEFT_TOP,
             anchor: None,
+            canvas: false,
+            parent: None,
+            popup_parent: None,
         }
     }
 
@@ -128,6 +134,10 @@ impl Area {
 
     /// If false, clicks goes straight through to what is behind us.
     /// Good for tooltips etc.
+    ///
+    /// A non-interactable area doesn't count for [`Context::hit_test`] and [`Context::wants_pointer_input`],
+    /// so it can be used for decorations that shouldn't block the input to the host application.
+    /// See also [`Context::add_passthrough_rect`].
     #[inline]
     pub fn interactable(mut self, interactable: bool) -> Self {
         self.interactable = interactable;
@@ -135,6 +145,45 @@ impl Area {
         self
     }
 
+    /// Make this area a background canvas.
+    ///
+    /// A canvas is always kept below the other areas in the same [`Order`],
+    /// and is not brought to the front when clicked.
+    /// It senses all clicks and drags that are not claimed by any widget in it,
+    /// nor by any area above it, and reports them in the [`Response`] of [`Self::show`].
+    ///
+    /// This is useful for e.g. node graphs or maps, where dragging the background should pan the view,
+    /// while windows float on top.
+    ///
+    /// A canvas is never movable.
+    /// Keep the default [`Order::Middle`], so that the canvas is still above any panels.
+    ///
+    /// ```
+    /// # egui::__run_test_ctx(|ctx| {
+    /// # let mut pan = egui::Vec2::ZERO;
+    /// let response = egui::Area::new("canvas")
+    ///     .canvas(true)
+    ///     .fixed_pos(ctx.available_rect().min)
+    ///     .show(ctx, |ui| {
+    ///         ui.set_min_size(ui.available_size());
+    ///         ui.label("Drag the background to pan");
+    ///     })
+    ///     .response;
+    /// if response.dragged() {
+    ///     pan += response.drag_delta();
+    /// }
+    /// # });
+    /// ```
+    #[inline]
+    pub fn canvas(mut self, canvas: bool) -> Self {
+        self.canvas = canvas;
+        if canvas {
+            self.movable = false;
+            self.interactable = true;
+        }
+        self
+    }
+
     /// `order(Order::Foreground)` for an Area that should always be on top
     #[inline]
     pub fn order(mut self, order: Order) -> Self {
@@ -157,6 +206,13 @@ impl Area {
     }
 
     /// Constrains this area to the screen bounds.
+    ///
+    /// The area is clamped every frame, so it is kept on screen both after being dragged
+    /// and after the screen is resized. It can thus never be lost off-screen.
+    ///
+    /// To change the area to constrain to, use [`Self::constrain_to`].
+    ///
+    /// Default: `false` (but `true` for [`Window`]s).
     #[inline]
     pub fn constrain(mut self, constrain: bool) -> Self {
         self.constrain = constrain;
@@ -175,9 +231,58 @@ impl Area {
 
     #[deprecated = "Use `constrain_to` instead"]
     #[inline]
-    pub fn drag_bounds(mut self, constrain_rect: Rect) -> Self {
-        self.constrain_rect = Some(constrain_rect);
-        self
+    pub fn drag_bounds(self, constrain_rect: Rect) -> Self {
+        self.constrain_to(constrain_rect)
+    }
+
+    /// Make this area a child of the given [`Ui`], e.g. of another window or a panel.
+    ///
+    /// The area will then:
+    /// * move along with the parent [`Ui`]
+    /// * be constrained to, and clipped by, the parent [`Ui`] (see [`Self::constrain_to`])
+    /// * always be shown above the parent layer, in the same [`Order`]
+    /// * bring the parent layer to the top when it is brought to the top itself
+    ///
+    /// Call this every frame, from within the parent [`Ui`].
+    /// This is useful for MDI-style applications, where documents are windows within a window.
+    ///
+    /// Unless you set a [`Self::default_pos`], the area starts out in the top left corner of the parent.
+    #[inline]
+    pub fn child_of(mut self, parent: &Ui) -> Self {
+        let parent_rect = parent.max_rect().intersect(parent.clip_rect());
+        self.parent = Some((parent.layer_id(), parent_rect));
+        self.order = parent.layer_id().order;
+        self.constrain_to(parent_rect)
+    }
+
+    /// Make this area a popup of the given [`Ui`], e.g. a drop-down list.
+    ///
+    /// The area will then:
+    /// * be shown on the [`Order::Foreground`] layer, above all windows
+    /// * always be shown above the layer of the parent [`Ui`], even if that is another popup
+    /// * be constrained to the screen, so it is never clipped by the parent, e.g. by a [`ScrollArea`]
+    ///
+    /// Like any [`Area`], it does not affect the layout of the parent [`Ui`].
+    /// Call this every frame the popup is shown.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let response = ui.button("Open");
+    /// egui::Area::new("my_popup")
+    ///     .popup_of(ui)
+    ///     .fixed_pos(response.rect.left_bottom())
+    ///     .show(ui.ctx(), |ui| {
+    ///         egui::Frame::popup(ui.style()).show(ui, |ui| {
+    ///             ui.label("Never cut off by the parent");
+    ///         });
+    ///     });
+    /// # });
+    /// ```
+    #[inline]
+    pub fn popup_of(mut self, parent: &Ui) -> Self {
+        self.popup_parent = Some(parent.layer_id());
+        self.order = Order::Foreground;
+        self.constrain_to(parent.ctx().screen_rect())
     }
 
     /// Where the "root" of the area is.
@@ -186,6 +291,22 @@ impl Area {
     /// then [`Self::fixed_pos`] will set the position of the right-top
     /// corner of the area.
     ///
+    /// This lets you position an area by its center or right edge,
+    /// e.g. a tooltip centered above the mouse cursor:
+    ///
+    /// ```
+    /// # egui::__run_test_ctx(|ctx| {
+    /// if let Some(pointer_pos) = ctx.pointer_hover_pos() {
+    ///     egui::Area::new("centered_above_pointer")
+    ///         .pivot(egui::Align2::CENTER_BOTTOM)
+    ///         .fixed_pos(pointer_pos - egui::vec2(0.0, 8.0))
+    ///         .show(ctx, |ui| {
+    ///             ui.label("Centered above the cursor");
+    ///         });
+    /// }
+    /// # });
+    /// ```
+    ///
     /// Default: [`Align2::LEFT_TOP`].
     #[inline]
     pub fn pivot(mut self, pivot: Align2) -> Self {
@@ -194,6 +315,8 @@ impl Area {
     }
 
     /// Positions the window but you can still move it.
+    ///
+    /// The position is that of the [`Self::pivot`].
     #[inline]
     pub fn current_pos(mut self, current_pos: impl Into<Pos2>) -> Self {
         self.new_pos = Some(current_pos.into());
@@ -221,7 +344,7 @@ impl Area {
         if let Some((pivot, _)) = self.anchor {
             pivot
         } else {
-            Align2::LEFT_TOP
+            self.pivot
         }
     }
 }
@@ -268,10 +391,15 @@ impl Area {
             anchor,
             constrain,
             constrain_rect,
+            canvas,
+            parent,
+            popup_parent,
         } = self;
 
         let layer_id = LayerId::new(order, id);
 
+        let default_pos = default_pos.or(parent.map(|(_, parent_rect)| parent_rect.min));
+
         let state = ctx.memory(|mem| mem.areas().get(id).copied());
         let is_new = state.is_none();
         if is_new {
@@ -283,9 +411,34 @@ impl Area {
             size: Vec2::ZERO,
             interactable,
         });
+        if state.pivot != pivot {
+            // Keep the area where it is, and only change what position refers to:
+            let left_top = state.left_top_pos();
+            state.pivot = pivot;
+            state.set_left_top_pos(left_top);
+        }
         state.pivot_pos = new_pos.unwrap_or(state.pivot_pos);
         state.interactable = interactable;
 
+        if let Some((parent_layer_id, parent_rect)) = parent {
+            // Move along with the parent:
+            let last_parent_pos = ctx.data_mut(|data| {
+                let parent_pos = data.get_temp_mut_or(id.with("parent_pos"), parent_rect.min);
+                std::mem::replace(parent_pos, parent_rect.min)
+            });
+            if new_pos.is_none() {
+                state.pivot_pos += parent_rect.min - last_parent_pos;
+            }
+            ctx.memory_mut(|mem| mem.areas_mut().set_parent(layer_id, parent_layer_id));
+        }
+
+        if let Some(popup_parent) = popup_parent {
+            // Children can't leave their own `Order`, so only popups of popups need this:
+            if popup_parent.order == order {
+                ctx.memory_mut(|mem| mem.areas_mut().set_parent(layer_id, popup_parent));
+            }
+        }
+
         if let Some((anchor, offset)) = anchor {
             let screen = ctx.available_rect();
             state.set_left_top_pos(
@@ -296,7 +449,7 @@ impl Area {
         // interact right away to prevent frame-delay
         let mut move_response = {
             let interact_id = layer_id.id.with("move");
-            let sense = if movable {
+            let sense = if movable || canvas {
                 Sense::click_and_drag()
             } else if interactable {
                 Sense::click() // allow clicks to bring to front
@@ -306,19 +459,22 @@ impl Area {
 
             let move_response = ctx.interact(
                 Rect::EVERYTHING,
-                ctx.style().spacing.item_spacing,
+                &ctx.style().spacing,
                 layer_id,
                 interact_id,
                 state.rect(),
                 sense,
                 enabled,
+                0,
             );
 
             if movable && move_response.dragged() {
                 state.pivot_pos += ctx.input(|i| i.pointer.delta());
             }
 
-            if (move_response.dragged() || move_response.clicked())
+            if canvas {
+                ctx.memory_mut(|m| m.areas_mut().move_to_bottom(layer_id));
+            } else if (move_response.dragged() || move_response.clicked())
                 || pointer_pressed_on_area(ctx, layer_id)
                 || !ctx.memory(|m| m.areas().visible_last_frame(&layer_id))
             {
@@ -336,6 +492,13 @@ impl Area {
             );
         }
 
+        if let Some((_, parent_rect)) = parent {
+            // Unlike other areas, a child may not spill out of its parent on any side.
+            // If it is too large, it is kept in the left top corner, and clipped:
+            let max_pos = (parent_rect.max - state.size).max(parent_rect.min);
+            state.set_left_top_pos(state.left_top_pos().clamp(parent_rect.min, max_pos));
+        }
+
         state.set_left_top_pos(ctx.round_pos_to_pixels(state.left_top_pos()));
 
         // Update responsbe with posisbly moved/constrained rect:
@@ -376,7 +539,10 @@ impl Area {
             //     Rect::from_center_size(area_rect.center(), visibility_factor * area_rect.size());
 
             let frame = frame.multiply_with_opacity(visibility_factor);
-            painter.add(frame.paint(area_rect));
+            painter.add(frame.paint_with_circle_detail(area_rect, ctx.shadow_circle_detail()));
+            if frame.blur_behind {
+                ctx.add_blur_behind(layer_id, area_rect, frame.rounding);
+            }
         }
     }
 }
@@ -436,7 +602,9 @@ impl Prepared {
             clip_rect,
         );
         ui.set_enabled(self.enabled);
-        ui.set_visible(!self.temporarily_invisible);
+        if self.temporarily_invisible {
+            ui.set_sizing_pass();
+        }
         ui
     }
 
@@ -351,9 +351,10 @@ pub type IconPainter = Box<dyn FnOnce(&mut Ui, f32, &Response)>;
 /// # });
 /// ```
 ///
-/// If you want to customize the header contents, see [`CollapsingState::show_header`].
+/// To add your own widgets to the header, see [`Self::header_ui`].
+/// If you want to customize the header contents even more, see [`CollapsingState::show_header`].
 #[must_use = "You should call .show()"]
-pub struct CollapsingHeader {
+pub struct CollapsingHeader<'a> {
     text: WidgetText,
     default_open: bool,
     open: Option<bool>,
@@ -363,9 +364,10 @@ pub struct CollapsingHeader {
     selected: bool,
     show_background: bool,
     icon: Option<IconPainter>,
+    header_ui: Option<Box<dyn FnOnce(&mut Ui) + 'a>>,
 }
 
-impl CollapsingHeader {
+impl<'a> CollapsingHeader<'a> {
     /// The [`CollapsingHeader`] starts out collapsed unless you call `default_open`.
     ///
     /// The label is used as an [`Id`] source.
@@ -385,6 +387,7 @@ impl CollapsingHeader {
             selected: false,
             show_background: false,
             icon: None,
+            header_ui: None,
         }
     }
 
@@ -490,6 +493,36 @@ impl CollapsingHeader {
         self.icon = Some(Box::new(icon_fn));
         self
     }
+
+    /// Add your own widgets to the header row, after the text.
+    ///
+    /// This can be used for status icons, a count badge, or buttons.
+    /// The header then fills the available width,
+    /// so you can use a right-to-left layout to put things on the right side.
+    /// Clicking your widgets will not open or close the [`CollapsingHeader`].
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let mut items = vec!["apple", "banana"];
+    /// egui::CollapsingHeader::new("Fruits")
+    ///     .header_ui(|ui| {
+    ///         ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+    ///             if ui.small_button("➕").clicked() {
+    ///                 items.push("cherry");
+    ///             }
+    ///             ui.weak(format!("({})", items.len()));
+    ///         });
+    ///     })
+    ///     .show(ui, |ui| {
+    ///         ui.label("…");
+    ///     });
+    /// # });
+    /// ```
+    #[inline]
+    pub fn header_ui(mut self, header_ui: impl FnOnce(&mut Ui) + 'a) -> Self {
+        self.header_ui = Some(Box::new(header_ui));
+        self
+    }
 }
 
 struct Prepared {
@@ -498,7 +531,7 @@ struct Prepared {
     openness: f32,
 }
 
-impl CollapsingHeader {
+impl<'a> CollapsingHeader<'a> {
     fn begin(self, ui: &mut Ui) -> Prepared {
         assert!(
             ui.layout().main_dir().is_vertical(),
@@ -514,11 +547,13 @@ impl CollapsingHeader {
             selectable,
             selected,
             show_background,
+            header_ui,
         } = self;
 
         // TODO(emilk): horizontal layout, with icon and text as labels. Insert background behind using Frame.
 
         let id = ui.make_persistent_id(id_source);
+        ui.ctx().set_id_name(id, text.text());
         let button_padding = ui.spacing().button_padding;
 
         let available = ui.available_rect_before_wrap();
@@ -529,7 +564,7 @@ impl CollapsingHeader {
         let text_max_x = text_pos.x + text.size().x;
 
         let mut desired_width = text_max_x + button_padding.x - available.left();
-        if ui.visuals().collapsing_header_frame {
+        if ui.visuals().collapsing_header_frame || header_ui.is_some() {
             desired_width = desired_width.max(available.width()); // fill full width
         }
 
@@ -596,6 +631,19 @@ impl CollapsingHeader {
             text.paint_with_visuals(ui.painter(), text_pos, &visuals);
         }
 
+        if let Some(header_ui) = header_ui {
+            // Added after the header, so these widgets get the clicks:
+            let header_ui_rect = Rect::from_min_max(
+                pos2(
+                    (text_max_x + ui.spacing().item_spacing.x).at_most(rect.right()),
+                    rect.top(),
+                ),
+                rect.right_bottom(),
+            );
+            let mut child_ui = ui.child_ui(header_ui_rect, Layout::left_to_right(Align::Center));
+            header_ui(&mut child_ui);
+        }
+
         Prepared {
             header_response,
             state,
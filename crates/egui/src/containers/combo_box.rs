@@ -234,6 +234,16 @@ impl ComboBox {
     }
 }
 
+/// Keyboard navigation state of an open [`ComboBox`] popup.
+#[derive(Clone, Copy, Default)]
+struct ComboBoxKeyboardState {
+    /// The popup was opened with the keyboard, so give the focus to the first item.
+    focus_first_item: bool,
+
+    /// Does the popup contain the focused widget?
+    has_focus: bool,
+}
+
 fn combo_box_dyn<'c, R>(
     ui: &mut Ui,
     button_id: Id,
@@ -331,22 +341,64 @@ fn combo_box_dyn<'c, R>(
         }
     });
 
+    let mut keyboard_state: ComboBoxKeyboardState =
+        ui.data(|d| d.get_temp(popup_id)).unwrap_or_default();
+
     if button_response.clicked() {
         ui.memory_mut(|mem| mem.toggle_popup(popup_id));
+        keyboard_state.focus_first_item = button_response.has_focus() && !is_popup_open;
+    } else if button_response.has_focus()
+        && !is_popup_open
+        && ui.input(|i| i.key_pressed(Key::ArrowDown))
+    {
+        ui.memory_mut(|mem| {
+            mem.open_popup(popup_id);
+            mem.stop_focus_navigation();
+        });
+        keyboard_state.focus_first_item = true;
     }
+
+    let had_focus = keyboard_state.has_focus;
     let inner = crate::popup::popup_above_or_below_widget(
         ui,
         popup_id,
         &button_response,
         above_or_below,
         |ui| {
-            ScrollArea::vertical()
+            let layer_id = ui.layer_id();
+            ui.memory_mut(|mem| mem.trap_focus(layer_id));
+            let keyboard_nav = crate::menu::MenuKeyboardNav::begin(
+                ui,
+                keyboard_state.has_focus,
+                &mut keyboard_state.focus_first_item,
+            );
+            let inner = ScrollArea::vertical()
                 .max_height(ui.spacing().combo_height)
                 .show(ui, menu_contents)
-                .inner
+                .inner;
+            keyboard_state.has_focus = keyboard_nav.end(ui, ui.min_rect());
+            inner
         },
     );
 
+    if had_focus || keyboard_state.has_focus {
+        if ui.input(|i| i.key_pressed(Key::Enter) || i.key_pressed(Key::Space)) {
+            // An item was selected with the keyboard
+            ui.memory_mut(|mem| mem.close_popup());
+        }
+        if !ui.memory(|mem| mem.is_popup_open(popup_id)) {
+            // Give the focus back to the combo box:
+            ui.memory_mut(|mem| mem.request_focus(button_id));
+            keyboard_state = Default::default();
+        }
+    }
+
+    if ui.memory(|mem| mem.is_popup_open(popup_id)) {
+        ui.data_mut(|d| d.insert_temp(popup_id, keyboard_state));
+    } else {
+        ui.data_mut(|d| d.remove::<ComboBoxKeyboardState>(popup_id));
+    }
+
     InnerResponse {
         inner,
         response: button_response,
@@ -30,6 +30,11 @@ pub struct Frame {
     pub fill: Color32,
 
     pub stroke: Stroke,
+
+    /// Ask the integration to blur what is behind the frame, see [`Context::add_blur_behind`].
+    ///
+    /// Set for windows, menus, popups and panels when [`Visuals::blur_behind`] is.
+    pub blur_behind: bool,
 }
 
 impl Frame {
@@ -51,6 +56,7 @@ impl Frame {
         Self {
             inner_margin: Margin::symmetric(8.0, 2.0),
             fill: style.visuals.panel_fill,
+            blur_behind: style.visuals.blur_behind,
             ..Default::default()
         }
     }
@@ -59,6 +65,7 @@ impl Frame {
         Self {
             inner_margin: Margin::same(8.0),
             fill: style.visuals.panel_fill,
+            blur_behind: style.visuals.blur_behind,
             ..Default::default()
         }
     }
@@ -70,6 +77,7 @@ impl Frame {
             shadow: style.visuals.window_shadow,
             fill: style.visuals.window_fill(),
             stroke: style.visuals.window_stroke(),
+            blur_behind: style.visuals.blur_behind,
             ..Default::default()
         }
     }
@@ -81,6 +89,7 @@ impl Frame {
             shadow: style.visuals.popup_shadow,
             fill: style.visuals.window_fill(),
             stroke: style.visuals.window_stroke(),
+            blur_behind: style.visuals.blur_behind,
             ..Default::default()
         }
     }
@@ -92,6 +101,7 @@ impl Frame {
             shadow: style.visuals.popup_shadow,
             fill: style.visuals.window_fill(),
             stroke: style.visuals.window_stroke(),
+            blur_behind: style.visuals.blur_behind,
             ..Default::default()
         }
     }
@@ -164,6 +174,15 @@ impl Frame {
         self
     }
 
+    /// Ask the integration to blur what is behind the frame, for a frosted-glass look.
+    ///
+    /// Only makes sense with a translucent [`Self::fill`]. See [`Context::add_blur_behind`].
+    #[inline]
+    pub fn blur_behind(mut self, blur_behind: bool) -> Self {
+        self.blur_behind = blur_behind;
+        self
+    }
+
     #[inline]
     pub fn multiply_with_opacity(mut self, opacity: f32) -> Self {
         self.fill = self.fill.linear_multiply(opacity);
@@ -227,6 +246,11 @@ impl Frame {
     }
 
     pub fn paint(&self, outer_rect: Rect) -> Shape {
+        self.paint_with_circle_detail(outer_rect, 1.0)
+    }
+
+    /// Like [`Self::paint`], but with a coarser shadow if `circle_detail < 1.0`.
+    pub(crate) fn paint_with_circle_detail(&self, outer_rect: Rect, circle_detail: f32) -> Shape {
         let Self {
             inner_margin: _,
             outer_margin: _,
@@ -234,6 +258,7 @@ impl Frame {
             shadow,
             fill,
             stroke,
+            blur_behind: _,
         } = *self;
 
         let frame_shape = Shape::Rect(epaint::RectShape::new(outer_rect, rounding, fill, stroke));
@@ -241,7 +266,7 @@ impl Frame {
         if shadow == Default::default() {
             frame_shape
         } else {
-            let shadow = shadow.tessellate(outer_rect, rounding);
+            let shadow = shadow.tessellate_with_circle_detail(outer_rect, rounding, circle_detail);
             let shadow = Shape::Mesh(shadow);
             Shape::Vec(vec![shadow, frame_shape])
         }
@@ -249,7 +274,7 @@ impl Frame {
 }
 
 impl Prepared {
-    fn paint_rect(&self) -> Rect {
+    pub(crate) fn paint_rect(&self) -> Rect {
         self.frame
             .inner_margin
             .expand_rect(self.content_ui.min_rect())
@@ -269,8 +294,12 @@ impl Prepared {
         } = self;
 
         if ui.is_rect_visible(paint_rect) {
-            let shape = frame.paint(paint_rect);
+            let shape = frame.paint_with_circle_detail(paint_rect, ui.ctx().shadow_circle_detail());
             ui.painter().set(where_to_put_background, shape);
+            if frame.blur_behind {
+                ui.ctx()
+                    .add_blur_behind(ui.layer_id(), paint_rect, frame.rounding);
+            }
         }
 
         ui.allocate_rect(self.content_with_margin(), Sense::hover())
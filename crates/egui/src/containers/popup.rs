@@ -312,9 +312,13 @@ pub fn popup_below_widget<R>(
 /// Useful for drop-down menus (combo boxes) or suggestion menus under text fields.
 ///
 /// The opened popup will have the same width as the parent.
+/// It is shown with [`Area::popup_of`], so it is never clipped by the parent, e.g. by a [`ScrollArea`].
 ///
 /// You must open the popup with [`Memory::open_popup`] or  [`Memory::toggle_popup`].
 ///
+/// The popup does not take the keyboard focus, so e.g. a text field with a suggestion popup keeps it.
+/// To keep the focus within the popup instead, call [`Memory::trap_focus`] in `add_contents`.
+///
 /// Returns `None` if the popup is not open.
 ///
 /// ```
@@ -346,8 +350,7 @@ pub fn popup_above_or_below_widget<R>(
         };
 
         let inner = Area::new(popup_id)
-            .order(Order::Foreground)
-            .constrain(true)
+            .popup_of(ui)
             .fixed_pos(pos)
             .pivot(pivot)
             .show(ui.ctx(), |ui| {
@@ -26,7 +26,28 @@ impl State {
     }
 }
 
-/// A region that can be resized by dragging the bottom right corner.
+/// A region that can be resized by dragging the bottom right corner,
+/// and optionally also the right and bottom edges (see [`Self::edge_handles`]).
+///
+/// The size picked by the user is stored in [`Memory`] and persisted between frames
+/// (and between sessions, with the `persistence` feature).
+///
+/// This is what [`Window`] uses internally, but it can also be used on its own,
+/// e.g. for a resizable preview or text area inside some other container:
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// egui::Resize::default()
+///     .id_source("preview")
+///     .default_size([200.0, 100.0])
+///     .min_size([100.0, 50.0])
+///     .max_size([400.0, 300.0])
+///     .edge_handles(true)
+///     .show(ui, |ui| {
+///         ui.label("Drag the edges or the corner to resize me");
+///     });
+/// # });
+/// ```
 #[derive(Clone, Copy, Debug)]
 #[must_use = "You should call .show()"]
 pub struct Resize {
@@ -42,6 +63,10 @@ pub struct Resize {
     default_size: Vec2,
 
     with_stroke: bool,
+
+    edge_handles: bool,
+
+    auto_shrink: Vec2b,
 }
 
 impl Default for Resize {
@@ -54,6 +79,8 @@ impl Default for Resize {
             max_size: Vec2::splat(f32::INFINITY),
             default_size: vec2(320.0, 128.0), // TODO(emilk): preferred size of [`Resize`] area.
             with_stroke: true,
+            edge_handles: false,
+            auto_shrink: Vec2b::FALSE,
         }
     }
 }
@@ -184,12 +211,67 @@ impl Resize {
         self.with_stroke = with_stroke;
         self
     }
+
+    /// Can the region also be resized by dragging its right and bottom edges,
+    /// and not only the bottom right corner?
+    ///
+    /// Only has an effect if [`Self::resizable`].
+    ///
+    /// Default is `false`.
+    #[inline]
+    pub fn edge_handles(mut self, edge_handles: bool) -> Self {
+        self.edge_handles = edge_handles;
+        self
+    }
+
+    /// Should the region shrink back when its contents get smaller?
+    ///
+    /// Normally the size of the region grows to fit the contents, and then stays that size,
+    /// even if the contents later gets smaller (e.g. when switching between tabs).
+    /// With `auto_shrink` the region instead goes back to the size picked by the user
+    /// (or the default size) as soon as the contents no longer need the extra space.
+    ///
+    /// Note that contents that fill all available space (e.g. wrapping text and separators)
+    /// will fill the size picked by the user.
+    ///
+    /// Default is `false` for both axes.
+    #[inline]
+    pub fn auto_shrink(mut self, auto_shrink: impl Into<Vec2b>) -> Self {
+        self.auto_shrink = auto_shrink.into();
+        self
+    }
+}
+
+impl Resize {
+    /// The size last picked by the user for the [`Resize`] region with the given [`Id`],
+    /// if it has been shown before.
+    pub fn stored_size(ctx: &Context, id: Id) -> Option<Vec2> {
+        State::load(ctx, id).map(|state| state.desired_size)
+    }
+
+    /// Resize the region with the given [`Id`] to this size the next frame,
+    /// as if the user had dragged the handles.
+    pub fn set_size(ctx: &Context, id: Id, size: impl Into<Vec2>) {
+        let mut state = State::load(ctx, id).unwrap_or(State {
+            desired_size: Vec2::ZERO,
+            last_content_size: Vec2::ZERO,
+            requested_size: None,
+        });
+        state.requested_size = Some(size.into());
+        state.store(ctx, id);
+    }
+
+    /// Forget the size picked by the user, so that the region goes back to its default size.
+    pub fn reset(ctx: &Context, id: Id) {
+        ctx.data_mut(|d| d.remove::<State>(id));
+    }
 }
 
 struct Prepared {
     id: Id,
     state: State,
     corner_response: Option<Response>,
+    edge_responses: Option<[Response; 2]>,
     content_ui: Ui,
 }
 
@@ -243,13 +325,43 @@ impl Resize {
             None
         };
 
+        let edge_responses = if self.resizable && self.edge_handles {
+            let grab_radius = ui.style().interaction.resize_grab_radius_side;
+            let corner_size = ui.visuals().resize_corner_size;
+            let max = position + state.desired_size;
+
+            let right_rect = Rect::from_min_max(
+                pos2(max.x - grab_radius, position.y),
+                pos2(max.x + grab_radius, max.y - corner_size),
+            );
+            let right_response = ui.interact(right_rect, id.with("right_edge"), Sense::drag());
+            if let Some(pointer_pos) = right_response.interact_pointer_pos() {
+                let size = user_requested_size.get_or_insert(state.desired_size);
+                size.x = pointer_pos.x - position.x;
+            }
+
+            let bottom_rect = Rect::from_min_max(
+                pos2(position.x, max.y - grab_radius),
+                pos2(max.x - corner_size, max.y + grab_radius),
+            );
+            let bottom_response = ui.interact(bottom_rect, id.with("bottom_edge"), Sense::drag());
+            if let Some(pointer_pos) = bottom_response.interact_pointer_pos() {
+                let size = user_requested_size.get_or_insert(state.desired_size);
+                size.y = pointer_pos.y - position.y;
+            }
+
+            Some([right_response, bottom_response])
+        } else {
+            None
+        };
+
         if let Some(user_requested_size) = user_requested_size {
             state.desired_size = user_requested_size;
         } else {
             // We are not being actively resized, so auto-expand to include size of last frame.
             // This prevents auto-shrinking if the contents contain width-filling widgets (separators etc)
             // but it makes a lot of interactions with [`Window`]s nicer.
-            state.desired_size = state.desired_size.max(state.last_content_size);
+            state.desired_size = self.grow_to_fit(state.desired_size, state.last_content_size);
         }
 
         state.desired_size = state
@@ -281,10 +393,23 @@ impl Resize {
             id,
             state,
             corner_response,
+            edge_responses,
             content_ui,
         }
     }
 
+    /// Grow `desired_size` to fit the contents, except along the axes we [`Self::auto_shrink`].
+    fn grow_to_fit(&self, desired_size: Vec2, content_size: Vec2) -> Vec2 {
+        let mut size = desired_size.max(content_size);
+        if self.auto_shrink.x {
+            size.x = desired_size.x;
+        }
+        if self.auto_shrink.y {
+            size.y = desired_size.y;
+        }
+        size
+    }
+
     pub fn show<R>(mut self, ui: &mut Ui, add_contents: impl FnOnce(&mut Ui) -> R) -> R {
         let mut prepared = self.begin(ui);
         let ret = add_contents(&mut prepared.content_ui);
@@ -297,21 +422,32 @@ impl Resize {
             id,
             mut state,
             corner_response,
+            edge_responses,
             content_ui,
         } = prepared;
 
         state.last_content_size = content_ui.min_size();
 
+        if ui.is_sizing_pass() {
+            // Start out no larger than the contents need along the axes we auto-shrink:
+            if self.auto_shrink.x {
+                state.desired_size.x = state.last_content_size.x;
+            }
+            if self.auto_shrink.y {
+                state.desired_size.y = state.last_content_size.y;
+            }
+        }
+
         // ------------------------------
 
         let size = if self.with_stroke || self.resizable {
             // We show how large we are,
             // so we must follow the contents:
 
-            state.desired_size = state.desired_size.max(state.last_content_size);
+            state.desired_size = self.grow_to_fit(state.desired_size, state.last_content_size);
 
             // We are as large as we look
-            state.desired_size
+            state.desired_size.max(state.last_content_size)
         } else {
             // Probably a window.
             state.last_content_size
@@ -321,7 +457,7 @@ impl Resize {
         // ------------------------------
 
         if self.with_stroke && corner_response.is_some() {
-            let rect = Rect::from_min_size(content_ui.min_rect().left_top(), state.desired_size);
+            let rect = Rect::from_min_size(content_ui.min_rect().left_top(), size);
             let rect = rect.expand(2.0); // breathing room for content
             ui.painter().add(Shape::rect_stroke(
                 rect,
@@ -338,6 +474,28 @@ impl Resize {
             }
         }
 
+        if let Some([right_response, bottom_response]) = edge_responses {
+            let rect = Rect::from_min_size(content_ui.min_rect().left_top(), size);
+            for (response, edge, cursor_icon) in [
+                (
+                    right_response,
+                    [rect.right_top(), rect.right_bottom()],
+                    CursorIcon::ResizeHorizontal,
+                ),
+                (
+                    bottom_response,
+                    [rect.left_bottom(), rect.right_bottom()],
+                    CursorIcon::ResizeVertical,
+                ),
+            ] {
+                if response.hovered() || response.dragged() {
+                    ui.ctx().set_cursor_icon(cursor_icon);
+                    let stroke = ui.style().interact(&response).fg_stroke;
+                    ui.painter().line_segment(edge, stroke);
+                }
+            }
+        }
+
         state.store(ui.ctx(), id);
 
         #[cfg(debug_assertions)]
@@ -385,3 +543,33 @@ pub fn paint_resize_corner_with_style(
         w += 4.0;
     }
 }
+
+#[test]
+fn resize_set_size_and_reset() {
+    let ctx = Context::default();
+    let id = Id::new("resize");
+    let run = || {
+        let _ = ctx.run(Default::default(), |ctx| {
+            crate::CentralPanel::default().show(ctx, |ui| {
+                Resize::default()
+                    .id(id)
+                    .default_size([200.0, 100.0])
+                    .max_size([300.0, 300.0])
+                    .edge_handles(true)
+                    .show(ui, |_ui| {});
+            });
+        });
+    };
+
+    run();
+    assert_eq!(Resize::stored_size(&ctx, id), Some(vec2(200.0, 100.0)));
+
+    Resize::set_size(&ctx, id, [250.0, 500.0]);
+    run();
+    assert_eq!(Resize::stored_size(&ctx, id), Some(vec2(250.0, 300.0)));
+
+    Resize::reset(&ctx, id);
+    assert_eq!(Resize::stored_size(&ctx, id), None);
+    run();
+    assert_eq!(Resize::stored_size(&ctx, id), Some(vec2(200.0, 100.0)));
+}
@@ -25,10 +25,21 @@ pub struct State {
     /// Mouse offset relative to the top of the handle when started moving the handle.
     scroll_start_offset_from_top_left: [Option<f32>; 2],
 
+    /// Is the user pressing the scroll bar outside of the handle?
+    ///
+    /// This jumps one page, and then nothing more happens until the mouse is released.
+    scroll_bar_track_pressed: Vec2b,
+
     /// Is the scroll sticky. This is true while scroll handle is in the end position
     /// and remains that way until the user moves the scroll_handle. Once unstuck (false)
     /// it remains false until the scroll touches the end position, which reenables stickiness.
     scroll_stuck_to_end: Vec2b,
+
+    /// When did the user last scroll or interact with the scroll bars?
+    ///
+    /// Used for [`crate::style::ScrollStyle::auto_hide_delay`].
+    #[cfg_attr(feature = "serde", serde(skip))]
+    last_activity_time: Option<f64>,
 }
 
 impl Default for State {
@@ -40,7 +51,9 @@ impl Default for State {
             scroll_bar_interaction: Vec2b::FALSE,
             vel: Vec2::ZERO,
             scroll_start_offset_from_top_left: [None; 2],
+            scroll_bar_track_pressed: Vec2b::FALSE,
             scroll_stuck_to_end: Vec2b::TRUE,
+            last_activity_time: None,
         }
     }
 }
@@ -165,6 +178,11 @@ pub struct ScrollArea {
     /// end position until user manually changes position. It will become true
     /// again once scroll handle makes contact with end.
     stick_to_end: Vec2b,
+
+    show_position_indicator: bool,
+
+    /// Set by [`Self::show_rows`]: the row height (including spacing) and the number of rows.
+    rows: Option<(f32, usize)>,
 }
 
 impl ScrollArea {
@@ -208,6 +226,8 @@ impl ScrollArea {
             scrolling_enabled: true,
             drag_to_scroll: true,
             stick_to_end: Vec2b::FALSE,
+            show_position_indicator: false,
+            rows: None,
         }
     }
 
@@ -395,11 +415,36 @@ impl ScrollArea {
     /// it will remain focused on whatever content viewport the user left it on. If the scroll
     /// handle is dragged to the bottom it will again become stuck and remain there until manually
     /// pulled from the end position.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let messages = ["Hello", "World"];
+    /// egui::ScrollArea::vertical()
+    ///     .stick_to_bottom(true)
+    ///     .show(ui, |ui| {
+    ///         for message in messages {
+    ///             ui.label(message);
+    ///         }
+    ///     });
+    /// # });
+    /// ```
     #[inline]
     pub fn stick_to_bottom(mut self, stick: bool) -> Self {
         self.stick_to_end[1] = stick;
         self
     }
+
+    /// Show the scroll position (e.g. "1 234 / 56 789") next to the handle while dragging a scroll bar.
+    ///
+    /// With [`Self::show_rows`] this is the first visible row and the number of rows,
+    /// otherwise it is the scroll offset and the maximum offset, in points.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn show_position_indicator(mut self, show_position_indicator: bool) -> Self {
+        self.show_position_indicator = show_position_indicator;
+        self
+    }
 }
 
 struct Prepared {
@@ -438,6 +483,11 @@ struct Prepared {
 
     scrolling_enabled: bool,
     stick_to_end: Vec2b,
+    show_position_indicator: bool,
+    rows: Option<(f32, usize)>,
+
+    /// The scroll offset at the end of the previous frame.
+    prev_offset: Vec2,
 }
 
 impl ScrollArea {
@@ -454,6 +504,8 @@ impl ScrollArea {
             scrolling_enabled,
             drag_to_scroll,
             stick_to_end,
+            show_position_indicator,
+            rows,
         } = self;
 
         let ctx = ui.ctx().clone();
@@ -466,6 +518,7 @@ impl ScrollArea {
             "ScrollArea",
         );
         let mut state = State::load(&ctx, id).unwrap_or_default();
+        let prev_offset = state.offset;
 
         state.offset.x = offset_x.unwrap_or(state.offset.x);
         state.offset.y = offset_y.unwrap_or(state.offset.y);
@@ -520,6 +573,7 @@ impl ScrollArea {
 
         let content_max_rect = Rect::from_min_size(inner_rect.min - state.offset, content_max_size);
         let mut content_ui = ui.child_ui(content_max_rect, *ui.layout());
+        content_ui.set_scrolling(scroll_enabled);
 
         {
             // Clip the content, but only when we really need to:
@@ -566,7 +620,7 @@ impl ScrollArea {
             } else {
                 let stop_speed = 20.0; // Pixels per second.
                 let friction_coeff = 1000.0; // Pixels per second squared.
-                let dt = ui.input(|i| i.unstable_dt);
+                let dt = ui.input(|i| i.stable_dt);
 
                 let friction = friction_coeff * dt;
                 if friction > state.vel.length() || state.vel.length() < stop_speed {
@@ -594,6 +648,9 @@ impl ScrollArea {
             viewport,
             scrolling_enabled,
             stick_to_end,
+            show_position_indicator,
+            rows,
+            prev_offset,
         }
     }
 
@@ -633,7 +690,11 @@ impl ScrollArea {
     ) -> ScrollAreaOutput<R> {
         let spacing = ui.spacing().item_spacing;
         let row_height_with_spacing = row_height_sans_spacing + spacing.y;
-        self.show_viewport(ui, |ui, viewport| {
+        let scroll_area = Self {
+            rows: Some((row_height_with_spacing, total_rows)),
+            ..self
+        };
+        scroll_area.show_viewport(ui, |ui, viewport| {
             ui.set_height((row_height_with_spacing * total_rows as f32 - spacing.y).at_least(0.0));
 
             let mut min_row = (viewport.min.y / row_height_with_spacing).floor() as usize;
@@ -705,6 +766,9 @@ impl Prepared {
             viewport: _,
             scrolling_enabled,
             stick_to_end,
+            show_position_indicator,
+            rows,
+            prev_offset,
         } = self;
 
         let content_size = content_ui.min_size();
@@ -810,11 +874,34 @@ impl Prepared {
 
         let scroll_style = ui.spacing().scroll;
 
+        let now = ui.input(|i| i.time);
+        let last_activity_time = *state.last_activity_time.get_or_insert(now);
+
+        // Fade out floating scroll bars after a period of inactivity:
+        let auto_hide_factor = match scroll_style.auto_hide_delay {
+            Some(delay) if scroll_style.floating => {
+                let time_left = delay as f64 - (now - last_activity_time);
+                if time_left > 0.0 {
+                    ui.ctx()
+                        .request_repaint_after(std::time::Duration::from_secs_f64(time_left));
+                }
+                ui.ctx().animate_bool(id.with("auto_hide"), time_left > 0.0)
+            }
+            _ => 1.0,
+        };
+
+        let bar_rounding = scroll_style.bar_rounding;
+
         // Paint the bars:
         for d in 0..2 {
             // maybe force increase in offset to keep scroll stuck to end position
             if stick_to_end[d] && state.scroll_stuck_to_end[d] {
-                state.offset[d] = content_size[d] - inner_rect.size()[d];
+                let end_offset = (content_size[d] - inner_rect.size()[d]).at_least(0.0);
+                if state.offset[d] != end_offset {
+                    state.offset[d] = end_offset;
+                    // The new content was shown at the old offset, so show it again at the end:
+                    ui.ctx().request_repaint();
+                }
             }
 
             let show_factor = show_bars_factor[d];
@@ -887,23 +974,30 @@ impl Prepared {
                 )
             };
 
-            let from_content = |content| remap_clamp(content, 0.0..=content_size[d], main_range);
-
-            let handle_rect = if d == 0 {
-                Rect::from_min_max(
-                    pos2(from_content(state.offset.x), cross.min),
-                    pos2(from_content(state.offset.x + inner_rect.width()), cross.max),
-                )
-            } else {
-                Rect::from_min_max(
-                    pos2(cross.min, from_content(state.offset.y)),
-                    pos2(
-                        cross.max,
-                        from_content(state.offset.y + inner_rect.height()),
-                    ),
-                )
+            // The handle is never shorter than `handle_min_length`,
+            // so it moves within a range that is a bit shorter than the whole bar:
+            let handle_length = (main_range.span() * inner_rect.size()[d] / content_size[d])
+                .at_least(scroll_style.handle_min_length)
+                .at_most(main_range.span());
+            let handle_top_range = Rangef::new(main_range.min, main_range.max - handle_length);
+            let max_offset_d = max_offset[d].at_least(0.0);
+
+            let handle_rect_at = |offset: f32| {
+                let top = if max_offset_d > 0.0 {
+                    remap_clamp(offset, 0.0..=max_offset_d, handle_top_range)
+                } else {
+                    main_range.min
+                };
+                let main = Rangef::new(top, top + handle_length);
+                if d == 0 {
+                    Rect::from_x_y_ranges(main, cross)
+                } else {
+                    Rect::from_x_y_ranges(cross, main)
+                }
             };
 
+            let handle_rect = handle_rect_at(state.offset[d]);
+
             let interact_id = id.with(d);
             let sense = if self.scrolling_enabled {
                 Sense::click_and_drag()
@@ -915,26 +1009,35 @@ impl Prepared {
             state.scroll_bar_interaction[d] = response.hovered() || response.dragged();
 
             if let Some(pointer_pos) = response.interact_pointer_pos() {
-                let scroll_start_offset_from_top_left = state.scroll_start_offset_from_top_left[d]
-                    .get_or_insert_with(|| {
-                        if handle_rect.contains(pointer_pos) {
-                            pointer_pos[d] - handle_rect.min[d]
-                        } else {
-                            let handle_top_pos_at_bottom = main_range.max - handle_rect.size()[d];
-                            // Calculate the new handle top position, centering the handle on the mouse.
-                            let new_handle_top_pos = (pointer_pos[d] - handle_rect.size()[d] / 2.0)
-                                .clamp(main_range.min, handle_top_pos_at_bottom);
-                            pointer_pos[d] - new_handle_top_pos
-                        }
-                    });
-
-                let new_handle_top = pointer_pos[d] - *scroll_start_offset_from_top_left;
-                state.offset[d] = remap(new_handle_top, main_range, 0.0..=content_size[d]);
+                let is_new_press = state.scroll_start_offset_from_top_left[d].is_none()
+                    && !state.scroll_bar_track_pressed[d];
+
+                if is_new_press && !handle_rect.contains(pointer_pos) {
+                    // Clicked the track: jump one page towards the click.
+                    state.scroll_bar_track_pressed[d] = true;
+                    let page = inner_rect.size()[d];
+                    if pointer_pos[d] < handle_rect.min[d] {
+                        state.offset[d] -= page;
+                    } else {
+                        state.offset[d] += page;
+                    }
+                } else if !state.scroll_bar_track_pressed[d] {
+                    let scroll_start_offset_from_top_left = state.scroll_start_offset_from_top_left
+                        [d]
+                        .get_or_insert(pointer_pos[d] - handle_rect.min[d]);
+
+                    let new_handle_top = pointer_pos[d] - *scroll_start_offset_from_top_left;
+                    if handle_top_range.span() > 0.0 {
+                        state.offset[d] =
+                            remap(new_handle_top, handle_top_range, 0.0..=max_offset_d);
+                    }
+                }
 
                 // some manual action taken, scroll not stuck
                 state.scroll_stuck_to_end[d] = false;
             } else {
                 state.scroll_start_offset_from_top_left[d] = None;
+                state.scroll_bar_track_pressed[d] = false;
             }
 
             let unbounded_offset = state.offset[d];
@@ -947,31 +1050,7 @@ impl Prepared {
 
             if ui.is_rect_visible(outer_scroll_rect) {
                 // Avoid frame-delay by calculating a new handle rect:
-                let mut handle_rect = if d == 0 {
-                    Rect::from_min_max(
-                        pos2(from_content(state.offset.x), cross.min),
-                        pos2(from_content(state.offset.x + inner_rect.width()), cross.max),
-                    )
-                } else {
-                    Rect::from_min_max(
-                        pos2(cross.min, from_content(state.offset.y)),
-                        pos2(
-                            cross.max,
-                            from_content(state.offset.y + inner_rect.height()),
-                        ),
-                    )
-                };
-                let min_handle_size = scroll_style.handle_min_length;
-                if handle_rect.size()[d] < min_handle_size {
-                    handle_rect = Rect::from_center_size(
-                        handle_rect.center(),
-                        if d == 0 {
-                            vec2(min_handle_size, handle_rect.size().y)
-                        } else {
-                            vec2(handle_rect.size().x, min_handle_size)
-                        },
-                    );
-                }
+                let handle_rect = handle_rect_at(state.offset[d]);
 
                 let visuals = if scrolling_enabled {
                     // Pick visuals based on interaction with the handle.
@@ -1002,11 +1081,12 @@ impl Prepared {
                             id.with((d, "is_hovering_outer_rect")),
                             is_hovering_outer_rect,
                         );
-                        lerp(
-                            scroll_style.dormant_handle_opacity
-                                ..=scroll_style.active_handle_opacity,
-                            is_hovering_outer_rect_t,
-                        )
+                        auto_hide_factor
+                            * lerp(
+                                scroll_style.dormant_handle_opacity
+                                    ..=scroll_style.active_handle_opacity,
+                                is_hovering_outer_rect_t,
+                            )
                     }
                 } else {
                     1.0
@@ -1016,9 +1096,9 @@ impl Prepared {
                     if response.hovered() || response.dragged() {
                         scroll_style.interact_background_opacity
                     } else if is_hovering_outer_rect {
-                        scroll_style.active_background_opacity
+                        auto_hide_factor * scroll_style.active_background_opacity
                     } else {
-                        scroll_style.dormant_background_opacity
+                        auto_hide_factor * scroll_style.dormant_background_opacity
                     }
                 } else {
                     1.0
@@ -1030,10 +1110,12 @@ impl Prepared {
                     visuals.bg_fill
                 };
 
+                let rounding = bar_rounding.unwrap_or(visuals.rounding);
+
                 // Background:
                 ui.painter().add(epaint::Shape::rect_filled(
                     outer_scroll_rect,
-                    visuals.rounding,
+                    rounding,
                     ui.visuals()
                         .extreme_bg_color
                         .gamma_multiply(background_opacity),
@@ -1042,9 +1124,30 @@ impl Prepared {
                 // Handle:
                 ui.painter().add(epaint::Shape::rect_filled(
                     handle_rect,
-                    visuals.rounding,
+                    rounding,
                     handle_color.gamma_multiply(handle_opacity),
                 ));
+
+                if show_position_indicator && state.scroll_start_offset_from_top_left[d].is_some() {
+                    let number_format = ui.style().number_format;
+                    let format = |n: usize| number_format.format(&n.to_string());
+                    let text = match rows {
+                        Some((row_height, total_rows)) if d == 1 && row_height > 0.0 => {
+                            let first_row = (state.offset[d] / row_height).floor() as usize + 1;
+                            format!(
+                                "{} / {}",
+                                format(first_row.min(total_rows)),
+                                format(total_rows)
+                            )
+                        }
+                        _ => format!(
+                            "{} / {}",
+                            format(state.offset[d].round() as usize),
+                            format(max_offset_d.round() as usize)
+                        ),
+                    };
+                    paint_position_indicator(ui, id.with((d, "position")), d, text, handle_rect);
+                }
             }
         }
 
@@ -1070,6 +1173,10 @@ impl Prepared {
                 || (self.stick_to_end[1] && available_offset[1] < 0.0),
         );
 
+        if state.offset != prev_offset || state.scroll_bar_interaction.any() {
+            state.last_activity_time = Some(now);
+        }
+
         state.show_scroll = show_scroll_this_frame;
         state.content_is_too_large = content_is_too_large;
 
@@ -1078,3 +1185,38 @@ impl Prepared {
         (content_size, state)
     }
 }
+
+/// Paint the scroll position next to the handle of a scroll bar.
+fn paint_position_indicator(ui: &Ui, id: Id, d: usize, text: String, handle_rect: Rect) {
+    let painter = ui.ctx().layer_painter(LayerId::new(Order::Tooltip, id));
+    let galley = painter.layout_no_wrap(
+        text,
+        TextStyle::Body.resolve(ui.style()),
+        ui.visuals().text_color(),
+    );
+
+    let margin = ui.spacing().button_padding;
+    let gap = ui.spacing().item_spacing;
+    let size = galley.size() + 2.0 * margin;
+    let min = if d == 0 {
+        pos2(
+            handle_rect.center().x - size.x / 2.0,
+            handle_rect.top() - gap.y - size.y,
+        )
+    } else {
+        pos2(
+            handle_rect.left() - gap.x - size.x,
+            handle_rect.center().y - size.y / 2.0,
+        )
+    };
+    let rect = Rect::from_min_size(min, size);
+
+    let visuals = ui.visuals();
+    painter.rect(
+        rect,
+        visuals.menu_rounding,
+        visuals.window_fill,
+        visuals.window_stroke,
+    );
+    painter.galley(rect.min + margin, galley);
+}
@@ -0,0 +1,343 @@
+//! Divide a region between two child [`Ui`]s, with a draggable divider between them.
+
+use crate::*;
+
+/// The persisted state of a [`Splitter`].
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+struct State {
+    /// How large a fraction of the space goes to the first pane, in `0..=1`.
+    ///
+    /// This is what the user chose, before making room for [`Splitter::min_size`],
+    /// so that it survives the available space shrinking for a while.
+    ratio: f32,
+}
+
+impl State {
+    fn load(ctx: &Context, id: Id) -> Option<Self> {
+        ctx.data_mut(|d| d.get_persisted(id))
+    }
+
+    fn store(self, ctx: &Context, id: Id) {
+        ctx.data_mut(|d| d.insert_persisted(id, self));
+    }
+}
+
+/// Divides all the available space between two panes, with a divider the user can drag.
+///
+/// A [`Self::horizontal`] splitter puts the panes side by side,
+/// a [`Self::vertical`] one puts them on top of each other.
+///
+/// The position of the divider is stored in [`Memory`].
+/// Double-click the divider to reset it to [`Self::default_ratio`].
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// egui::Splitter::horizontal("editor_splitter")
+///     .default_ratio(0.3)
+///     .show(ui, |left_ui, right_ui| {
+///         left_ui.label("File tree");
+///         right_ui.label("Editor");
+///     });
+/// # });
+/// ```
+#[must_use = "You should call .show()"]
+pub struct Splitter {
+    id_source: Id,
+    vertical: bool,
+    default_ratio: f32,
+    min_size: f32,
+}
+
+impl Splitter {
+    /// Put the two panes side by side, with a vertical divider between them.
+    pub fn horizontal(id_source: impl std::hash::Hash) -> Self {
+        Self::new(id_source, false)
+    }
+
+    /// Put the two panes on top of each other, with a horizontal divider between them.
+    pub fn vertical(id_source: impl std::hash::Hash) -> Self {
+        Self::new(id_source, true)
+    }
+
+    fn new(id_source: impl std::hash::Hash, vertical: bool) -> Self {
+        Self {
+            id_source: Id::new(id_source),
+            vertical,
+            default_ratio: 0.5,
+            min_size: 32.0,
+        }
+    }
+
+    /// How large a fraction of the space goes to the first pane, until the user drags the divider.
+    ///
+    /// Default is `0.5`.
+    #[inline]
+    pub fn default_ratio(mut self, default_ratio: f32) -> Self {
+        self.default_ratio = default_ratio.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Neither pane can be dragged to be smaller than this (in points).
+    ///
+    /// Default is `32.0`.
+    #[inline]
+    pub fn min_size(mut self, min_size: f32) -> Self {
+        self.min_size = min_size;
+        self
+    }
+
+    /// Show the splitter, filling all the available space.
+    ///
+    /// `add_contents` is given the first (left or top) and second (right or bottom) pane.
+    pub fn show<R>(
+        self,
+        ui: &mut Ui,
+        add_contents: impl FnOnce(&mut Ui, &mut Ui) -> R,
+    ) -> InnerResponse<R> {
+        self.show_dyn(ui, Box::new(add_contents))
+    }
+
+    fn show_dyn<'c, R>(
+        self,
+        ui: &mut Ui,
+        add_contents: Box<dyn FnOnce(&mut Ui, &mut Ui) -> R + 'c>,
+    ) -> InnerResponse<R> {
+        let Self {
+            id_source,
+            vertical,
+            default_ratio,
+            min_size,
+        } = self;
+
+        let id = ui.make_persistent_id(id_source);
+        let rect = ui.available_rect_before_wrap();
+        let mut state = State::load(ui.ctx(), id).unwrap_or(State {
+            ratio: default_ratio,
+        });
+
+        // Lengths along the split axis:
+        let gap = if vertical {
+            ui.spacing().item_spacing.y
+        } else {
+            ui.spacing().item_spacing.x
+        };
+        let (start, length) = if vertical {
+            (rect.top(), rect.height())
+        } else {
+            (rect.left(), rect.width())
+        };
+        let available = (length - gap).at_least(0.0);
+        let clamp_ratio = |ratio: f32| {
+            if available <= 2.0 * min_size {
+                0.5
+            } else {
+                ratio.clamp(min_size / available, 1.0 - min_size / available)
+            }
+        };
+
+        let divider_pos = |ratio: f32| start + clamp_ratio(ratio) * available + 0.5 * gap;
+        let divider_rect = |ratio: f32| {
+            let grab_radius = ui
+                .style()
+                .interaction
+                .resize_grab_radius_side
+                .max(0.5 * gap);
+            let pos = divider_pos(ratio);
+            if vertical {
+                Rect::from_x_y_ranges(rect.x_range(), pos - grab_radius..=pos + grab_radius)
+            } else {
+                Rect::from_x_y_ranges(pos - grab_radius..=pos + grab_radius, rect.y_range())
+            }
+        };
+
+        let divider_response = ui.interact(
+            divider_rect(state.ratio),
+            id.with("divider"),
+            Sense::click_and_drag(),
+        );
+        if divider_response.double_clicked() {
+            state.ratio = default_ratio;
+        } else if let Some(pointer_pos) = divider_response.interact_pointer_pos() {
+            let pointer = if vertical {
+                pointer_pos.y
+            } else {
+                pointer_pos.x
+            };
+            // Keep the divider where it was grabbed, instead of jumping to the pointer:
+            let grab_offset_id = id.with("grab_offset");
+            if divider_response.drag_started() {
+                let grab_offset = pointer - divider_pos(state.ratio);
+                ui.data_mut(|d| d.insert_temp(grab_offset_id, grab_offset));
+            }
+            if divider_response.dragged() && available > 0.0 {
+                let grab_offset: f32 = ui.data(|d| d.get_temp(grab_offset_id)).unwrap_or(0.0);
+                let divider = pointer - grab_offset;
+                // If the divider is dragged against `min_size`, it will stay there:
+                state.ratio = clamp_ratio((divider - 0.5 * gap - start) / available);
+            }
+        }
+
+        let divider = divider_pos(state.ratio);
+        let (first_rect, second_rect) = if vertical {
+            (
+                Rect::from_x_y_ranges(rect.x_range(), rect.top()..=divider - 0.5 * gap),
+                Rect::from_x_y_ranges(rect.x_range(), divider + 0.5 * gap..=rect.bottom()),
+            )
+        } else {
+            (
+                Rect::from_x_y_ranges(rect.left()..=divider - 0.5 * gap, rect.y_range()),
+                Rect::from_x_y_ranges(divider + 0.5 * gap..=rect.right(), rect.y_range()),
+            )
+        };
+
+        let mut first_ui = ui.child_ui_with_id_source(first_rect, *ui.layout(), "first");
+        first_ui.set_clip_rect(first_rect.intersect(ui.clip_rect()));
+        let mut second_ui = ui.child_ui_with_id_source(second_rect, *ui.layout(), "second");
+        second_ui.set_clip_rect(second_rect.intersect(ui.clip_rect()));
+
+        let inner = add_contents(&mut first_ui, &mut second_ui);
+
+        {
+            let stroke = if divider_response.dragged() {
+                ui.visuals().widgets.active.fg_stroke
+            } else if divider_response.hovered() {
+                ui.visuals().widgets.hovered.fg_stroke
+            } else {
+                ui.visuals().widgets.noninteractive.bg_stroke
+            };
+            if vertical {
+                ui.painter().hline(rect.x_range(), divider, stroke);
+            } else {
+                ui.painter().vline(divider, rect.y_range(), stroke);
+            }
+        }
+
+        if divider_response.hovered() || divider_response.dragged() {
+            ui.ctx().set_cursor_icon(if vertical {
+                CursorIcon::ResizeVertical
+            } else {
+                CursorIcon::ResizeHorizontal
+            });
+        }
+
+        state.store(ui.ctx(), id);
+
+        let response = ui.allocate_rect(rect, Sense::hover());
+        InnerResponse::new(inner, response | divider_response)
+    }
+}
+
+#[test]
+fn splitter_divides_the_available_space() {
+    let ctx = Context::default();
+    let run = |ratio: f32| {
+        let mut rects = (Rect::NOTHING, Rect::NOTHING);
+        let _ = ctx.run(Default::default(), |ctx| {
+            crate::CentralPanel::default().show(ctx, |ui| {
+                ui.spacing_mut().item_spacing.x = 0.0;
+                let width = ui.available_width();
+                Splitter::horizontal("splitter")
+                    .default_ratio(ratio)
+                    .min_size(0.1 * width)
+                    .show(ui, |left_ui, right_ui| {
+                        rects = (left_ui.max_rect(), right_ui.max_rect());
+                    });
+            });
+        });
+        let (left, right) = rects;
+        assert_eq!(left.right(), right.left());
+        left.width() / (left.width() + right.width())
+    };
+
+    assert!((run(0.25) - 0.25).abs() < 1e-3);
+
+    // The ratio is stored, so a new default is ignored:
+    assert!((run(0.75) - 0.25).abs() < 1e-3);
+
+    // The panes are never smaller than `min_size`:
+    let ctx_clean = Context::default();
+    let _ = ctx_clean.run(Default::default(), |ctx| {
+        crate::CentralPanel::default().show(ctx, |ui| {
+            let width = ui.available_width();
+            Splitter::horizontal("splitter")
+                .default_ratio(0.0)
+                .min_size(0.1 * width)
+                .show(ui, |left_ui, _right_ui| {
+                    assert!(left_ui.max_rect().width() >= 0.1 * width - 1e-3);
+                });
+        });
+    });
+}
+
+#[test]
+fn splitter_keeps_its_ratio_while_too_small() {
+    let ctx = Context::default();
+    let run = |width: f32| {
+        let mut left_width = 0.0;
+        let input = RawInput {
+            screen_rect: Some(Rect::from_min_size(Pos2::ZERO, vec2(width, 100.0))),
+            ..Default::default()
+        };
+        let _ = ctx.run(input, |ctx| {
+            crate::CentralPanel::default()
+                .frame(Frame::none())
+                .show(ctx, |ui| {
+                    ui.spacing_mut().item_spacing.x = 0.0;
+                    Splitter::horizontal("splitter")
+                        .default_ratio(0.25)
+                        .min_size(40.0)
+                        .show(ui, |left_ui, _right_ui| {
+                            left_width = left_ui.max_rect().width();
+                        });
+                });
+        });
+        left_width
+    };
+
+    assert_eq!(run(400.0), 100.0);
+    assert_eq!(run(60.0), 30.0); // No room for `min_size`
+    assert_eq!(run(100.0), 40.0); // `min_size`
+    assert_eq!(run(400.0), 100.0); // Back to the ratio of the user
+}
+
+#[test]
+fn splitter_divider_does_not_jump_when_dragged() {
+    let ctx = Context::default();
+    let screen_rect = Rect::from_min_size(Pos2::ZERO, vec2(400.0, 100.0));
+    let run = |events: Vec<Event>| {
+        let mut left_width = 0.0;
+        let input = RawInput {
+            screen_rect: Some(screen_rect),
+            events,
+            ..Default::default()
+        };
+        let _ = ctx.run(input, |ctx| {
+            crate::CentralPanel::default()
+                .frame(Frame::none())
+                .show(ctx, |ui| {
+                    ui.spacing_mut().item_spacing.x = 0.0;
+                    Splitter::horizontal("splitter").show(ui, |left_ui, _right_ui| {
+                        left_width = left_ui.max_rect().width();
+                    });
+                });
+        });
+        left_width
+    };
+    let pointer_button = |pos: Pos2, pressed: bool| Event::PointerButton {
+        pos,
+        button: PointerButton::Primary,
+        pressed,
+        modifiers: Default::default(),
+    };
+
+    assert_eq!(run(vec![]), 200.0);
+
+    // Grab the divider a bit to the right of its center:
+    let grab = pos2(203.0, 50.0);
+    run(vec![Event::PointerMoved(grab), pointer_button(grab, true)]);
+    run(vec![Event::PointerMoved(grab + vec2(10.0, 0.0))]);
+    let width = run(vec![Event::PointerMoved(grab + vec2(20.0, 0.0))]);
+    assert_eq!(width, 220.0);
+    run(vec![pointer_button(grab + vec2(20.0, 0.0), false)]);
+}
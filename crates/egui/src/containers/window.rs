@@ -38,6 +38,7 @@ pub struct Window<'open> {
     collapsible: bool,
     default_open: bool,
     with_title_bar: bool,
+    title_bar_ui: Option<Box<dyn FnOnce(&mut Ui) + 'open>>,
 }
 
 impl<'open> Window<'open> {
@@ -60,6 +61,7 @@ impl<'open> Window<'open> {
             collapsible: true,
             default_open: true,
             with_title_bar: true,
+            title_bar_ui: None,
         }
     }
 
@@ -218,6 +220,26 @@ impl<'open> Window<'open> {
         self
     }
 
+    /// Make this window a child of the given [`Ui`], e.g. of another window or a panel.
+    ///
+    /// The window will move along with, be clipped by, and always be shown above, its parent.
+    /// See [`Area::child_of`] for details.
+    ///
+    /// ```
+    /// # egui::__run_test_ctx(|ctx| {
+    /// egui::Window::new("Editor").show(ctx, |ui| {
+    ///     egui::Window::new("Document").child_of(ui).show(ctx, |ui| {
+    ///         ui.label("Lives inside the editor window");
+    ///     });
+    /// });
+    /// # });
+    /// ```
+    #[inline]
+    pub fn child_of(mut self, parent: &Ui) -> Self {
+        self.area = self.area.child_of(parent);
+        self
+    }
+
     /// Where the "root" of the window is.
     ///
     /// For instance, if you set this to [`Align2::RIGHT_TOP`]
@@ -319,6 +341,51 @@ impl<'open> Window<'open> {
         self
     }
 
+    /// Replace the title text in the title bar with your own widgets.
+    ///
+    /// The closure is given a left-to-right [`Ui`] spanning the title bar between
+    /// the collapse button and the close button (if any).
+    /// Use this for extra buttons, status icons, or an editable title.
+    ///
+    /// The contents are clipped to the title bar, and no extra room is made for them,
+    /// so use [`Self::min_width`] if they need more space.
+    /// Double-clicking the title bar right of your widgets still collapses the window.
+    ///
+    /// The window title is still used for the [`Id`] (see [`Self::new`]).
+    ///
+    /// ```
+    /// # egui::__run_test_ctx(|ctx| {
+    /// # let mut title = String::from("Untitled");
+    /// egui::Window::new("editor")
+    ///     .title_bar_ui(|ui| {
+    ///         ui.text_edit_singleline(&mut title);
+    ///         ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+    ///             ui.small_button("⟳");
+    ///         });
+    ///     })
+    ///     .show(ctx, |ui| {
+    ///         ui.label("Hello World!");
+    ///     });
+    /// # });
+    /// ```
+    #[inline]
+    pub fn title_bar_ui(mut self, title_bar_ui: impl FnOnce(&mut Ui) + 'open) -> Self {
+        self.title_bar_ui = Some(Box::new(title_bar_ui));
+        self
+    }
+
+    /// Should the window shrink back when its contents get smaller?
+    ///
+    /// Useful for dialogs that switch between tabs of different sizes.
+    /// See [`Resize::auto_shrink`].
+    ///
+    /// Default is `false` for both axes.
+    #[inline]
+    pub fn auto_shrink(mut self, auto_shrink: impl Into<Vec2b>) -> Self {
+        self.resize = self.resize.auto_shrink(auto_shrink);
+        self
+    }
+
     /// Not resizable, just takes the size of its contents.
     /// Also disabled scrolling.
     /// Text will not wrap, but will instead make your window width expand.
@@ -387,6 +454,7 @@ impl<'open> Window<'open> {
             collapsible,
             default_open,
             with_title_bar,
+            title_bar_ui,
         } = self;
 
         let frame = frame.unwrap_or_else(|| Frame::window(&ctx.style()));
@@ -401,6 +469,7 @@ impl<'open> Window<'open> {
 
         let area_id = area.id;
         let area_layer_id = area.layer();
+        ctx.set_id_name(area_id, title.text());
         let resize_id = area_id.with("resize");
         let mut collapsing =
             CollapsingState::load_with_default_open(ctx, area_id.with("collapsing"), default_open);
@@ -464,6 +533,7 @@ impl<'open> Window<'open> {
                 let title_bar = show_title_bar(
                     &mut frame.content_ui,
                     title,
+                    title_bar_ui,
                     show_close_button,
                     &mut collapsing,
                     collapsible,
@@ -889,12 +959,21 @@ fn paint_frame_interaction(
 
 // ----------------------------------------------------------------------------
 
-struct TitleBar {
+/// What to show in the title bar, next to the collapse- and close buttons.
+enum TitleContent<'a> {
+    /// The window title, prepared and centered in the title bar.
+    Text(WidgetTextGalley),
+
+    /// User widgets, from [`Window::title_bar_ui`].
+    Custom(Box<dyn FnOnce(&mut Ui) + 'a>),
+}
+
+struct TitleBar<'a> {
     /// A title Id used for dragging windows
     id: Id,
 
-    /// Prepared text in the title
-    title_galley: WidgetTextGalley,
+    /// Prepared text in the title, or custom contents
+    content: TitleContent<'a>,
 
     /// Size of the title bar in a collapsed state (if window is collapsible),
     /// which includes all necessary space for showing the expand button, the
@@ -906,13 +985,14 @@ struct TitleBar {
     rect: Rect,
 }
 
-fn show_title_bar(
+fn show_title_bar<'a>(
     ui: &mut Ui,
     title: WidgetText,
+    title_bar_ui: Option<Box<dyn FnOnce(&mut Ui) + 'a>>,
     show_close_button: bool,
     collapsing: &mut CollapsingState,
     collapsible: bool,
-) -> TitleBar {
+) -> TitleBar<'a> {
     let inner_response = ui.horizontal(|ui| {
         let height = ui
             .fonts(|fonts| title.font_height(fonts, ui.style()))
@@ -929,20 +1009,28 @@ fn show_title_bar(
             collapsing.show_default_button_with_size(ui, button_size);
         }
 
-        let title_galley = title.into_galley(ui, Some(false), f32::INFINITY, TextStyle::Heading);
+        let (content, content_width) = if let Some(title_bar_ui) = title_bar_ui {
+            // We don't know how wide the custom contents will be, so we don't reserve any space for it.
+            (TitleContent::Custom(title_bar_ui), 0.0)
+        } else {
+            let title_galley =
+                title.into_galley(ui, Some(false), f32::INFINITY, TextStyle::Heading);
+            let width = title_galley.size().x;
+            (TitleContent::Text(title_galley), width)
+        };
 
         let minimum_width = if collapsible || show_close_button {
             // If at least one button is shown we make room for both buttons (since title is centered):
-            2.0 * (pad + button_size.x + item_spacing.x) + title_galley.size().x
+            2.0 * (pad + button_size.x + item_spacing.x) + content_width
         } else {
-            pad + title_galley.size().x + pad
+            pad + content_width + pad
         };
         let min_rect = Rect::from_min_size(ui.min_rect().min, vec2(minimum_width, height));
         let id = ui.advance_cursor_after_rect(min_rect);
 
         TitleBar {
             id,
-            title_galley,
+            content,
             min_rect,
             rect: Rect::NAN, // Will be filled in later
         }
@@ -954,7 +1042,7 @@ fn show_title_bar(
     TitleBar { rect, ..title_bar }
 }
 
-impl TitleBar {
+impl<'a> TitleBar<'a> {
     /// Finishes painting of the title bar when the window content size already known.
     ///
     /// # Parameters
@@ -983,6 +1071,7 @@ impl TitleBar {
             self.rect.max.x = self.rect.max.x.max(content_response.rect.max.x);
         }
 
+        let show_close_button = open.is_some();
         if let Some(open) = open {
             // Add close button now that we know our full width:
             if self.close_button_ui(ui).clicked() {
@@ -991,15 +1080,45 @@ impl TitleBar {
         }
 
         let full_top_rect = Rect::from_x_y_ranges(self.rect.x_range(), self.min_rect.y_range());
-        let text_pos =
-            emath::align::center_size_in_rect(self.title_galley.size(), full_top_rect).left_top();
-        let text_pos = text_pos - self.title_galley.galley().rect.min.to_vec2();
-        let text_pos = text_pos - 1.5 * Vec2::Y; // HACK: center on x-height of text (looks better)
-        self.title_galley.paint_with_fallback_color(
-            ui.painter(),
-            text_pos,
-            ui.visuals().text_color(),
-        );
+
+        // Don't cover the close- and collapse buttons:
+        let mut double_click_rect = self.rect.shrink2(vec2(32.0, 0.0));
+
+        match self.content {
+            TitleContent::Text(title_galley) => {
+                let text_pos =
+                    emath::align::center_size_in_rect(title_galley.size(), full_top_rect)
+                        .left_top();
+                let text_pos = text_pos - title_galley.galley().rect.min.to_vec2();
+                let text_pos = text_pos - 1.5 * Vec2::Y; // HACK: center on x-height of text (looks better)
+                title_galley.paint_with_fallback_color(
+                    ui.painter(),
+                    text_pos,
+                    ui.visuals().text_color(),
+                );
+            }
+            TitleContent::Custom(add_contents) => {
+                let item_spacing = ui.spacing().item_spacing;
+                let button_width = ui.spacing().icon_width;
+                let pad = (full_top_rect.height() - button_width) / 2.0;
+                let button_space = pad + button_width + item_spacing.x;
+
+                let mut content_rect = full_top_rect;
+                content_rect.min.x += if collapsible { button_space } else { pad };
+                content_rect.max.x -= if show_close_button { button_space } else { pad };
+
+                let mut content_ui =
+                    ui.child_ui(content_rect, Layout::left_to_right(Align::Center));
+                content_ui.set_clip_rect(content_rect.intersect(ui.clip_rect()));
+                add_contents(&mut content_ui);
+
+                // Double-clicking the custom widgets should not collapse the window:
+                double_click_rect.min.x = double_click_rect
+                    .min
+                    .x
+                    .max(content_ui.min_rect().right() + item_spacing.x);
+            }
+        }
 
         if let Some(content_response) = &content_response {
             // paint separator between title and content:
@@ -1009,9 +1128,6 @@ impl TitleBar {
             ui.painter().hline(outer_rect.x_range(), y, stroke);
         }
 
-        // Don't cover the close- and collapse buttons:
-        let double_click_rect = self.rect.shrink2(vec2(32.0, 0.0));
-
         if ui
             .interact(double_click_rect, self.id, Sense::click())
             .double_clicked()
@@ -1054,6 +1170,7 @@ impl TitleBar {
 fn close_button(ui: &mut Ui, rect: Rect) -> Response {
     let close_id = ui.auto_id_with("window_close_button");
     let response = ui.interact(rect, close_id, Sense::click());
+    response.widget_info(|| WidgetInfo::labeled(WidgetType::Button, ui.ctx().translate("Close")));
     ui.expand_to_include_rect(response.rect);
 
     let visuals = ui.style().interact(&response);
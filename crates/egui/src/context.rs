@@ -15,6 +15,7 @@ use crate::{
     memory::Options,
     os::OperatingSystem,
     output::FullOutput,
+    quality_governor::QualityGovernor,
     util::IdTypeMap,
     viewport::ViewportClass,
     TextureHandle, ViewportCommand, *,
@@ -40,6 +41,39 @@ pub struct RequestRepaintInfo {
 
 // ----------------------------------------------------------------------------
 
+/// What called [`Context::request_repaint`]?
+///
+/// See [`Context::repaint_causes`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct RepaintCause {
+    /// What file had the call that requested the repaint?
+    pub file: &'static str,
+
+    /// What line number of the call that requested the repaint?
+    pub line: u32,
+}
+
+impl RepaintCause {
+    /// Capture the file and line number of the call site.
+    #[allow(clippy::new_without_default)]
+    #[track_caller]
+    pub fn new() -> Self {
+        let caller = std::panic::Location::caller();
+        Self {
+            file: caller.file(),
+            line: caller.line(),
+        }
+    }
+}
+
+impl std::fmt::Display for RepaintCause {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}", self.file, self.line)
+    }
+}
+
+// ----------------------------------------------------------------------------
+
 thread_local! {
     static IMMEDIATE_VIEWPORT_RENDERER: RefCell<Option<Box<ImmediateViewportRendererCallback>>> = Default::default();
 }
@@ -68,13 +102,22 @@ impl Default for WrappedTextureManager {
 
 /// Repaint-logic
 impl ContextImpl {
-    fn request_repaint(&mut self, viewport_id: ViewportId) {
-        self.request_repaint_after(Duration::ZERO, viewport_id);
+    fn request_repaint(&mut self, viewport_id: ViewportId, cause: RepaintCause) {
+        self.request_repaint_after(Duration::ZERO, viewport_id, cause);
     }
 
-    fn request_repaint_after(&mut self, delay: Duration, viewport_id: ViewportId) {
+    fn request_repaint_after(
+        &mut self,
+        delay: Duration,
+        viewport_id: ViewportId,
+        cause: RepaintCause,
+    ) {
         let viewport = self.viewports.entry(viewport_id).or_default();
 
+        if !viewport.repaint.causes.contains(&cause) {
+            viewport.repaint.causes.push(cause);
+        }
+
         // Each request results in two repaints, just to give some things time to settle.
         // This solves some corner-cases of missing repaints on frame-delayed responses.
         viewport.repaint.outstanding = 1;
@@ -137,11 +180,13 @@ struct ViewportState {
     /// Has this viewport been updated this frame?
     used: bool,
 
+    /// The interactive widgets of each layer, with their interaction priority, in the order they were added.
+    ///
     /// Written to during the frame.
-    layer_rects_this_frame: HashMap<LayerId, Vec<(Id, Rect)>>,
+    layer_rects_this_frame: HashMap<LayerId, Vec<(Id, Rect, i32)>>,
 
     /// Read
-    layer_rects_prev_frame: HashMap<LayerId, Vec<(Id, Rect)>>,
+    layer_rects_prev_frame: HashMap<LayerId, Vec<(Id, Rect, i32)>>,
 
     /// State related to repaint scheduling.
     repaint: ViewportRepaintInfo,
@@ -149,9 +194,14 @@ struct ViewportState {
     // ----------------------
     // The output of a frame:
     graphics: GraphicLayers,
+    /// Set by [`Context::capture_shapes`].
+    capture_shapes: bool,
+    captured_shapes: Option<Vec<LayerShapes>>,
     // Most of the things in `PlatformOutput` are not actually viewport dependent.
     output: PlatformOutput,
     commands: Vec<ViewportCommand>,
+    /// Hash of what was painted last frame, if [`Options::detect_unchanged_frames`] is set.
+    prev_frame_hash: Option<u64>,
 }
 
 /// Per-viewport state related to repaint scheduling.
@@ -172,6 +222,12 @@ struct ViewportRepaintInfo {
 
     /// Did we?
     requested_last_frame: bool,
+
+    /// What called [`Context::request_repaint`] since the start of the current frame?
+    causes: Vec<RepaintCause>,
+
+    /// What called [`Context::request_repaint`] during the previous frame (or between frames)?
+    prev_causes: Vec<RepaintCause>,
 }
 
 impl Default for ViewportRepaintInfo {
@@ -186,6 +242,9 @@ impl Default for ViewportRepaintInfo {
             outstanding: 1,
 
             requested_last_frame: false,
+
+            causes: Default::default(),
+            prev_causes: Default::default(),
         }
     }
 }
@@ -199,6 +258,7 @@ struct ContextImpl {
     memory: Memory,
     animation_manager: AnimationManager,
     tex_manager: WrappedTextureManager,
+    quality_governor: QualityGovernor,
 
     /// Set during the frame, becomes active at the start of the next frame.
     new_zoom_factor: Option<f32>,
@@ -215,6 +275,8 @@ struct ContextImpl {
 
     request_repaint_callback: Option<Box<dyn Fn(RequestRepaintInfo) + Send + Sync>>,
 
+    localizer: Option<Box<dyn Localizer>>,
+
     viewport_parents: ViewportIdMap<ViewportId>,
     viewports: ViewportIdMap<ViewportState>,
 
@@ -228,6 +290,18 @@ struct ContextImpl {
     loaders: Arc<Loaders>,
 }
 
+/// Guess the native pixels-per-point when the integration has never told us.
+fn guess_native_pixels_per_point(info: &ViewportInfo) -> Option<f32> {
+    // Without a known scale, the monitor size is reported in physical pixels.
+    // Most monitors that are 4K or larger are used with a scale factor of 2:
+    let monitor_size = info.monitor_size?;
+    Some(if monitor_size.min_elem() >= 2160.0 {
+        2.0
+    } else {
+        1.0
+    })
+}
+
 impl ContextImpl {
     fn begin_frame_mut(&mut self, mut new_raw_input: RawInput) {
         let viewport_id = new_raw_input.viewport_id;
@@ -242,6 +316,9 @@ impl ContextImpl {
         self.viewport_stack.push(ids);
         let viewport = self.viewports.entry(viewport_id).or_default();
 
+        viewport.repaint.prev_causes = std::mem::take(&mut viewport.repaint.causes);
+        viewport.repaint.requested_last_frame = 0 < viewport.repaint.outstanding;
+
         if viewport.repaint.outstanding == 0 {
             // We are repainting now, so we can wait a while for the next repaint.
             viewport.repaint.repaint_delay = Duration::MAX;
@@ -272,12 +349,34 @@ impl ContextImpl {
                 // but the `screen_rect` is the most important part.
             }
         }
+
+        // Not all integrations know the native scale factor at all times (e.g. before the window is shown),
+        // and some don't send any `ViewportInfo` at all.
+        // Rather than snapping back to 1.0 we keep using what we used before:
+        let info = new_raw_input.viewports.entry(viewport_id).or_default();
+        if info.native_pixels_per_point.is_none() {
+            let last_known = |id: ViewportId| {
+                self.viewports
+                    .get(&id)
+                    .and_then(|viewport| viewport.input.raw.viewports.get(&id))
+                    .and_then(|info| info.native_pixels_per_point)
+            };
+            info.native_pixels_per_point = last_known(viewport_id)
+                .or_else(|| last_known(parent_id))
+                .or_else(|| last_known(ViewportId::ROOT))
+                .or_else(|| guess_native_pixels_per_point(info));
+        }
+
         let pixels_per_point = self.memory.options.zoom_factor
             * new_raw_input
                 .viewport()
                 .native_pixels_per_point
                 .unwrap_or(1.0);
 
+        let viewport = self.viewports.entry(viewport_id).or_default();
+        let pixels_per_point_changed = viewport.repaint.frame_nr > 0
+            && (viewport.input.pixels_per_point() - pixels_per_point).abs() > 1e-3;
+
         viewport.layer_rects_prev_frame = std::mem::take(&mut viewport.layer_rects_this_frame);
 
         let all_viewport_ids: ViewportIdSet = self.all_viewport_ids();
@@ -295,6 +394,15 @@ impl ContextImpl {
 
         viewport.frame_state.begin_frame(&viewport.input);
 
+        // The time between frames is only a measure of how long a frame takes
+        // if we were repainting continuously:
+        let frame_time = viewport
+            .repaint
+            .requested_last_frame
+            .then_some(viewport.input.unstable_dt);
+        self.quality_governor
+            .on_frame(self.memory.options.frame_time_budget, frame_time);
+
         // Ensure we register the background area so panels and background ui can catch clicks:
         let screen_rect = viewport.input.screen_rect();
         self.memory.areas_mut().set_state(
@@ -324,6 +432,13 @@ impl ContextImpl {
         }
 
         self.update_fonts_mut();
+
+        if pixels_per_point_changed {
+            // The fonts and all cached galleys were just re-created for the new scale,
+            // but the sizes and hit-test rectangles from last frame were computed with the old one.
+            // Do another pass so that everything settles (e.g. when moving a window to another monitor):
+            self.request_repaint(viewport_id, RepaintCause::new());
+        }
     }
 
     /// Load fonts unless already loaded.
@@ -708,9 +823,56 @@ impl Context {
         self.write(move |ctx| writer(&mut ctx.memory.options.tessellation_options))
     }
 
+    /// Does a [`crate::TextEdit`] have keyboard focus?
+    ///
+    /// Keyboard shortcuts of widgets should then be ignored, since the keys are meant for the text.
+    pub(crate) fn text_edit_has_focus(&self) -> bool {
+        self.memory(|mem| mem.focus()).map_or(false, |id| {
+            crate::text_edit::TextEditState::load(self, id).is_some()
+        })
+    }
+
+    /// Is egui currently reducing the tessellation quality to stay within [`Options::frame_time_budget`]?
+    ///
+    /// You can use this to also skip expensive painting of your own.
+    pub fn is_quality_reduced(&self) -> bool {
+        self.read(|ctx| ctx.quality_governor.is_reduced())
+    }
+
+    /// How detailed the shadows of frames should be, see [`Options::frame_time_budget`].
+    pub(crate) fn shadow_circle_detail(&self) -> f32 {
+        if self.is_quality_reduced() {
+            crate::quality_governor::REDUCED_CIRCLE_DETAIL
+        } else {
+            1.0
+        }
+    }
+
+    /// Give the [`Id`] a human-readable name, shown if the [`Id`] clashes with another.
+    ///
+    /// Does nothing unless [`crate::Options::retain_id_names`] is set (it is by default in debug builds).
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let id = egui::Id::new(("my_plot", 3));
+    /// ui.ctx().set_id_name(id, "Plot #3");
+    /// # });
+    /// ```
+    pub fn set_id_name(&self, id: Id, name: &str) {
+        self.memory_mut(|mem| mem.set_id_name(id, name));
+    }
+
+    /// The name given to the [`Id`] with [`Self::set_id_name`], if any.
+    pub fn id_name(&self, id: Id) -> Option<String> {
+        self.memory(|mem| mem.id_name(id).map(ToOwned::to_owned))
+    }
+
     /// If the given [`Id`] has been used previously the same frame at at different position,
     /// then an error will be printed on screen.
     ///
+    /// The error includes the name of the [`Id`], if one was given with [`Self::set_id_name`].
+    /// [`Id::NULL`] is never considered to clash.
+    ///
     /// This function is already called for all widgets that do any interaction,
     /// but you can call this from widgets that store state but that does not interact.
     ///
@@ -718,6 +880,10 @@ impl Context {
     /// The most important thing is that [`Rect::min`] is approximately correct,
     /// because that's where the warning will be painted. If you don't know what size to pick, just pick [`Vec2::ZERO`].
     pub fn check_for_id_clash(&self, id: Id, new_rect: Rect, what: &str) {
+        if id == Id::NULL {
+            return; // Intentionally anonymous
+        }
+
         let prev_rect = self.frame_state_mut(move |state| state.used_ids.insert(id, new_rect));
 
         if !self.options(|opt| opt.warn_on_id_clash) {
@@ -778,7 +944,10 @@ impl Context {
             }
         };
 
-        let id_str = id.short_debug_format();
+        let id_str = match self.id_name(id) {
+            Some(name) => format!("{} {name:?}", id.short_debug_format()),
+            None => id.short_debug_format(),
+        };
 
         if prev_rect.min.distance(new_rect.min) < 4.0 {
             show_error(new_rect, format!("Double use of {what} ID {id_str}"));
@@ -795,28 +964,33 @@ impl Context {
     pub(crate) fn interact(
         &self,
         clip_rect: Rect,
-        item_spacing: Vec2,
+        spacing: &style::Spacing,
         layer_id: LayerId,
         id: Id,
         rect: Rect,
         sense: Sense,
         enabled: bool,
+        priority: i32,
     ) -> Response {
         let gap = 0.1; // Just to make sure we don't accidentally hover two things at once (a small eps should be sufficient).
 
         // Make it easier to click things:
-        let interact_rect = rect.expand2(
-            (0.5 * item_spacing - Vec2::splat(gap))
-                .at_least(Vec2::splat(0.0))
-                .at_most(Vec2::splat(5.0)),
-        );
+        let spacing_margin = (0.5 * spacing.item_spacing - Vec2::splat(gap))
+            .at_least(Vec2::splat(0.0))
+            .at_most(Vec2::splat(5.0));
+
+        // Make small things big enough to hit with a finger:
+        let touch_margin = (0.5 * (spacing.min_touch_target - rect.size())).at_least(Vec2::ZERO);
+
+        let interact_rect = rect.expand2(spacing_margin.max(touch_margin));
 
         // Respect clip rectangle when interacting
         let interact_rect = clip_rect.intersect(interact_rect);
         let mut hovered = self.rect_contains_pointer(layer_id, interact_rect);
 
         // This solves the problem of overlapping widgets.
-        // Whichever widget is added LAST (=on top) gets the input:
+        // Whichever widget has the highest priority gets the input,
+        // and of those, whichever is added LAST (=on top). See `Ui::interaction_priority`.
         if interact_rect.is_positive() && sense.interactive() {
             #[cfg(debug_assertions)]
             if self.style().debug.show_interactive_widgets {
@@ -831,6 +1005,8 @@ impl Context {
             #[cfg(debug_assertions)]
             let mut show_blocking_widget = None;
 
+            let layer_transform = self.layer_transform(layer_id);
+
             self.write(|ctx| {
                 let viewport = ctx.viewport();
 
@@ -838,17 +1014,23 @@ impl Context {
                     .layer_rects_this_frame
                     .entry(layer_id)
                     .or_default()
-                    .push((id, interact_rect));
+                    .push((id, interact_rect, priority));
 
                 if hovered {
-                    let pointer_pos = viewport.input.pointer.interact_pos();
+                    let pointer_pos = viewport.input.pointer.interact_pos().map(|pos| {
+                        layer_transform.map_or(pos, |transform| transform.inverse() * pos)
+                    });
                     if let Some(pointer_pos) = pointer_pos {
                         if let Some(rects) = viewport.layer_rects_prev_frame.get(&layer_id) {
-                            for &(prev_id, prev_rect) in rects.iter().rev() {
-                                if prev_id == id {
-                                    break; // there is no other interactive widget covering us at the pointer position.
-                                }
-                                if prev_rect.contains(pointer_pos) {
+                            // Widgets we didn't see last frame are treated as if added first:
+                            let our_index = rects.iter().position(|(prev_id, ..)| *prev_id == id);
+                            for (index, &(prev_id, prev_rect, prev_priority)) in
+                                rects.iter().enumerate()
+                            {
+                                let is_above_us = prev_priority > priority
+                                    || (prev_priority == priority
+                                        && our_index.map_or(true, |our_index| our_index < index));
+                                if prev_id != id && is_above_us && prev_rect.contains(pointer_pos) {
                                     // Another interactive widget is covering us at the pointer position,
                                     // so we aren't hovered.
 
@@ -915,13 +1097,21 @@ impl Context {
             dragged: false,
             drag_released: false,
             is_pointer_button_down_on: false,
+            long_touched: false,
             interact_pointer_pos: None,
-            changed: false, // must be set by the widget itself
+            changed: false,       // must be set by the widget itself
+            intrinsic_size: None, // set by the widget itself, if it knows
         };
 
-        if !enabled || !sense.focusable || !layer_id.allow_interaction() {
+        if !enabled || !sense.focusable || !layer_id.allow_interaction() || id == Id::NULL {
             // Not interested or allowed input:
-            self.memory_mut(|mem| mem.surrender_focus(id));
+            let time = self.input(|i| i.time);
+            self.memory_mut(|mem| {
+                mem.surrender_focus(id);
+                if hovered {
+                    mem.interaction_mut().set_hovered(id, time);
+                }
+            });
             return response;
         }
 
@@ -936,12 +1126,13 @@ impl Context {
         }
 
         let clicked_elsewhere = response.clicked_elsewhere();
+        let mut long_touch_repaint_delay = None;
         self.write(|ctx| {
             let input = &ctx.viewports.entry(ctx.viewport_id()).or_default().input;
             let memory = &mut ctx.memory;
 
             if sense.focusable {
-                memory.interested_in_focus(id);
+                memory.interested_in_focus_on_layer(id, layer_id);
             }
 
             if sense.click
@@ -1020,8 +1211,30 @@ impl Context {
                 }
             }
 
+            if sense.click
+                && response.is_pointer_button_down_on
+                && input.any_touches()
+                && !input.pointer.has_moved_too_much_for_a_click
+            {
+                if let Some(press_start_time) = input.pointer.press_start_time() {
+                    // Holding a finger still for longer than a click is a long-press,
+                    // which is the touch equivalent of a secondary click:
+                    let press_duration = input.time - press_start_time;
+                    if press_duration < MAX_CLICK_DURATION {
+                        long_touch_repaint_delay = Some(MAX_CLICK_DURATION - press_duration);
+                    } else if press_duration - (input.unstable_dt as f64) < MAX_CLICK_DURATION {
+                        response.long_touched = true;
+                        response.clicked[PointerButton::Secondary as usize] = true;
+                    }
+                }
+            }
+
             if response.is_pointer_button_down_on {
-                response.interact_pointer_pos = input.pointer.interact_pos();
+                let layer_transform = memory.areas().layer_transform(layer_id);
+                response.interact_pointer_pos = input
+                    .pointer
+                    .interact_pos()
+                    .map(|pos| layer_transform.map_or(pos, |transform| transform.inverse() * pos));
             }
 
             if input.pointer.any_down() {
@@ -1036,8 +1249,16 @@ impl Context {
                 // e.g.: remove focus from a widget when you drag something else
                 memory.stop_text_input();
             }
+
+            if response.hovered {
+                memory.interaction_mut().set_hovered(id, input.time);
+            }
         });
 
+        if let Some(delay) = long_touch_repaint_delay {
+            self.request_repaint_after(Duration::from_secs_f64(delay));
+        }
+
         response
     }
 
@@ -1107,6 +1328,19 @@ impl Context {
         self.output_mut(|o| o.copied_text = text);
     }
 
+    /// Copy the widget hierarchy of the next frame to the system clipboard, as indented text.
+    ///
+    /// Each line has the type, [`Id`] and rect of a [`Ui`] or widget,
+    /// plus the text and value of widgets that describe themselves with [`Response::widget_info`].
+    /// This is useful to attach to bug reports.
+    ///
+    /// The next frame is recorded in full, and copied at the end of it.
+    /// A repaint is requested, so you don't need to wait for one.
+    pub fn copy_widget_tree(&self) {
+        self.frame_state_mut(|fs| fs.record_widget_tree_next_frame = true);
+        self.request_repaint();
+    }
+
     /// Format the given shortcut in a human-readable way (e.g. `Ctrl+Shift+X`).
     ///
     /// Can be used to get the text for [`Button::shortcut_text`].
@@ -1170,6 +1404,7 @@ impl Context {
     /// (this will work on `eframe`).
     ///
     /// This will repaint the current viewport
+    #[track_caller]
     pub fn request_repaint(&self) {
         self.request_repaint_of(self.viewport_id());
     }
@@ -1184,8 +1419,10 @@ impl Context {
     /// (this will work on `eframe`).
     ///
     /// This will repaint the specified viewport
+    #[track_caller]
     pub fn request_repaint_of(&self, id: ViewportId) {
-        self.write(|ctx| ctx.request_repaint(id));
+        let cause = RepaintCause::new();
+        self.write(|ctx| ctx.request_repaint(id, cause));
     }
 
     /// Request repaint after at most the specified duration elapses.
@@ -1216,6 +1453,7 @@ impl Context {
     /// during app idle time where we are not receiving any new input events.
     ///
     /// This repaints the current viewport
+    #[track_caller]
     pub fn request_repaint_after(&self, duration: Duration) {
         self.request_repaint_after_for(duration, self.viewport_id());
     }
@@ -1248,8 +1486,10 @@ impl Context {
     /// during app idle time where we are not receiving any new input events.
     ///
     /// This repaints the specified viewport
+    #[track_caller]
     pub fn request_repaint_after_for(&self, duration: Duration, id: ViewportId) {
-        self.write(|ctx| ctx.request_repaint_after(duration, id));
+        let cause = RepaintCause::new();
+        self.write(|ctx| ctx.request_repaint_after(duration, id, cause));
     }
 
     /// Was a repaint requested last frame for the current viewport?
@@ -1276,6 +1516,21 @@ impl Context {
         self.read(|ctx| ctx.has_requested_repaint(viewport_id))
     }
 
+    /// What called [`Self::request_repaint`] or [`Self::request_repaint_after`]
+    /// during the previous frame of the current viewport (or in between frames)?
+    ///
+    /// This tells you why this frame is being painted,
+    /// unless it was because of new input.
+    /// Can be shown with [`crate::style::DebugOptions::show_repaint_causes`].
+    pub fn repaint_causes(&self) -> Vec<RepaintCause> {
+        self.read(|ctx| {
+            ctx.viewports
+                .get(&ctx.viewport_id())
+                .map(|v| v.repaint.prev_causes.clone())
+                .unwrap_or_default()
+        })
+    }
+
     /// For integrations: this callback will be called when an egui user calls [`Self::request_repaint`] or [`Self::request_repaint_after`].
     ///
     /// This lets you wake up a sleeping UI thread.
@@ -1289,6 +1544,41 @@ impl Context {
         self.write(|ctx| ctx.request_repaint_callback = Some(callback));
     }
 
+    /// Translate the strings that egui shows on its own, e.g. for non-English apps.
+    ///
+    /// See [`Localizer`] for an example.
+    /// Any new call overrides the previous localizer.
+    pub fn set_localizer(&self, localizer: impl Localizer + 'static) {
+        let localizer = Box::new(localizer);
+        self.write(|ctx| ctx.localizer = Some(localizer));
+    }
+
+    /// Translate a string that egui shows on its own, using the [`Localizer`] set with [`Self::set_localizer`].
+    ///
+    /// Returns the English `text` as-is if there is no translation.
+    pub fn translate(&self, text: &str) -> String {
+        self.read(|ctx| ctx.localizer.as_ref()?.translate(text))
+            .unwrap_or_else(|| text.to_owned())
+    }
+
+    /// Translate a string that depends on `count`, given as its English singular and plural forms,
+    /// using the [`Localizer`] set with [`Self::set_localizer`].
+    ///
+    /// Any `{count}` in the result is replaced by `count`.
+    ///
+    /// ```
+    /// # let ctx = egui::Context::default();
+    /// assert_eq!(ctx.translate_plural(1, "{count} item", "{count} items"), "1 item");
+    /// assert_eq!(ctx.translate_plural(3, "{count} item", "{count} items"), "3 items");
+    /// ```
+    pub fn translate_plural(&self, count: u64, one: &str, other: &str) -> String {
+        let english = if count == 1 { one } else { other };
+        let text = self
+            .read(|ctx| ctx.localizer.as_ref()?.translate_plural(count, one, other))
+            .unwrap_or_else(|| english.to_owned());
+        str::replace(&text, "{count}", &count.to_string())
+    }
+
     /// Tell `egui` which fonts to use.
     ///
     /// The default `egui` fonts only support latin and cyrillic alphabets,
@@ -1361,10 +1651,16 @@ impl Context {
     /// Set the number of physical pixels for each logical point.
     /// Will become active at the start of the next frame.
     ///
-    /// This will actually translate to a call to [`Self::set_zoom_factor`].
+    /// This will actually translate to a call to [`Self::set_zoom_factor`],
+    /// so the override follows the window if it is moved to a monitor with a different scale.
+    ///
+    /// Whenever the pixels-per-point changes, the font atlas and all cached galleys are re-created
+    /// and another frame is requested, so text stays crisp and hit-testing stays correct.
     pub fn set_pixels_per_point(&self, pixels_per_point: f32) {
         if pixels_per_point != self.pixels_per_point() {
-            self.set_zoom_factor(pixels_per_point / self.native_pixels_per_point().unwrap_or(1.0));
+            // Use the native scale we actually used this frame, which may be a fallback:
+            let native_pixels_per_point = self.pixels_per_point() / self.zoom_factor();
+            self.set_zoom_factor(pixels_per_point / native_pixels_per_point);
         }
     }
 
@@ -1372,6 +1668,12 @@ impl Context {
     ///
     /// This is given as input to egui via [`ViewportInfo::native_pixels_per_point`]
     /// and cannot be changed.
+    ///
+    /// If the integration doesn't know it for a frame, egui keeps using the value from
+    /// the previous frame of this viewport, or else the one of its parent or of the root viewport.
+    /// If it has never been known, egui guesses it from [`ViewportInfo::monitor_size`]
+    /// (`2.0` for 4K monitors and larger, otherwise `1.0`).
+    /// `None` means there was nothing to go on, in which case `1.0` is used.
     #[inline(always)]
     pub fn native_pixels_per_point(&self) -> Option<f32> {
         self.input(|i| i.viewport().native_pixels_per_point)
@@ -1404,11 +1706,12 @@ impl Context {
     /// [`Options::zoom_factor`].
     #[inline(always)]
     pub fn set_zoom_factor(&self, zoom_factor: f32) {
+        let cause = RepaintCause::new();
         self.write(|ctx| {
             if ctx.memory.options.zoom_factor != zoom_factor {
                 ctx.new_zoom_factor = Some(zoom_factor);
                 for id in ctx.all_viewport_ids() {
-                    ctx.request_repaint(id);
+                    ctx.request_repaint(id, cause.clone());
                 }
             }
         });
@@ -1560,10 +1863,55 @@ impl Context {
             crate::gui_zoom::zoom_with_keyboard(self);
         }
 
+        crate::DragAndDrop::end_frame(self);
+
+        let is_pointer_over_area = self.is_pointer_over_area();
+        let wants_pointer_input = self.wants_pointer_input();
+        let wants_keyboard_input = self.wants_keyboard_input();
+        self.output_mut(|o| {
+            o.is_pointer_over_area = is_pointer_over_area;
+            o.wants_pointer_input = wants_pointer_input;
+            o.wants_keyboard_input = wants_keyboard_input;
+        });
+
+        #[cfg(debug_assertions)]
+        if self.style().debug.show_repaint_causes {
+            self.paint_repaint_causes();
+        }
+
+        if let Some(widget_tree) = self.frame_state_mut(|fs| fs.widget_tree.take()) {
+            self.copy_text(widget_tree.to_string());
+        }
+
         self.write(|ctx| ctx.end_frame())
     }
 }
 
+impl Context {
+    /// Shown with [`crate::style::DebugOptions::show_repaint_causes`].
+    #[cfg(debug_assertions)]
+    fn paint_repaint_causes(&self) {
+        let mut text = format!("Frame #{}", self.frame_nr());
+
+        let num_events = self.input(|i| i.raw.events.len());
+        if num_events > 0 {
+            text += &format!("\nInput events: {num_events}");
+        }
+
+        let causes = self.repaint_causes();
+        if !causes.is_empty() {
+            text += "\nRepaint requested by:";
+            for cause in causes {
+                text += &format!("\n  {cause}");
+            }
+        }
+
+        let pos = self.screen_rect().right_top() + vec2(-4.0, 4.0);
+        self.debug_painter()
+            .debug_text(pos, Align2::RIGHT_TOP, Color32::WHITE, text);
+    }
+}
+
 impl ContextImpl {
     fn end_frame(&mut self) -> FullOutput {
         let ended_viewport_id = self.viewport_id();
@@ -1615,12 +1963,78 @@ impl ContextImpl {
             }
         }
 
-        let shapes = viewport.graphics.drain(self.memory.areas().order());
+        let mut shapes: Vec<ClippedShape> = vec![];
+        let mut shape_layers = vec![];
+        let mut blur_behind = std::mem::take(&mut viewport.frame_state.blur_behind);
+        {
+            let areas = self.memory.areas();
+            for blur in &mut blur_behind {
+                if let Some(transform) = areas.layer_transforms().get(&blur.layer_id) {
+                    let Rounding { nw, ne, sw, se } = blur.rounding;
+                    let s = transform.scaling;
+                    blur.rect = *transform * blur.rect;
+                    blur.rounding = Rounding {
+                        nw: s * nw,
+                        ne: s * ne,
+                        sw: s * sw,
+                        se: s * se,
+                    };
+                }
+            }
+            let layers = viewport.graphics.drain(
+                areas.order(),
+                areas.layer_transforms(),
+                areas.layer_opacities(),
+            );
+            if std::mem::take(&mut viewport.capture_shapes) {
+                viewport.captured_shapes = Some(layers.clone());
+            }
+            for layer in layers {
+                let start = shapes.len();
+                shapes.extend(layer.shapes);
+                shape_layers.push((layer.layer_id, start..shapes.len()));
+            }
+        }
+        blur_behind.sort_by_key(|blur| {
+            shape_layers
+                .iter()
+                .position(|(layer_id, _)| *layer_id == blur.layer_id)
+        });
 
         if viewport.input.wants_repaint() {
-            self.request_repaint(ended_viewport_id);
+            self.request_repaint(ended_viewport_id, RepaintCause::new());
         }
 
+        if !self.has_requested_repaint(&ended_viewport_id) {
+            // This frame will be shown for a while, so tessellate it at the full quality:
+            self.quality_governor.on_idle();
+        }
+
+        // The quality governor may have changed the options, so do this after the above.
+        let tessellation_options = self
+            .quality_governor
+            .tessellation_options(self.memory.options.tessellation_options);
+
+        let viewport = self.viewports.entry(ended_viewport_id).or_default();
+        let unchanged = if self.memory.options.detect_unchanged_frames {
+            crate::profile_scope!("frame_hash");
+            let hash = frame_hash(
+                &shapes,
+                &blur_behind,
+                &tessellation_options,
+                pixels_per_point,
+                viewport.input.screen_rect,
+            );
+            let unchanged = viewport.prev_frame_hash == Some(hash)
+                && textures_delta.is_empty()
+                && !shapes.iter().any(|s| contains_callback(&s.shape));
+            viewport.prev_frame_hash = Some(hash);
+            unchanged
+        } else {
+            viewport.prev_frame_hash = None;
+            false
+        };
+
         //  -------------------
 
         let all_viewport_ids = self.all_viewport_ids();
@@ -1709,12 +2123,111 @@ impl ContextImpl {
             platform_output,
             textures_delta,
             shapes,
+            shape_layers,
+            blur_behind,
             pixels_per_point,
             viewport_output,
+            unchanged,
         }
     }
 }
 
+/// Hash everything that affects what a frame looks like on screen.
+fn frame_hash(
+    shapes: &[ClippedShape],
+    blur_behind: &[crate::output::BlurBehind],
+    tessellation_options: &TessellationOptions,
+    pixels_per_point: f32,
+    screen_rect: Rect,
+) -> u64 {
+    let rect_bits = |rect: Rect| [rect.min.x, rect.min.y, rect.max.x, rect.max.y].map(f32::to_bits);
+    let blur_behind: Vec<_> = blur_behind
+        .iter()
+        .map(|blur| (blur.layer_id, rect_bits(blur.rect), blur.rounding))
+        .collect();
+
+    // Destructure so we don't forget to hash new options:
+    let TessellationOptions {
+        feathering,
+        feathering_size_in_pixels,
+        coarse_tessellation_culling,
+        prerasterized_discs,
+        round_text_to_pixels,
+        round_line_segments_to_pixels,
+        debug_paint_clip_rects,
+        debug_paint_text_rects,
+        debug_ignore_clip_rects,
+        circle_detail,
+        bezier_tolerance,
+        epsilon,
+    } = *tessellation_options;
+    let tessellation_options = (
+        [
+            feathering,
+            coarse_tessellation_culling,
+            prerasterized_discs,
+            round_text_to_pixels,
+            round_line_segments_to_pixels,
+            debug_paint_clip_rects,
+            debug_paint_text_rects,
+            debug_ignore_clip_rects,
+        ],
+        [
+            feathering_size_in_pixels,
+            circle_detail,
+            bezier_tolerance,
+            epsilon,
+        ]
+        .map(f32::to_bits),
+    );
+
+    epaint::util::hash((
+        shapes,
+        blur_behind,
+        tessellation_options,
+        pixels_per_point.to_bits(),
+        rect_bits(screen_rect),
+    ))
+}
+
+fn contains_callback(shape: &Shape) -> bool {
+    match shape {
+        Shape::Callback(_) => true,
+        Shape::Vec(shapes) => shapes.iter().any(contains_callback),
+        _ => false,
+    }
+}
+
+impl ContextImpl {
+    fn tessellate(
+        &mut self,
+        shapes: Vec<ClippedShape>,
+        pixels_per_point: f32,
+    ) -> Vec<ClippedPrimitive> {
+        let tessellation_options = self
+            .quality_governor
+            .tessellation_options(self.memory.options.tessellation_options);
+        let texture_atlas = self
+            .fonts
+            .as_ref()
+            .expect("tessellate called before first call to Context::run()")
+            .texture_atlas();
+        let (font_tex_size, prepared_discs) = {
+            let atlas = texture_atlas.lock();
+            (atlas.size(), atlas.prepared_discs())
+        };
+
+        crate::profile_scope!("tessellator::tessellate_shapes");
+        tessellator::tessellate_shapes(
+            pixels_per_point,
+            tessellation_options,
+            font_tex_size,
+            prepared_discs,
+            shapes,
+        )
+    }
+}
+
 impl Context {
     /// Tessellate the given shapes into triangle meshes.
     ///
@@ -1733,33 +2246,101 @@ impl Context {
         // it takes to tessellate them, so it is not a worth optimization.
 
         self.write(|ctx| {
-            let tessellation_options = ctx.memory.options.tessellation_options;
-            let texture_atlas = ctx
-                .fonts
-                .as_ref()
-                .expect("tessellate called before first call to Context::run()")
-                .texture_atlas();
-            let (font_tex_size, prepared_discs) = {
-                let atlas = texture_atlas.lock();
-                (atlas.size(), atlas.prepared_discs())
-            };
-
             let paint_stats = PaintStats::from_shapes(&shapes);
-            let clipped_primitives = {
-                crate::profile_scope!("tessellator::tessellate_shapes");
-                tessellator::tessellate_shapes(
-                    pixels_per_point,
-                    tessellation_options,
-                    font_tex_size,
-                    prepared_discs,
-                    shapes,
-                )
-            };
+            let clipped_primitives = ctx.tessellate(shapes, pixels_per_point);
             ctx.paint_stats = paint_stats.with_clipped_primitives(&clipped_primitives);
             clipped_primitives
         })
     }
 
+    /// Like [`Self::tessellate`], but keeps the meshes of each layer apart,
+    /// so you know which layer (e.g. which [`Window`]) each mesh belongs to.
+    ///
+    /// This is useful for per-layer effects, like blurring what is behind a window.
+    /// Pass in [`FullOutput::shapes`] and [`FullOutput::shape_layers`].
+    /// Use [`ClippedPrimitive::scissor_rect`] to get the clip rectangles in physical pixels.
+    ///
+    /// ```
+    /// let ctx = egui::Context::default();
+    /// let full_output = ctx.run(Default::default(), |ctx| {
+    ///     egui::Window::new("Window").show(ctx, |ui| {
+    ///         ui.label("Hello");
+    ///     });
+    /// });
+    /// let layers = ctx.tessellate_layers(
+    ///     full_output.shapes,
+    ///     &full_output.shape_layers,
+    ///     full_output.pixels_per_point,
+    /// );
+    /// for layer in layers {
+    ///     for primitive in layer.primitives {
+    ///         let scissor_rect = primitive.scissor_rect(full_output.pixels_per_point, [1024, 768]);
+    ///         // Paint `primitive.primitive` within `scissor_rect`…
+    ///     }
+    /// }
+    /// ```
+    pub fn tessellate_layers(
+        &self,
+        shapes: Vec<ClippedShape>,
+        shape_layers: &[(LayerId, std::ops::Range<usize>)],
+        pixels_per_point: f32,
+    ) -> Vec<LayerPrimitives> {
+        crate::profile_function!();
+
+        self.write(|ctx| {
+            let mut paint_stats = PaintStats::from_shapes(&shapes);
+            let mut shapes: Vec<Option<ClippedShape>> = shapes.into_iter().map(Some).collect();
+            let mut layers = Vec::with_capacity(shape_layers.len());
+            for (layer_id, range) in shape_layers {
+                let layer_shapes = shapes
+                    .get_mut(range.clone())
+                    .into_iter()
+                    .flatten()
+                    .filter_map(Option::take)
+                    .collect();
+                let primitives = ctx.tessellate(layer_shapes, pixels_per_point);
+                paint_stats = paint_stats.with_clipped_primitives(&primitives);
+                layers.push(LayerPrimitives {
+                    layer_id: *layer_id,
+                    primitives,
+                });
+            }
+            ctx.paint_stats = paint_stats;
+            layers
+        })
+    }
+
+    /// Capture the shapes of this frame, layer by layer.
+    ///
+    /// When the frame ends, the shapes of each layer are stored (in paint order),
+    /// and you can get them with [`Self::take_captured_shapes`].
+    /// This is useful for exporting a frame (e.g. as SVG), or for comparing frames in tests.
+    ///
+    /// This only captures the current frame, of the current viewport.
+    /// To change what is painted, modify [`FullOutput::shapes`] before calling [`Self::tessellate`].
+    ///
+    /// ```
+    /// let ctx = egui::Context::default();
+    /// let _ = ctx.run(Default::default(), |ctx| {
+    ///     ctx.capture_shapes();
+    ///     egui::CentralPanel::default().show(ctx, |ui| {
+    ///         ui.label("Hello");
+    ///     });
+    /// });
+    /// let layers = ctx.take_captured_shapes().unwrap();
+    /// assert!(layers.iter().any(|layer| !layer.shapes.is_empty()));
+    /// ```
+    pub fn capture_shapes(&self) {
+        self.write(|ctx| ctx.viewport().capture_shapes = true);
+    }
+
+    /// The shapes captured with [`Self::capture_shapes`], if any.
+    ///
+    /// Returns `None` if no frame has been captured since the last call.
+    pub fn take_captured_shapes(&self) -> Option<Vec<LayerShapes>> {
+        self.write(|ctx| ctx.viewport().captured_shapes.take())
+    }
+
     // ---------------------------------------------------------------------
 
     /// Position and size of the egui area.
@@ -1798,15 +2379,22 @@ impl Context {
     /// Is the pointer (mouse/touch) over any egui area?
     pub fn is_pointer_over_area(&self) -> bool {
         let pointer_pos = self.input(|i| i.pointer.interact_pos());
-        if let Some(pointer_pos) = pointer_pos {
-            if let Some(layer) = self.layer_id_at(pointer_pos) {
-                if layer.order == Order::Background {
-                    !self.frame_state(|state| state.unused_rect.contains(pointer_pos))
-                } else {
-                    true
-                }
+        pointer_pos.map_or(false, |pos| self.hit_test(pos))
+    }
+
+    /// Is the given screen position over any egui area,
+    /// i.e. over a window, a panel or a widget in the background?
+    ///
+    /// This is cheap, so integrations (e.g. games) can call it for any position
+    /// to decide whether input there should go to egui or to something else.
+    ///
+    /// See also [`Self::is_pointer_over_area`] and [`PlatformOutput::is_pointer_over_area`].
+    pub fn hit_test(&self, pos: Pos2) -> bool {
+        if let Some(layer) = self.layer_id_at(pos) {
+            if layer.order == Order::Background {
+                !self.frame_state(|state| state.unused_rect.contains(pos))
             } else {
-                false
+                true
             }
         } else {
             false
@@ -1815,13 +2403,15 @@ impl Context {
 
     /// True if egui is currently interested in the pointer (mouse or touch).
     ///
-    /// Could be the pointer is hovering over a [`Window`] or the user is dragging a widget.
+    /// Could be the pointer is hovering over a [`Window`], the user is dragging a widget,
+    /// or a menu or popup is open (which a click anywhere will close).
     /// If `false`, the pointer is outside of any egui area and so
     /// you may be interested in what it is doing (e.g. controlling your game).
     /// Returns `false` if a drag started outside of egui and then moved over an egui area.
     pub fn wants_pointer_input(&self) -> bool {
         self.is_using_pointer()
             || (self.is_pointer_over_area() && !self.input(|i| i.pointer.any_down()))
+            || self.is_menu_or_popup_open()
     }
 
     /// Is egui currently using the pointer position (e.g. dragging a slider)?
@@ -1831,9 +2421,68 @@ impl Context {
         self.memory(|m| m.interaction().is_using_pointer())
     }
 
-    /// If `true`, egui is currently listening on text input (e.g. typing text in a [`TextEdit`]).
+    /// If `true`, egui is currently listening on keyboard input.
+    ///
+    /// This is the case if a widget has keyboard focus (e.g. when typing text in a [`TextEdit`]),
+    /// if a menu or popup is open (they can be navigated and closed with the keyboard),
+    /// or during a drag-and-drop (which can be canceled with the escape key).
+    ///
+    /// If `false`, your application can handle the keyboard input itself,
+    /// e.g. for controlling a game or for its own keyboard shortcuts.
     pub fn wants_keyboard_input(&self) -> bool {
         self.memory(|m| m.interaction().focus.focused().is_some())
+            || self.is_menu_or_popup_open()
+            || crate::DragAndDrop::has_any_payload(self)
+    }
+
+    /// Let the pointer through to the host application within this rectangle (in screen coordinates),
+    /// even if egui has painted something there, e.g. a decorative frame of a HUD over a 3D scene.
+    ///
+    /// Within the rectangle, no egui widget is hovered or clicked,
+    /// and [`Self::hit_test`], [`Self::is_pointer_over_area`] and [`Self::wants_pointer_input`]
+    /// ignore egui (unless egui is already using the pointer, e.g. for a drag).
+    ///
+    /// Call this every frame the rectangle should let the pointer through.
+    /// To let the pointer through a whole [`Area`] instead, use [`Area::interactable`].
+    pub fn add_passthrough_rect(&self, rect: Rect) {
+        self.frame_state_mut(|fs| fs.passthrough_rects_this_frame.push(rect));
+    }
+
+    /// Ask the integration to blur whatever is painted behind this rectangle (in screen coordinates),
+    /// i.e. in the layers below `layer_id`, for a frosted-glass look.
+    ///
+    /// This is done by [`Frame`] when [`Visuals::blur_behind`] is set,
+    /// so you only need this for backgrounds you paint yourself.
+    /// The rectangles end up in [`FullOutput::blur_behind`].
+    /// Call this every frame the rectangle should be blurred.
+    pub fn add_blur_behind(&self, layer_id: LayerId, rect: Rect, rounding: impl Into<Rounding>) {
+        let blur = output::BlurBehind {
+            layer_id,
+            rect,
+            rounding: rounding.into(),
+        };
+        self.frame_state_mut(|fs| fs.blur_behind.push(blur));
+    }
+
+    /// Is the position within a rectangle added with [`Self::add_passthrough_rect`]
+    /// this frame or the previous one?
+    fn is_passthrough(&self, pos: Pos2) -> bool {
+        self.frame_state(|fs| {
+            fs.passthrough_rects_this_frame
+                .iter()
+                .chain(&fs.passthrough_rects_last_frame)
+                .any(|rect| rect.contains(pos))
+        })
+    }
+
+    /// Is any menu or popup open?
+    ///
+    /// This includes menus of a [`menu::bar`], context menus and the popup of a [`ComboBox`].
+    /// Menus are detected when they are shown, so this uses the previous frame for menus
+    /// that have not been shown yet this frame.
+    pub fn is_menu_or_popup_open(&self) -> bool {
+        self.memory(|m| m.any_popup_open())
+            || self.frame_state(|fs| fs.menu_open_this_frame || fs.menu_open_last_frame)
     }
 
     /// Highlight this widget, to make it look like it is hovered, even if it isn't.
@@ -1896,8 +2545,89 @@ impl Context {
         }
     }
 
+    /// Scale and translate all the graphics and input of the given layer.
+    ///
+    /// This can be used to implement zoomable and pannable canvases that contain real,
+    /// interactive widgets. The shapes of the layer are transformed at the end of the frame,
+    /// and pointer positions are mapped through the inverse transform,
+    /// so [`Response::hover_pos`], [`Response::interact_pointer_pos`] and [`Response::drag_delta`]
+    /// are in the (untransformed) coordinates of the layer.
+    ///
+    /// The transform is remembered until changed.
+    /// Set it to [`emath::TSTransform::IDENTITY`] to remove it.
+    ///
+    /// ```
+    /// # egui::__run_test_ctx(|ctx| {
+    /// # let zoom = 2.0;
+    /// # let pan = egui::vec2(10.0, 0.0);
+    /// let layer_id = egui::LayerId::new(egui::Order::Middle, egui::Id::new("zoomed"));
+    /// ctx.transform_layer(layer_id, egui::emath::TSTransform::new(pan, zoom));
+    /// egui::Area::new(layer_id.id).show(ctx, |ui| {
+    ///     if ui.button("Zoomed button").clicked() {
+    ///         /* … */
+    ///     }
+    /// });
+    /// # });
+    /// ```
+    pub fn transform_layer(&self, layer_id: LayerId, transform: emath::TSTransform) {
+        self.memory_mut(|mem| mem.areas_mut().set_layer_transform(layer_id, transform));
+    }
+
+    /// The transform of the given layer, as set by [`Self::transform_layer`].
+    pub fn layer_transform(&self, layer_id: LayerId) -> Option<emath::TSTransform> {
+        self.memory(|mem| mem.areas().layer_transform(layer_id))
+    }
+
+    /// Make everything painted in the given layer semi-transparent.
+    ///
+    /// The opacity is multiplied into the colors of all the shapes of the layer
+    /// when they are collected at the end of the frame, so the widgets don't need to know about it.
+    /// This is useful for fading in and out a whole window or HUD, e.g. with [`Self::animate_bool`].
+    ///
+    /// The opacity stays until you change it.
+    /// `opacity` must be between 0.0 and 1.0, where 0.0 means fully transparent (i.e., invisible)
+    /// and 1.0 means fully opaque (the default).
+    ///
+    /// See also [`Ui::set_opacity`], which only affects the widgets in that [`Ui`].
+    ///
+    /// ```
+    /// # egui::__run_test_ctx(|ctx| {
+    /// # let show_hud = true;
+    /// let layer_id = egui::LayerId::new(egui::Order::Foreground, egui::Id::new("hud"));
+    /// let opacity = ctx.animate_bool(layer_id.id, show_hud);
+    /// ctx.set_layer_opacity(layer_id, opacity);
+    /// egui::Area::new(layer_id.id)
+    ///     .order(layer_id.order)
+    ///     .show(ctx, |ui| {
+    ///         ui.label("Health: 100");
+    ///     });
+    /// # });
+    /// ```
+    pub fn set_layer_opacity(&self, layer_id: LayerId, opacity: f32) {
+        self.memory_mut(|mem| mem.areas_mut().set_layer_opacity(layer_id, opacity));
+    }
+
+    /// The opacity of the given layer, as set by [`Self::set_layer_opacity`].
+    pub fn layer_opacity(&self, layer_id: LayerId) -> f32 {
+        self.memory(|mem| mem.areas().layer_opacity(layer_id))
+            .unwrap_or(1.0)
+    }
+
+    /// Map a global position (e.g. of the pointer) into the coordinates of the given layer.
+    pub(crate) fn layer_pos_from_global(&self, layer_id: LayerId, pos: Pos2) -> Pos2 {
+        match self.layer_transform(layer_id) {
+            Some(transform) => transform.inverse() * pos,
+            None => pos,
+        }
+    }
+
     /// Top-most layer at the given position.
+    ///
+    /// Returns `None` inside the rectangles added with [`Self::add_passthrough_rect`].
     pub fn layer_id_at(&self, pos: Pos2) -> Option<LayerId> {
+        if self.is_passthrough(pos) {
+            return None;
+        }
         self.memory(|mem| {
             mem.layer_id_at(pos, mem.options.style.interaction.resize_grab_radius_side)
         })
@@ -1914,7 +2644,8 @@ impl Context {
         rect.is_positive() && {
             let pointer_pos = self.input(|i| i.pointer.interact_pos());
             if let Some(pointer_pos) = pointer_pos {
-                rect.contains(pointer_pos) && self.layer_id_at(pointer_pos) == Some(layer_id)
+                rect.contains(self.layer_pos_from_global(layer_id, pointer_pos))
+                    && self.layer_id_at(pointer_pos) == Some(layer_id)
             } else {
                 false
             }
@@ -1947,12 +2678,14 @@ impl Context {
     /// The function will call [`Self::request_repaint()`] when appropriate.
     ///
     /// The animation time is taken from [`Style::animation_time`].
+    #[track_caller]
     pub fn animate_bool(&self, id: Id, value: bool) -> f32 {
         let animation_time = self.style().animation_time;
         self.animate_bool_with_time(id, value, animation_time)
     }
 
     /// Like [`Self::animate_bool`] but allows you to control the animation time.
+    #[track_caller]
     pub fn animate_bool_with_time(&self, id: Id, target_value: bool, animation_time: f32) -> f32 {
         let animated_value = self.write(|ctx| {
             ctx.animation_manager.animate_bool(
@@ -1973,6 +2706,7 @@ impl Context {
     ///
     /// At the first call the value is written to memory.
     /// When it is called with a new value, it linearly interpolates to it in the given time.
+    #[track_caller]
     pub fn animate_value_with_time(&self, id: Id, target_value: f32, animation_time: f32) -> f32 {
         let animated_value = self.write(|ctx| {
             ctx.animation_manager.animate_value(
@@ -2017,6 +2751,10 @@ impl Context {
                 if tessellation_options != prev_tessellation_options {
                     self.tessellation_options_mut(move |o| *o = tessellation_options);
                 }
+                if self.is_quality_reduced() {
+                    ui.label("⚠ Quality is reduced to stay within the frame time budget")
+                        .on_hover_text("See Options::frame_time_budget");
+                }
             });
     }
 
@@ -2062,6 +2800,14 @@ impl Context {
             self.fonts(|f| f.num_galleys_in_cache())
         ))
         .on_hover_text("This is approximately the number of text strings on screen");
+        if ui
+            .button("📋 Copy widget tree")
+            .on_hover_text("Copy the Ui:s and widgets of the next frame to the clipboard, e.g. for bug reports")
+            .clicked()
+        {
+            self.copy_widget_tree();
+        }
+
         ui.add_space(16.0);
 
         CollapsingHeader::new("📥 Input")
@@ -2628,6 +3374,29 @@ impl Context {
         self.send_viewport_cmd_to(self.viewport_id(), command);
     }
 
+    /// Request a screenshot of the current viewport.
+    ///
+    /// The integration will read back the pixels after painting this frame,
+    /// and send them as an [`crate::Event::Screenshot`] in the input of the next frame.
+    /// Use [`crate::InputState::screenshot`] to get it.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// if ui.button("📷 Save screenshot").clicked() {
+    ///     ui.ctx().request_screenshot();
+    /// }
+    /// if let Some(image) = ui.input(|i| i.screenshot()) {
+    ///     // Save `image` to disk, compare it to a reference image, …
+    /// }
+    /// # });
+    /// ```
+    ///
+    /// This is the same as sending [`ViewportCommand::Screenshot`].
+    /// Not all integrations support screenshots (e.g. `eframe` on the web does not, yet).
+    pub fn request_screenshot(&self) {
+        self.send_viewport_cmd(ViewportCommand::Screenshot);
+    }
+
     /// Send a command to a speicfic viewport.
     ///
     /// This lets you affect another viewport, e.g. resizing its window.
@@ -2782,3 +3551,179 @@ fn context_impl_send_sync() {
     fn assert_send_sync<T: Send + Sync>() {}
     assert_send_sync::<Context>();
 }
+
+#[test]
+fn blur_behind_is_in_paint_order() {
+    let ctx = Context::default();
+    ctx.style_mut(|style| style.visuals.blur_behind = true);
+    let run_ui = |ctx: &Context| {
+        Window::new("Window").show(ctx, |ui| {
+            ui.label("Frosted");
+        });
+        SidePanel::left("panel").show(ctx, |ui| {
+            ui.label("Frosted");
+        });
+    };
+    let _ = ctx.run(Default::default(), run_ui); // Windows are invisible the first frame.
+    let output = ctx.run(Default::default(), run_ui);
+    let orders: Vec<Order> = output
+        .blur_behind
+        .iter()
+        .map(|blur| blur.layer_id.order)
+        .collect();
+    assert_eq!(orders, [Order::Background, Order::Middle]);
+}
+
+#[test]
+fn unchanged_frames_are_detected() {
+    let ctx = Context::default();
+    ctx.options_mut(|o| o.detect_unchanged_frames = true);
+    let run = |text: &str| {
+        ctx.run(Default::default(), |ctx| {
+            CentralPanel::default().show(ctx, |ui| {
+                ui.label(text);
+            });
+        })
+        .unchanged
+    };
+    assert!(!run("Hello")); // First frame, with the font texture.
+    assert!(run("Hello"));
+    assert!(!run("World"));
+    assert!(run("World"));
+
+    // The same shapes look different with other tessellation options:
+    ctx.tessellation_options_mut(|o| o.feathering = !o.feathering);
+    assert!(!run("World"));
+    assert!(run("World"));
+
+    // Fading a layer with only text in it only changes the colors of the glyph vertices:
+    let hud = LayerId::new(Order::Foreground, Id::new("hud"));
+    let run_hud = || {
+        ctx.run(Default::default(), |ctx| {
+            ctx.layer_painter(hud).text(
+                Pos2::ZERO,
+                Align2::LEFT_TOP,
+                "HUD",
+                FontId::default(),
+                Color32::RED,
+            );
+        })
+        .unchanged
+    };
+    assert!(!run_hud());
+    assert!(run_hud());
+    ctx.set_layer_opacity(hud, 0.5);
+    assert!(!run_hud());
+    assert!(run_hud());
+    ctx.set_layer_opacity(hud, 0.1);
+    assert!(!run_hud());
+
+    ctx.options_mut(|o| o.detect_unchanged_frames = false);
+    assert!(!run("World"));
+}
+
+#[test]
+fn layer_opacity_is_applied_to_the_shapes_of_the_layer() {
+    let ctx = Context::default();
+    let faded = LayerId::new(Order::Foreground, Id::new("faded"));
+    let redirected = LayerId::new(Order::Foreground, Id::new("redirected"));
+    ctx.set_layer_opacity(faded, 0.5);
+    assert_eq!(ctx.layer_opacity(faded), 0.5);
+
+    let rect = Rect::from_min_size(Pos2::ZERO, Vec2::splat(10.0));
+    let output = ctx.run(Default::default(), |ctx| {
+        ctx.layer_painter(faded)
+            .rect_filled(rect, 0.0, Color32::WHITE);
+
+        // Redirecting a painter keeps its opacity:
+        let mut painter = ctx.layer_painter(LayerId::background());
+        painter.set_opacity(0.5);
+        painter
+            .with_layer_id(redirected)
+            .rect_filled(rect, 0.0, Color32::WHITE);
+    });
+
+    let fill = |layer_id: LayerId| {
+        let (_, range) = output
+            .shape_layers
+            .iter()
+            .find(|(id, _)| *id == layer_id)
+            .unwrap();
+        match &output.shapes[range.start].shape {
+            Shape::Rect(rect) => rect.fill,
+            shape => panic!("Unexpected shape: {shape:?}"),
+        }
+    };
+    let half_white = Color32::WHITE.gamma_multiply(0.5);
+    assert_eq!(fill(faded), half_white);
+    assert_eq!(fill(redirected), half_white);
+}
+
+#[test]
+fn native_pixels_per_point_fallback() {
+    let ctx = Context::default();
+    let input = |native_pixels_per_point: Option<f32>| {
+        let mut input = RawInput::default();
+        input
+            .viewports
+            .get_mut(&ViewportId::ROOT)
+            .unwrap()
+            .native_pixels_per_point = native_pixels_per_point;
+        input
+    };
+
+    let _ = ctx.run(input(Some(1.5)), |_| {});
+    assert_eq!(ctx.pixels_per_point(), 1.5);
+
+    // The integration sends no `ViewportInfo` at all:
+    let no_viewports = RawInput {
+        viewports: Default::default(),
+        ..Default::default()
+    };
+    let _ = ctx.run(no_viewports, |_| {});
+    assert_eq!(ctx.pixels_per_point(), 1.5);
+
+    let _ = ctx.run(input(None), |_| {});
+    assert_eq!(ctx.pixels_per_point(), 1.5);
+
+    // Never known: guess from the monitor size.
+    let ctx = Context::default();
+    let mut input = input(None);
+    input
+        .viewports
+        .get_mut(&ViewportId::ROOT)
+        .unwrap()
+        .monitor_size = Some(vec2(3840.0, 2160.0));
+    let _ = ctx.run(input, |_| {});
+    assert_eq!(ctx.pixels_per_point(), 2.0);
+}
+
+#[test]
+fn quality_is_restored_when_idle() {
+    let ctx = Context::default();
+    ctx.options_mut(|o| o.frame_time_budget = Some(1.0 / 30.0));
+    let mut time = 0.0;
+    let mut run = |request_repaint: bool| {
+        time += 0.1; // Too slow
+        let input = RawInput {
+            time: Some(time),
+            ..Default::default()
+        };
+        let _ = ctx.run(input, |ctx| {
+            if request_repaint {
+                ctx.request_repaint();
+            }
+        });
+    };
+
+    for _ in 0..20 {
+        run(true);
+    }
+    assert!(ctx.is_quality_reduced());
+
+    // egui repaints once more after the last request, then sleeps:
+    run(false);
+    assert!(ctx.is_quality_reduced());
+    run(false);
+    assert!(!ctx.is_quality_reduced());
+}
@@ -187,6 +187,7 @@ pub struct ViewportInfo {
     /// The OS native pixels-per-point.
     ///
     /// This should always be set, if known.
+    /// If it is `None`, egui keeps using the last known value (see [`crate::Context::native_pixels_per_point`]).
     ///
     /// On web this takes browser scaling into account,
     /// and orresponds to [`window.devicePixelRatio`](https://developer.mozilla.org/en-US/docs/Web/API/Window/devicePixelRatio) in JavaScript.
@@ -1230,6 +1231,12 @@ impl From<u32> for TouchId {
 /// but in the future this `struct` should be extended into a full callback thing.
 ///
 /// Any events not covered by the filter are given to the widget, but are not exclusive.
+///
+/// A focused widget sets its filter with [`crate::Memory::set_focus_lock_filter`].
+/// For instance, a [`crate::TextEdit`] keeps the arrow keys for moving the cursor,
+/// and a [`crate::Slider`] for changing its value,
+/// while the arrow keys move the focus away from a focused [`crate::Button`],
+/// which has no filter.
 #[derive(Clone, Copy, Debug)]
 pub struct EventFilter {
     /// If `true`, pressing tab will act on the widget,
@@ -1,6 +1,6 @@
 //! All the data egui returns to the backend at the end of each frame.
 
-use crate::{ViewportIdMap, ViewportOutput, WidgetType};
+use crate::{LayerId, Rect, Rounding, ViewportIdMap, ViewportOutput, WidgetType};
 
 /// What egui emits each frame from [`crate::Context::run`].
 ///
@@ -21,6 +21,20 @@ pub struct FullOutput {
     /// You can use [`crate::Context::tessellate`] to turn this into triangles.
     pub shapes: Vec<epaint::ClippedShape>,
 
+    /// Which layer the [`Self::shapes`] are in, as consecutive ranges into [`Self::shapes`],
+    /// in paint order (back to front).
+    ///
+    /// You can pass this to [`crate::Context::tessellate_layers`] together with [`Self::shapes`],
+    /// e.g. to know which meshes belong to which window.
+    pub shape_layers: Vec<(LayerId, std::ops::Range<usize>)>,
+
+    /// Regions that want whatever is painted behind them blurred, in paint order.
+    ///
+    /// Added with [`crate::Context::add_blur_behind`],
+    /// e.g. by windows and panels when [`crate::Visuals::blur_behind`] is set.
+    /// Integrations that can't blur can ignore this.
+    pub blur_behind: Vec<BlurBehind>,
+
     /// The number of physical pixels per logical ui point, for the viewport that was updated.
     ///
     /// You can pass this to [`crate::Context::tessellate`] together with [`Self::shapes`].
@@ -31,6 +45,18 @@ pub struct FullOutput {
     /// It is up to the integration to spawn a native window for each viewport,
     /// and to close any window that no longer has a viewport in this map.
     pub viewport_output: ViewportIdMap<ViewportOutput>,
+
+    /// `true` if this frame paints exactly what the previous frame of the same viewport painted,
+    /// and there are no texture changes.
+    ///
+    /// The integration can then skip tessellating and presenting the frame.
+    ///
+    /// Always `false` unless [`crate::Options::detect_unchanged_frames`] is set,
+    /// and for frames containing a [`epaint::PaintCallback`], since those can paint anything.
+    ///
+    /// This is opt-in for custom integrations: `eframe` and `egui_glow` ignore it,
+    /// and always present every frame.
+    pub unchanged: bool,
 }
 
 impl FullOutput {
@@ -40,14 +66,20 @@ impl FullOutput {
             platform_output,
             textures_delta,
             shapes,
+            shape_layers,
+            blur_behind,
             pixels_per_point,
             viewport_output: viewports,
+            unchanged,
         } = newer;
 
         self.platform_output.append(platform_output);
         self.textures_delta.append(textures_delta);
         self.shapes = shapes; // Only paint the latest
+        self.shape_layers = shape_layers;
+        self.blur_behind = blur_behind;
         self.pixels_per_point = pixels_per_point; // Use latest
+        self.unchanged &= unchanged; // The older frame may never have been painted
 
         for (id, new_viewport) in viewports {
             match self.viewport_output.entry(id) {
@@ -62,6 +94,27 @@ impl FullOutput {
     }
 }
 
+/// A region that wants whatever is painted behind it blurred, for a frosted-glass look.
+///
+/// See [`FullOutput::blur_behind`].
+///
+/// To render it, an integration can blur everything painted in the layers before [`Self::layer_id`]
+/// (see [`FullOutput::shape_layers`]) within the rounded [`Self::rect`],
+/// and then paint the rest of the layers on top of it.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BlurBehind {
+    /// The layer that is painted on top of the blurred region.
+    pub layer_id: LayerId,
+
+    /// The region to blur, in points.
+    pub rect: Rect,
+
+    /// The rounding of the corners of [`Self::rect`].
+    pub rounding: Rounding,
+}
+
+// ----------------------------------------------------------------------------
+
 /// The non-rendering part of what egui emits each frame.
 ///
 /// You can access (and modify) this with [`crate::Context::output`].
@@ -101,6 +154,24 @@ pub struct PlatformOutput {
     /// Iff `Some`, the user is editing text.
     pub text_cursor_pos: Option<crate::Pos2>,
 
+    /// Is the pointer over any egui area at the end of the frame?
+    ///
+    /// Same as [`crate::Context::is_pointer_over_area`].
+    /// Useful for integrations that share the input with something else, e.g. a game world.
+    pub is_pointer_over_area: bool,
+
+    /// Is egui interested in the pointer at the end of the frame?
+    ///
+    /// Same as [`crate::Context::wants_pointer_input`].
+    /// If `false`, the host application can use the pointer input.
+    pub wants_pointer_input: bool,
+
+    /// Is egui interested in keyboard input at the end of the frame?
+    ///
+    /// Same as [`crate::Context::wants_keyboard_input`].
+    /// If `false`, the host application can use the keyboard input.
+    pub wants_keyboard_input: bool,
+
     /// The difference in the widget tree since last frame.
     ///
     /// NOTE: this needs to be per-viewport.
@@ -144,6 +215,9 @@ impl PlatformOutput {
             mut events,
             mutable_text_under_cursor,
             text_cursor_pos,
+            is_pointer_over_area,
+            wants_pointer_input,
+            wants_keyboard_input,
             #[cfg(feature = "accesskit")]
             accesskit_update,
         } = newer;
@@ -158,6 +232,9 @@ impl PlatformOutput {
         self.events.append(&mut events);
         self.mutable_text_under_cursor = mutable_text_under_cursor;
         self.text_cursor_pos = text_cursor_pos.or(self.text_cursor_pos);
+        self.is_pointer_over_area = is_pointer_over_area;
+        self.wants_pointer_input = wants_pointer_input;
+        self.wants_keyboard_input = wants_keyboard_input;
 
         #[cfg(feature = "accesskit")]
         {
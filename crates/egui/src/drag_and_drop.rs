@@ -0,0 +1,98 @@
+use std::{any::Any, sync::Arc};
+
+use crate::{Context, Id};
+
+/// Tracking of drag-and-drop payloads.
+///
+/// This is a low-level API.
+///
+/// For a higher-level API, see:
+/// - [`crate::Ui::dnd_drag_source`]
+/// - [`crate::Ui::dnd_drop_zone`]
+/// - [`crate::Response::dnd_set_drag_payload`]
+/// - [`crate::Response::dnd_hover_payload`]
+/// - [`crate::Response::dnd_release_payload`]
+///
+/// The payload is stored on the [`Context`], so it can be dragged from a widget in one [`crate::Window`]
+/// and dropped onto a widget in another.
+/// It is cleared at the end of the frame in which the pointer is released, or if escape is pressed.
+#[doc(alias = "drag and drop")]
+#[derive(Clone, Default)]
+pub struct DragAndDrop {
+    /// If set, something is being dragged.
+    payload: Option<Arc<dyn Any + Send + Sync>>,
+}
+
+impl DragAndDrop {
+    /// The payload is stored at this id in the temporary data of the [`Context`].
+    fn id() -> Id {
+        Id::new("__drag_and_drop")
+    }
+
+    pub(crate) fn end_frame(ctx: &Context) {
+        let abort_dnd =
+            ctx.input(|i| i.pointer.any_released() || i.key_pressed(crate::Key::Escape));
+        if abort_dnd {
+            Self::clear_payload(ctx);
+        }
+    }
+
+    /// Set a drag-and-drop payload.
+    ///
+    /// This can be read by [`Self::payload`] until the pointer is released.
+    pub fn set_payload<Payload>(ctx: &Context, payload: Payload)
+    where
+        Payload: Any + Send + Sync,
+    {
+        ctx.data_mut(|data| {
+            let state = data.get_temp_mut_or_default::<Self>(Self::id());
+            state.payload = Some(Arc::new(payload));
+        });
+    }
+
+    /// Clears the payload, setting it to `None`.
+    pub fn clear_payload(ctx: &Context) {
+        ctx.data_mut(|data| {
+            data.get_temp_mut_or_default::<Self>(Self::id()).payload = None;
+        });
+    }
+
+    /// Retrieve the payload, if any.
+    ///
+    /// Returns `None` if there is no payload, or if it is not of the requested type.
+    ///
+    /// Returns `Some` both during a drag and on the frame the pointer is released
+    /// (if there is a payload).
+    pub fn payload<Payload>(ctx: &Context) -> Option<Arc<Payload>>
+    where
+        Payload: Any + Send + Sync,
+    {
+        ctx.data(|data| {
+            let state = data.get_temp::<Self>(Self::id())?;
+            let payload = state.payload?;
+            payload.downcast().ok()
+        })
+    }
+
+    /// Are we carrying a payload of the given type?
+    ///
+    /// Returns `true` both during a drag and on the frame the pointer is released
+    /// (if there is a payload).
+    pub fn has_payload_of_type<Payload>(ctx: &Context) -> bool
+    where
+        Payload: Any + Send + Sync,
+    {
+        Self::payload::<Payload>(ctx).is_some()
+    }
+
+    /// Are we carrying a payload?
+    ///
+    /// Returns `true` both during a drag and on the frame the pointer is released
+    /// (if there is a payload).
+    pub fn has_any_payload(ctx: &Context) -> bool {
+        ctx.data(|data| {
+            data.get_temp::<Self>(Self::id())
+                .map_or(false, |state| state.payload.is_some())
+        })
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{id::IdSet, *};
+use crate::{id::IdSet, widget_tree::WidgetTree, *};
 
 #[derive(Clone, Copy, Debug)]
 pub(crate) struct TooltipFrameState {
@@ -49,12 +49,40 @@ pub(crate) struct FrameState {
     #[cfg(feature = "accesskit")]
     pub(crate) accesskit_state: Option<AccessKitFrameState>,
 
+    /// If set, [`Response::widget_info`] records the label of each widget here.
+    ///
+    /// Used for type-to-jump in menus.
+    pub(crate) widget_labels: Option<Vec<(Id, String)>>,
+
+    /// If set, all [`Ui`]s and widgets of this frame are recorded here.
+    ///
+    /// Used by [`Context::copy_widget_tree`].
+    pub(crate) widget_tree: Option<WidgetTree>,
+
+    /// Record the [`Self::widget_tree`] the next frame. Write to this.
+    pub(crate) record_widget_tree_next_frame: bool,
+
     /// Highlight these widgets this next frame. Read from this.
     pub(crate) highlight_this_frame: IdSet,
 
     /// Highlight these widgets the next frame. Write to this.
     pub(crate) highlight_next_frame: IdSet,
 
+    /// Was a menu shown this frame? Write to this.
+    pub(crate) menu_open_this_frame: bool,
+
+    /// Was a menu shown last frame? Read from this.
+    pub(crate) menu_open_last_frame: bool,
+
+    /// Added with [`Context::add_passthrough_rect`] this frame.
+    pub(crate) passthrough_rects_this_frame: Vec<Rect>,
+
+    /// Added with [`Context::add_passthrough_rect`] last frame.
+    pub(crate) passthrough_rects_last_frame: Vec<Rect>,
+
+    /// Added with [`Context::add_blur_behind`] this frame.
+    pub(crate) blur_behind: Vec<output::BlurBehind>,
+
     #[cfg(debug_assertions)]
     pub(crate) has_debug_viewed_this_frame: bool,
 }
@@ -71,8 +99,16 @@ impl Default for FrameState {
             scroll_target: [None, None],
             #[cfg(feature = "accesskit")]
             accesskit_state: None,
+            widget_labels: None,
+            widget_tree: None,
+            record_widget_tree_next_frame: false,
             highlight_this_frame: Default::default(),
             highlight_next_frame: Default::default(),
+            menu_open_this_frame: false,
+            menu_open_last_frame: false,
+            passthrough_rects_this_frame: Default::default(),
+            passthrough_rects_last_frame: Default::default(),
+            blur_behind: Default::default(),
 
             #[cfg(debug_assertions)]
             has_debug_viewed_this_frame: false,
@@ -93,8 +129,16 @@ impl FrameState {
             scroll_target,
             #[cfg(feature = "accesskit")]
             accesskit_state,
+            widget_labels,
+            widget_tree,
+            record_widget_tree_next_frame,
             highlight_this_frame,
             highlight_next_frame,
+            menu_open_this_frame,
+            menu_open_last_frame,
+            passthrough_rects_this_frame,
+            passthrough_rects_last_frame,
+            blur_behind,
 
             #[cfg(debug_assertions)]
             has_debug_viewed_this_frame,
@@ -107,6 +151,8 @@ impl FrameState {
         *tooltip_state = None;
         *scroll_delta = input.scroll_delta;
         *scroll_target = [None, None];
+        *widget_labels = None;
+        *widget_tree = std::mem::take(record_widget_tree_next_frame).then(WidgetTree::default);
 
         #[cfg(debug_assertions)]
         {
@@ -119,6 +165,9 @@ impl FrameState {
         }
 
         *highlight_this_frame = std::mem::take(highlight_next_frame);
+        *menu_open_last_frame = std::mem::take(menu_open_this_frame);
+        *passthrough_rects_last_frame = std::mem::take(passthrough_rects_this_frame);
+        blur_behind.clear();
     }
 
     /// How much space is still available after panels has been added.
@@ -0,0 +1,555 @@
+//! A gallery of all the built-in widgets and containers, with live-editable parameters.
+//!
+//! Useful for checking that an egui integration renders and interacts correctly,
+//! and for exploring what egui can do from inside your own app.
+//!
+//! ```
+//! # egui::__run_test_ctx(|ctx| {
+//! # let mut open = true;
+//! # let mut gallery = egui::gallery::Gallery::default();
+//! // Keep the `Gallery` in your app state:
+//! gallery.show(ctx, &mut open);
+//! # });
+//! ```
+use crate::*;
+
+/// Shows every built-in widget and container, with controls for their parameters.
+///
+/// Use [`Self::show`] for a window, or [`Self::ui`] to show it anywhere.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Gallery {
+    enabled: bool,
+    visible: bool,
+    widgets: WidgetsState,
+    containers: ContainersState,
+}
+
+impl Default for Gallery {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            visible: true,
+            widgets: Default::default(),
+            containers: Default::default(),
+        }
+    }
+}
+
+impl Gallery {
+    /// Show the gallery in a [`Window`].
+    pub fn show(&mut self, ctx: &Context, open: &mut bool) {
+        Window::new("🗄 Gallery")
+            .open(open)
+            .default_width(360.0)
+            .vscroll(true)
+            .show(ctx, |ui| self.ui(ui));
+    }
+
+    /// Show the gallery in the given [`Ui`].
+    pub fn ui(&mut self, ui: &mut Ui) {
+        ui.horizontal(|ui| {
+            ui.checkbox(&mut self.enabled, "Enabled")
+                .on_hover_text("Uncheck to disable all the widgets below.");
+            ui.checkbox(&mut self.visible, "Visible")
+                .on_hover_text("Uncheck to hide all the widgets below.");
+        });
+        ui.separator();
+
+        let Self {
+            enabled,
+            visible,
+            widgets,
+            containers,
+        } = self;
+
+        ui.add_enabled_ui(*enabled, |ui| {
+            ui.set_visible(*visible);
+
+            CollapsingHeader::new("Widgets")
+                .default_open(true)
+                .show(ui, |ui| widgets.ui(ui));
+            CollapsingHeader::new("Containers")
+                .default_open(true)
+                .show(ui, |ui| containers.ui(ui));
+        });
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+enum Choice {
+    First,
+    Second,
+    Third,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+struct WidgetsState {
+    // Labels
+    label_text: String,
+    label_wrap: bool,
+
+    // Buttons
+    button_small: bool,
+    button_frame: bool,
+    clicks: usize,
+    boolean: bool,
+    choice: Choice,
+
+    // Numbers
+    value: f64,
+    range_min: f64,
+    range_max: f64,
+    logarithmic: bool,
+    clamp_to_range: bool,
+    vertical_slider: bool,
+    step: f64,
+    drag_speed: f64,
+    progress_animate: bool,
+    show_percentage: bool,
+    spinner_size: f32,
+
+    // Text editing
+    single_line: String,
+    multi_line: String,
+    hint_text: String,
+    password: bool,
+    code_editor: bool,
+
+    // Colors
+    color: Color32,
+    stroke: Stroke,
+}
+
+impl Default for WidgetsState {
+    fn default() -> Self {
+        Self {
+            label_text: "A label with some text that will wrap if it is too long to fit."
+                .to_owned(),
+            label_wrap: true,
+
+            button_small: false,
+            button_frame: true,
+            clicks: 0,
+            boolean: false,
+            choice: Choice::First,
+
+            value: 42.0,
+            range_min: 0.0,
+            range_max: 100.0,
+            logarithmic: false,
+            clamp_to_range: true,
+            vertical_slider: false,
+            step: 0.0,
+            drag_speed: 1.0,
+            progress_animate: false,
+            show_percentage: true,
+            spinner_size: 16.0,
+
+            single_line: Default::default(),
+            multi_line: "Multiple\nlines\nof text".to_owned(),
+            hint_text: "Hint text".to_owned(),
+            password: false,
+            code_editor: false,
+
+            color: Color32::LIGHT_BLUE.linear_multiply(0.5),
+            stroke: Stroke::new(1.0, Color32::LIGHT_BLUE),
+        }
+    }
+}
+
+impl WidgetsState {
+    fn ui(&mut self, ui: &mut Ui) {
+        ui.collapsing("Labels", |ui| self.labels_ui(ui));
+        ui.collapsing("Buttons", |ui| self.buttons_ui(ui));
+        ui.collapsing("Numbers", |ui| self.numbers_ui(ui));
+        ui.collapsing("Text editing", |ui| self.text_edit_ui(ui));
+        ui.collapsing("Colors", |ui| self.colors_ui(ui));
+    }
+
+    fn labels_ui(&mut self, ui: &mut Ui) {
+        ui.horizontal(|ui| {
+            ui.label("Text:");
+            ui.text_edit_singleline(&mut self.label_text);
+        });
+        ui.checkbox(&mut self.label_wrap, "Wrap");
+        ui.separator();
+
+        ui.add(Label::new(self.label_text.as_str()).wrap(self.label_wrap));
+        ui.horizontal_wrapped(|ui| {
+            ui.heading("Heading");
+            ui.strong("Strong");
+            ui.weak("Weak");
+            ui.small("Small");
+            ui.monospace("Monospace");
+            ui.code("code");
+            ui.label(RichText::new("Italics").italics());
+            ui.label(RichText::new("Underline").underline());
+            ui.label(RichText::new("Strikethrough").strikethrough());
+        });
+        ui.horizontal(|ui| {
+            ui.hyperlink_to("Hyperlink", "https://github.com/emilk/egui");
+            if ui.link("Link").clicked() {
+                self.clicks += 1;
+            }
+        });
+    }
+
+    fn buttons_ui(&mut self, ui: &mut Ui) {
+        ui.horizontal(|ui| {
+            ui.checkbox(&mut self.button_small, "Small");
+            ui.checkbox(&mut self.button_frame, "Frame");
+        });
+        ui.separator();
+
+        ui.horizontal(|ui| {
+            let mut button = Button::new("Click me").frame(self.button_frame);
+            if self.button_small {
+                button = button.small();
+            }
+            if ui.add(button).clicked() {
+                self.clicks += 1;
+            }
+            ui.label(format!("Clicked {} times", self.clicks));
+        });
+        ui.horizontal(|ui| {
+            ui.checkbox(&mut self.boolean, "Checkbox");
+            ui.toggle_value(&mut self.boolean, "Toggle value");
+        });
+        ui.horizontal(|ui| {
+            ui.radio_value(&mut self.choice, Choice::First, "First");
+            ui.radio_value(&mut self.choice, Choice::Second, "Second");
+            ui.radio_value(&mut self.choice, Choice::Third, "Third");
+        });
+        ui.horizontal(|ui| {
+            ui.selectable_value(&mut self.choice, Choice::First, "First");
+            ui.selectable_value(&mut self.choice, Choice::Second, "Second");
+            ui.selectable_value(&mut self.choice, Choice::Third, "Third");
+        });
+        ComboBox::from_label("Combo box")
+            .selected_text(format!("{:?}", self.choice))
+            .show_ui(ui, |ui| {
+                ui.selectable_value(&mut self.choice, Choice::First, "First");
+                ui.selectable_value(&mut self.choice, Choice::Second, "Second");
+                ui.selectable_value(&mut self.choice, Choice::Third, "Third");
+            });
+        ui.horizontal(|ui| {
+            ui.menu_button("Menu", |ui| {
+                if ui.button("Close").clicked() {
+                    ui.close_menu();
+                }
+                ui.menu_button("Submenu", |ui| {
+                    ui.checkbox(&mut self.boolean, "Checkbox");
+                });
+            });
+            ui.button("Right-click me")
+                .context_menu(|ui| {
+                    ui.radio_value(&mut self.choice, Choice::First, "First");
+                    ui.radio_value(&mut self.choice, Choice::Second, "Second");
+                    ui.radio_value(&mut self.choice, Choice::Third, "Third");
+                })
+                .on_hover_text("A tooltip");
+        });
+    }
+
+    fn numbers_ui(&mut self, ui: &mut Ui) {
+        Grid::new("number_parameters")
+            .num_columns(2)
+            .show(ui, |ui| {
+                ui.label("Range:");
+                ui.horizontal(|ui| {
+                    ui.add(DragValue::new(&mut self.range_min).prefix("min: "));
+                    ui.add(DragValue::new(&mut self.range_max).prefix("max: "));
+                });
+                ui.end_row();
+
+                ui.label("Step:");
+                ui.add(DragValue::new(&mut self.step).clamp_range(0.0..=f64::INFINITY))
+                    .on_hover_text("0 means no stepping");
+                ui.end_row();
+
+                ui.label("Drag speed:");
+                ui.add(DragValue::new(&mut self.drag_speed).speed(0.1));
+                ui.end_row();
+
+                ui.label("Spinner size:");
+                ui.add(Slider::new(&mut self.spinner_size, 8.0..=64.0));
+                ui.end_row();
+            });
+        ui.horizontal_wrapped(|ui| {
+            ui.checkbox(&mut self.logarithmic, "Logarithmic");
+            ui.checkbox(&mut self.clamp_to_range, "Clamp to range");
+            ui.checkbox(&mut self.vertical_slider, "Vertical slider");
+            ui.checkbox(&mut self.progress_animate, "Animate progress");
+            ui.checkbox(&mut self.show_percentage, "Show percentage");
+        });
+        ui.separator();
+
+        let mut slider = Slider::new(&mut self.value, self.range_min..=self.range_max)
+            .text("Slider")
+            .logarithmic(self.logarithmic)
+            .clamp_to_range(self.clamp_to_range)
+            .step_by(self.step);
+        if self.vertical_slider {
+            slider = slider.vertical();
+        }
+        ui.add(slider);
+
+        ui.horizontal(|ui| {
+            let mut drag_value = DragValue::new(&mut self.value).speed(self.drag_speed);
+            if self.clamp_to_range {
+                drag_value = drag_value.clamp_range(self.range_min..=self.range_max);
+            }
+            ui.add(drag_value);
+            ui.label("Drag value");
+        });
+
+        ui.horizontal(|ui| {
+            let mut number_field = NumberField::new(&mut self.value);
+            if self.clamp_to_range {
+                number_field = number_field.clamp_range(self.range_min..=self.range_max);
+            }
+            ui.add(number_field);
+            ui.label("Number field");
+        });
+
+        let range = self.range_max - self.range_min;
+        let progress = if range > 0.0 {
+            ((self.value - self.range_min) / range).clamp(0.0, 1.0) as f32
+        } else {
+            0.0
+        };
+        let mut progress_bar = ProgressBar::new(progress).animate(self.progress_animate);
+        if self.show_percentage {
+            progress_bar = progress_bar.show_percentage();
+        }
+        ui.add(progress_bar);
+
+        ui.horizontal(|ui| {
+            ui.add(Spinner::new().size(self.spinner_size));
+            ui.add(
+                Gauge::new(self.value as f32)
+                    .range(self.range_min as f32..=self.range_max as f32)
+                    .ticks(10),
+            );
+        });
+
+        ui.add(LevelMeter::new(progress).peak_hold(1.0).segments(20));
+    }
+
+    fn text_edit_ui(&mut self, ui: &mut Ui) {
+        ui.horizontal(|ui| {
+            ui.label("Hint text:");
+            ui.text_edit_singleline(&mut self.hint_text);
+        });
+        ui.horizontal(|ui| {
+            ui.checkbox(&mut self.password, "Password");
+            ui.checkbox(&mut self.code_editor, "Code editor");
+        });
+        ui.separator();
+
+        ui.add(
+            TextEdit::singleline(&mut self.single_line)
+                .hint_text(self.hint_text.as_str())
+                .password(self.password),
+        );
+        let mut multi_line =
+            TextEdit::multiline(&mut self.multi_line).hint_text(self.hint_text.as_str());
+        if self.code_editor {
+            multi_line = multi_line.code_editor();
+        }
+        ui.add(multi_line);
+    }
+
+    fn colors_ui(&mut self, ui: &mut Ui) {
+        ui.horizontal(|ui| {
+            ui.color_edit_button_srgba(&mut self.color);
+            ui.label("Color picker");
+        });
+        widgets::stroke_ui(ui, &mut self.stroke, "Stroke");
+        ui.separator();
+
+        let (rect, _) = ui.allocate_exact_size(vec2(64.0, 32.0), Sense::hover());
+        ui.painter().rect(rect, 4.0, self.color, self.stroke);
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+struct ContainersState {
+    // Frame
+    inner_margin: f32,
+    rounding: f32,
+    fill: Color32,
+    stroke: Stroke,
+
+    // Scroll area
+    scroll_rows: usize,
+    scroll_max_height: f32,
+    scroll_auto_shrink: bool,
+    stick_to_bottom: bool,
+
+    // Grid
+    grid_striped: bool,
+    grid_columns: usize,
+
+    // Window
+    window_open: bool,
+    window_title_bar: bool,
+    window_resizable: bool,
+    window_collapsible: bool,
+}
+
+impl Default for ContainersState {
+    fn default() -> Self {
+        Self {
+            inner_margin: 8.0,
+            rounding: 4.0,
+            fill: Color32::TRANSPARENT,
+            stroke: Stroke::new(1.0, Color32::GRAY),
+
+            scroll_rows: 20,
+            scroll_max_height: 100.0,
+            scroll_auto_shrink: true,
+            stick_to_bottom: false,
+
+            grid_striped: true,
+            grid_columns: 3,
+
+            window_open: false,
+            window_title_bar: true,
+            window_resizable: true,
+            window_collapsible: true,
+        }
+    }
+}
+
+impl ContainersState {
+    fn ui(&mut self, ui: &mut Ui) {
+        ui.collapsing("Frame", |ui| self.frame_ui(ui));
+        ui.collapsing("Scroll area", |ui| self.scroll_area_ui(ui));
+        ui.collapsing("Grid", |ui| self.grid_ui(ui));
+        ui.collapsing("Window", |ui| self.window_ui(ui));
+        ui.collapsing("Layouts", layouts_ui);
+    }
+
+    fn frame_ui(&mut self, ui: &mut Ui) {
+        ui.horizontal(|ui| {
+            ui.label("Inner margin:");
+            ui.add(Slider::new(&mut self.inner_margin, 0.0..=32.0));
+        });
+        ui.horizontal(|ui| {
+            ui.label("Rounding:");
+            ui.add(Slider::new(&mut self.rounding, 0.0..=16.0));
+        });
+        ui.horizontal(|ui| {
+            ui.label("Fill:");
+            ui.color_edit_button_srgba(&mut self.fill);
+        });
+        widgets::stroke_ui(ui, &mut self.stroke, "Stroke");
+        ui.separator();
+
+        Frame::none()
+            .inner_margin(self.inner_margin)
+            .rounding(self.rounding)
+            .fill(self.fill)
+            .stroke(self.stroke)
+            .show(ui, |ui| {
+                ui.label("Contents of a frame");
+            });
+        ui.group(|ui| {
+            ui.label("Contents of a group");
+        });
+    }
+
+    fn scroll_area_ui(&mut self, ui: &mut Ui) {
+        ui.add(Slider::new(&mut self.scroll_rows, 0..=1000).text("rows"));
+        ui.add(Slider::new(&mut self.scroll_max_height, 20.0..=400.0).text("max height"));
+        ui.horizontal(|ui| {
+            ui.checkbox(&mut self.scroll_auto_shrink, "Auto shrink");
+            ui.checkbox(&mut self.stick_to_bottom, "Stick to bottom");
+        });
+        ui.separator();
+
+        let row_height = ui.text_style_height(&TextStyle::Body);
+        ScrollArea::vertical()
+            .max_height(self.scroll_max_height)
+            .auto_shrink(self.scroll_auto_shrink)
+            .stick_to_bottom(self.stick_to_bottom)
+            .show_rows(ui, row_height, self.scroll_rows, |ui, row_range| {
+                for row in row_range {
+                    ui.label(format!("Row {row}"));
+                }
+            });
+    }
+
+    fn grid_ui(&mut self, ui: &mut Ui) {
+        ui.checkbox(&mut self.grid_striped, "Striped");
+        ui.add(Slider::new(&mut self.grid_columns, 1..=8).text("columns"));
+        ui.separator();
+
+        Grid::new("gallery_grid")
+            .striped(self.grid_striped)
+            .num_columns(self.grid_columns)
+            .show(ui, |ui| {
+                for row in 0..4 {
+                    for column in 0..self.grid_columns {
+                        ui.label(format!("{row}:{column}"));
+                    }
+                    ui.end_row();
+                }
+            });
+    }
+
+    fn window_ui(&mut self, ui: &mut Ui) {
+        ui.checkbox(&mut self.window_open, "Open");
+        ui.checkbox(&mut self.window_title_bar, "Title bar");
+        ui.checkbox(&mut self.window_resizable, "Resizable");
+        ui.checkbox(&mut self.window_collapsible, "Collapsible");
+
+        Window::new("Gallery window")
+            .open(&mut self.window_open)
+            .title_bar(self.window_title_bar)
+            .resizable(self.window_resizable)
+            .collapsible(self.window_collapsible)
+            .show(ui.ctx(), |ui| {
+                ui.label("Contents of a window");
+            });
+    }
+}
+
+fn layouts_ui(ui: &mut Ui) {
+    ui.horizontal(|ui| {
+        ui.label("Horizontal:");
+        for i in 0..3 {
+            let _ = ui.button(format!("{i}"));
+        }
+    });
+    ui.vertical(|ui| {
+        ui.label("Vertical:");
+        for i in 0..3 {
+            let _ = ui.button(format!("{i}"));
+        }
+    });
+    ui.label("Indented:");
+    ui.indent("gallery_indent", |ui| {
+        ui.label("Indented text");
+    });
+    ui.columns(3, |columns| {
+        for (i, column) in columns.iter_mut().enumerate() {
+            column.label(format!("Column {i}"));
+        }
+    });
+    ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
+        ui.label("Right-to-left");
+    });
+}
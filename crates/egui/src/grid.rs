@@ -71,10 +71,17 @@ pub(crate) struct GridLayout {
     min_cell_size: Vec2,
     max_cell_size: Vec2,
     color_picker: Option<ColorPickerFn>,
+    col_aligns: Vec<Option<Align>>,
+
+    /// Lay out the columns from right to left, with the cursor at the right edge of the next cell.
+    reversed: bool,
 
     // Cursor:
     col: usize,
     row: usize,
+
+    /// How many columns the next cell spans, see [`Ui::span_columns`].
+    span: usize,
 }
 
 impl GridLayout {
@@ -85,9 +92,10 @@ impl GridLayout {
         // TODO(emilk): respect current layout
 
         let initial_available = ui.placer().max_rect().intersect(ui.cursor());
+        let reversed = ui.layout().main_dir() == Direction::RightToLeft;
         crate::egui_assert!(
-            initial_available.min.x.is_finite(),
-            "Grid not yet available for right-to-left layouts"
+            initial_available.min.x.is_finite() && initial_available.max.x.is_finite(),
+            "Grid needs a finite width"
         );
 
         ui.ctx().check_for_id_clash(id, initial_available, "Grid");
@@ -106,9 +114,12 @@ impl GridLayout {
             min_cell_size: ui.spacing().interact_size,
             max_cell_size: Vec2::INFINITY,
             color_picker: None,
+            col_aligns: vec![],
+            reversed,
 
             col: 0,
             row: 0,
+            span: 1,
         }
     }
 }
@@ -120,6 +131,32 @@ impl GridLayout {
             .unwrap_or(self.min_cell_size.x)
     }
 
+    /// The width of the columns spanned by the next cell, including the spacing between them.
+    fn span_width(&self, col_width: impl Fn(usize) -> f32) -> f32 {
+        (self.col..self.col + self.span).map(col_width).sum::<f32>()
+            + (self.span - 1) as f32 * self.spacing.x
+    }
+
+    /// The horizontal position of the cursor, i.e. where the next cell starts.
+    fn cursor_x(&self, cursor: Rect) -> f32 {
+        if self.reversed {
+            cursor.max.x
+        } else {
+            cursor.min.x
+        }
+    }
+
+    /// A cell of the given size at the cursor.
+    fn cell_rect(&self, cursor: Rect, size: Vec2) -> Rect {
+        let x = self.cursor_x(cursor);
+        let x_range = if self.reversed {
+            Rangef::new(x - size.x, x)
+        } else {
+            Rangef::new(x, x + size.x)
+        };
+        Rect::from_x_y_ranges(x_range, Rangef::new(cursor.min.y, cursor.min.y + size.y))
+    }
+
     fn prev_row_height(&self, row: usize) -> f32 {
         self.prev_state
             .row_height(row)
@@ -131,18 +168,25 @@ impl GridLayout {
     }
 
     pub(crate) fn available_rect(&self, region: &Region) -> Rect {
-        let is_last_column = Some(self.col + 1) == self.num_columns;
+        let is_last_column = Some(self.col + self.span) == self.num_columns;
 
         let width = if is_last_column {
             // The first frame we don't really know the widths of the previous columns,
             // so returning a big available width here can cause trouble.
             if self.is_first_frame {
-                self.curr_state
-                    .col_width(self.col)
-                    .unwrap_or(self.min_cell_size.x)
+                self.span_width(|col| {
+                    self.curr_state
+                        .col_width(col)
+                        .unwrap_or(self.min_cell_size.x)
+                })
             } else {
-                (self.initial_available.right() - region.cursor.left())
-                    .at_most(self.max_cell_size.x)
+                let cursor_x = self.cursor_x(region.cursor);
+                let width = if self.reversed {
+                    cursor_x - self.initial_available.left()
+                } else {
+                    self.initial_available.right() - cursor_x
+                };
+                width.at_most(self.max_cell_size.x)
             }
         } else if self.max_cell_size.x.is_finite() {
             // TODO(emilk): should probably heed `prev_state` here too
@@ -150,14 +194,16 @@ impl GridLayout {
         } else {
             // If we want to allow width-filling widgets like [`Separator`] in one of the first cells
             // then we need to make sure they don't spill out of the first cell:
-            self.prev_state
-                .col_width(self.col)
-                .or_else(|| self.curr_state.col_width(self.col))
-                .unwrap_or(self.min_cell_size.x)
+            self.span_width(|col| {
+                self.prev_state
+                    .col_width(col)
+                    .or_else(|| self.curr_state.col_width(col))
+                    .unwrap_or(self.min_cell_size.x)
+            })
         };
 
         // If something above was wider, we can be wider:
-        let width = width.max(self.curr_state.col_width(self.col).unwrap_or(0.0));
+        let width = width.max(self.span_width(|col| self.curr_state.col_width(col).unwrap_or(0.0)));
 
         let available = region.max_rect.intersect(region.cursor);
 
@@ -166,20 +212,29 @@ impl GridLayout {
             .at_least(self.min_cell_size.y)
             .at_most(self.max_cell_size.y);
 
-        Rect::from_min_size(available.min, vec2(width, height))
+        self.cell_rect(region.cursor, vec2(width, height))
     }
 
     pub(crate) fn next_cell(&self, cursor: Rect, child_size: Vec2) -> Rect {
-        let width = self.prev_state.col_width(self.col).unwrap_or(0.0);
+        let width = self.span_width(|col| self.prev_state.col_width(col).unwrap_or(0.0));
         let height = self.prev_row_height(self.row);
         let size = child_size.max(vec2(width, height));
-        Rect::from_min_size(cursor.min, size)
+        self.cell_rect(cursor, size)
     }
 
-    #[allow(clippy::unused_self)]
     pub(crate) fn align_size_within_rect(&self, size: Vec2, frame: Rect) -> Rect {
-        // TODO(emilk): allow this alignment to be customized
-        Align2::LEFT_CENTER.align_size_within_rect(size, frame)
+        let default_align = if self.reversed {
+            Align::Max
+        } else {
+            Align::Min
+        };
+        let align = self
+            .col_aligns
+            .get(self.col)
+            .copied()
+            .flatten()
+            .unwrap_or(default_align);
+        Align2([align, Align::Center]).align_size_within_rect(size, frame)
     }
 
     pub(crate) fn justify_and_align(&self, frame: Rect, size: Vec2) -> Rect {
@@ -193,7 +248,7 @@ impl GridLayout {
             let debug_expand_height = self.style.debug.show_expand_height;
             if debug_expand_width || debug_expand_height {
                 let rect = widget_rect;
-                let too_wide = rect.width() > self.prev_col_width(self.col);
+                let too_wide = rect.width() > self.span_width(|col| self.prev_col_width(col));
                 let too_high = rect.height() > self.prev_row_height(self.row);
 
                 if (debug_expand_width && too_wide) || (debug_expand_height && too_high) {
@@ -212,13 +267,37 @@ impl GridLayout {
             }
         }
 
-        self.curr_state
-            .set_min_col_width(self.col, widget_rect.width().max(self.min_cell_size.x));
+        let last_col = self.col + self.span - 1;
+        for col in self.col..last_col {
+            self.curr_state.set_min_col_width(col, self.min_cell_size.x);
+        }
+        // A cell spanning several columns only widens the last of them, if needed:
+        let other_cols_width = self.span_width(|col| {
+            if col == last_col {
+                0.0
+            } else {
+                self.prev_col_width(col)
+            }
+        });
+        self.curr_state.set_min_col_width(
+            last_col,
+            (widget_rect.width() - other_cols_width).max(self.min_cell_size.x),
+        );
         self.curr_state
             .set_min_row_height(self.row, widget_rect.height().max(self.min_cell_size.y));
 
-        cursor.min.x += self.prev_col_width(self.col) + self.spacing.x;
-        self.col += 1;
+        let advance = self.span_width(|col| self.prev_col_width(col)) + self.spacing.x;
+        if self.reversed {
+            cursor.max.x -= advance;
+        } else {
+            cursor.min.x += advance;
+        }
+        self.col += self.span;
+        self.span = 1;
+    }
+
+    pub(crate) fn span_columns(&mut self, columns: usize) {
+        self.span = columns.at_least(1);
     }
 
     fn paint_row(&mut self, cursor: &Rect, painter: &Painter) {
@@ -234,7 +313,7 @@ impl GridLayout {
         };
         // Paint background for coming row:
         let size = Vec2::new(self.prev_state.full_width(self.spacing.x), height);
-        let rect = Rect::from_min_size(cursor.min, size);
+        let rect = self.cell_rect(*cursor, size);
         let rect = rect.expand2(0.5 * self.spacing.y * Vec2::Y);
         let rect = rect.expand2(2.0 * Vec2::X); // HACK: just looks better with some spacing on the sides
 
@@ -242,7 +321,11 @@ impl GridLayout {
     }
 
     pub(crate) fn end_row(&mut self, cursor: &mut Rect, painter: &Painter) {
-        cursor.min.x = self.initial_available.min.x;
+        if self.reversed {
+            cursor.max.x = self.initial_available.max.x;
+        } else {
+            cursor.min.x = self.initial_available.min.x;
+        }
         cursor.min.y += self.spacing.y;
         cursor.min.y += self
             .curr_state
@@ -251,6 +334,7 @@ impl GridLayout {
 
         self.col = 0;
         self.row += 1;
+        self.span = 1;
 
         self.paint_row(cursor, painter);
     }
@@ -267,11 +351,14 @@ impl GridLayout {
 
 /// A simple grid layout.
 ///
-/// The cells are always laid out left to right, top-down.
-/// The contents of each cell will be aligned to the left and center.
+/// The cells are laid out left to right, top-down
+/// (or right to left with [`Self::reverse_columns`]).
+/// The contents of each cell will be aligned to the left and center,
+/// which can be changed per column with [`Self::column_align`].
 ///
 /// If you want to add multiple widgets to a cell you need to group them with
 /// [`Ui::horizontal`], [`Ui::vertical`] etc.
+/// To let a cell span several columns, call [`Ui::span_columns`] before adding it.
 ///
 /// ```
 /// # egui::__run_test_ui(|ui| {
@@ -301,6 +388,8 @@ pub struct Grid {
     spacing: Option<Vec2>,
     start_row: usize,
     color_picker: Option<ColorPickerFn>,
+    col_aligns: Vec<Option<Align>>,
+    reverse_columns: Option<bool>,
 }
 
 impl Grid {
@@ -315,6 +404,8 @@ impl Grid {
             spacing: None,
             start_row: 0,
             color_picker: None,
+            col_aligns: vec![],
+            reverse_columns: None,
         }
     }
 
@@ -383,6 +474,44 @@ impl Grid {
         self
     }
 
+    /// How to horizontally align the contents of the cells of the given column.
+    ///
+    /// Default: [`Align::Min`] (left), or [`Align::Max`] (right) with [`Self::reverse_columns`].
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// egui::Grid::new("prices")
+    ///     .column_align(1, egui::Align::Max)
+    ///     .show(ui, |ui| {
+    ///         ui.label("Apples");
+    ///         ui.label("1.50");
+    ///         ui.end_row();
+    ///
+    ///         ui.span_columns(2);
+    ///         ui.label("Prices include taxes");
+    ///         ui.end_row();
+    ///     });
+    /// # });
+    /// ```
+    #[inline]
+    pub fn column_align(mut self, column: usize, align: Align) -> Self {
+        if self.col_aligns.len() <= column {
+            self.col_aligns.resize(column + 1, None);
+        }
+        self.col_aligns[column] = Some(align);
+        self
+    }
+
+    /// Lay out the columns from right to left, so that the first column is the rightmost one.
+    ///
+    /// This is for right-to-left languages, where a form should have its labels on the right.
+    /// Default: `true` if the parent [`Ui`] prefers right-to-left (see [`Layout::prefer_right_to_left`]).
+    #[inline]
+    pub fn reverse_columns(mut self, reverse_columns: bool) -> Self {
+        self.reverse_columns = Some(reverse_columns);
+        self
+    }
+
     /// Change which row number the grid starts on.
     /// This can be useful when you have a large [`Grid`] inside of [`ScrollArea::show_rows`].
     #[inline]
@@ -411,10 +540,13 @@ impl Grid {
             spacing,
             start_row,
             color_picker,
+            col_aligns,
+            reverse_columns,
         } = self;
         let min_col_width = min_col_width.unwrap_or_else(|| ui.spacing().interact_size.x);
         let min_row_height = min_row_height.unwrap_or_else(|| ui.spacing().interact_size.y);
         let spacing = spacing.unwrap_or_else(|| ui.spacing().item_spacing);
+        let reverse_columns = reverse_columns.unwrap_or_else(|| ui.layout().prefer_right_to_left());
 
         let id = ui.make_persistent_id(id_source);
         let prev_state = State::load(ui.ctx(), id);
@@ -425,12 +557,24 @@ impl Grid {
         // which we do here:
         let max_rect = ui.cursor().intersect(ui.max_rect());
         ui.allocate_ui_at_rect(max_rect, |ui| {
-            ui.set_visible(prev_state.is_some()); // Avoid visible first-frame jitter
-            ui.horizontal(|ui| {
+            if prev_state.is_none() {
+                ui.set_sizing_pass(); // Avoid visible first-frame jitter
+            }
+            let layout = if reverse_columns {
+                Layout::right_to_left(Align::Center)
+            } else {
+                Layout::left_to_right(Align::Center)
+            };
+            let initial_size = vec2(
+                ui.available_size_before_wrap().x,
+                ui.spacing().interact_size.y, // Assume there will be something interactive on the first row
+            );
+            ui.allocate_ui_with_layout(initial_size, layout, |ui| {
                 let is_color = color_picker.is_some();
                 let mut grid = GridLayout {
                     num_columns,
                     color_picker,
+                    col_aligns,
                     min_cell_size: vec2(min_col_width, min_row_height),
                     max_cell_size,
                     spacing,
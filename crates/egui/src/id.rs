@@ -33,8 +33,9 @@ impl Id {
     /// A special [`Id`], in particular as a key to [`crate::Memory::data`]
     /// for when there is no particular widget to attach the data.
     ///
-    /// The null [`Id`] is still a valid id to use in all circumstances,
-    /// though obviously it will lead to a lot of collisions if you do use it!
+    /// It can also be used to allocate space for something that should never be interacted with:
+    /// widgets with the null [`Id`] never sense clicks, drags or focus,
+    /// and are never reported as [`Id`] clashes.
     pub const NULL: Self = Self(0);
 
     #[deprecated = "Use Id::NULL"]
@@ -47,6 +48,16 @@ impl Id {
     }
 
     /// Generate a new [`Id`] by hashing some source (e.g. a string or integer).
+    ///
+    /// The source can be anything that implements [`std::hash::Hash`],
+    /// including tuples of several keys:
+    ///
+    /// ```
+    /// # use egui::Id;
+    /// let row = 7;
+    /// assert_eq!(Id::new(("table", row)), Id::new(("table", 7)));
+    /// assert_ne!(Id::new(("table", row)), Id::new(("table", 8)));
+    /// ```
     pub fn new(source: impl std::hash::Hash) -> Id {
         use std::hash::{BuildHasher, Hasher};
         let mut hasher = epaint::ahash::RandomState::with_seeds(1, 2, 3, 4).build_hasher();
@@ -54,6 +65,15 @@ impl Id {
         Id(hasher.finish())
     }
 
+    /// Use a hash you have already computed (e.g. of a key in your own data) as an [`Id`].
+    ///
+    /// Unlike [`Self::new`], the value is used as-is, so it should be well distributed.
+    /// A hash of zero gives [`Self::NULL`].
+    #[inline]
+    pub const fn from_hash(hash: u64) -> Self {
+        Self(hash)
+    }
+
     /// Generate a new [`Id`] by hashing the parent [`Id`] and the given argument.
     pub fn with(self, child: impl std::hash::Hash) -> Id {
         use std::hash::{BuildHasher, Hasher};
@@ -12,11 +12,14 @@ use touch_state::TouchState;
 const MAX_CLICK_DIST: f32 = 6.0; // TODO(emilk): move to settings
 
 /// If the pointer is down for longer than this, it won't become a click (but it is still a drag)
-const MAX_CLICK_DURATION: f64 = 0.6; // TODO(emilk): move to settings
+pub(crate) const MAX_CLICK_DURATION: f64 = 0.6; // TODO(emilk): move to settings
 
 /// The new pointer press must come within this many seconds from previous pointer release
 const MAX_DOUBLE_CLICK_DELAY: f64 = 0.3; // TODO(emilk): move to settings
 
+/// [`InputState::stable_dt`] is never larger than this, so animations don't jump after a hitch.
+const MAX_STABLE_DT: f32 = 0.1;
+
 /// Input state that egui updates each frame.
 ///
 /// You can check if `egui` is using the inputs using
@@ -65,7 +68,7 @@ pub struct InputState {
     /// Time in seconds. Relative to whatever. Used for animation.
     pub time: f64,
 
-    /// Time since last frame, in seconds.
+    /// Time since last frame, in seconds. Never negative.
     ///
     /// This can be very unstable in reactive mode (when we don't paint each frame).
     /// For animations it is therefore better to use [`Self::stable_dt`].
@@ -95,13 +98,13 @@ pub struct InputState {
     /// and in all other situations this will be an accurate measurement of time passed
     /// since the previous frame.
     ///
-    /// Note that a frame can still stall for various reasons, so `stable_dt` can
-    /// still be unusually large in some situations.
+    /// Note that a frame can still stall for various reasons, so `stable_dt` is
+    /// clamped to at most 0.1 seconds.
+    /// This gives you smooth animations when the framerate is good (even in reactive mode),
+    /// but avoids large jumps when a frame hitches,
+    /// and effectively slows down animations when FPS drops below 10.
     ///
-    /// When animating something, it is recommended that you use something like
-    /// `stable_dt.min(0.1)` - this will give you smooth animations when the framerate is good
-    /// (even in reactive mode), but will avoid large jumps when framerate is bad,
-    /// and will effectively slow down the animation when FPS drops below 10.
+    /// When animating something, use this rather than the difference between two [`Self::time`]s.
     pub stable_dt: f32,
 
     /// The native window has the keyboard focus (i.e. is receiving key presses).
@@ -153,7 +156,7 @@ impl InputState {
         crate::profile_function!();
 
         let time = new.time.unwrap_or(self.time + new.predicted_dt as f64);
-        let unstable_dt = (time - self.time) as f32;
+        let unstable_dt = ((time - self.time) as f32).at_least(0.0);
 
         let stable_dt = if requested_repaint_last_frame {
             // we should have had a repaint straight away,
@@ -162,6 +165,7 @@ impl InputState {
         } else {
             new.predicted_dt
         };
+        let stable_dt = stable_dt.at_most(MAX_STABLE_DT);
 
         let screen_rect = new.screen_rect.unwrap_or(self.screen_rect);
         self.create_touch_states_for_new_devices(&new.events);
@@ -238,6 +242,18 @@ impl InputState {
         self.raw.viewport()
     }
 
+    /// The screenshot of the active viewport, if one arrived this frame.
+    ///
+    /// See [`crate::Context::request_screenshot`].
+    pub fn screenshot(&self) -> Option<std::sync::Arc<epaint::ColorImage>> {
+        self.events.iter().find_map(|event| match event {
+            Event::Screenshot { viewport_id, image } if *viewport_id == self.raw.viewport_id => {
+                Some(image.clone())
+            }
+            _ => None,
+        })
+    }
+
     #[inline(always)]
     pub fn screen_rect(&self) -> Rect {
         self.screen_rect
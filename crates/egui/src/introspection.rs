@@ -141,9 +141,11 @@ impl Widget for &mut epaint::TessellationOptions {
                 coarse_tessellation_culling,
                 prerasterized_discs,
                 round_text_to_pixels,
+                round_line_segments_to_pixels,
                 debug_paint_clip_rects,
                 debug_paint_text_rects,
                 debug_ignore_clip_rects,
+                circle_detail,
                 bezier_tolerance,
                 epsilon: _,
             } = self;
@@ -158,6 +160,13 @@ impl Widget for &mut epaint::TessellationOptions {
 
             ui.checkbox(prerasterized_discs, "Speed up filled circles with pre-rasterization");
 
+            ui.add(
+                crate::widgets::Slider::new(circle_detail, 0.1..=1.0)
+                    .text("Circle detail")
+                    .show_value(true),
+            )
+            .on_hover_text("Lower values use fewer segments for circles and rounded corners.");
+
             ui.add(
                 crate::widgets::Slider::new(bezier_tolerance, 0.0001..=10.0)
                     .logarithmic(true)
@@ -171,6 +180,11 @@ impl Widget for &mut epaint::TessellationOptions {
                 );
                 ui.checkbox(round_text_to_pixels, "Align text positions to pixel grid")
                     .on_hover_text("Most text already is, so don't expect to see a large change.");
+                ui.checkbox(
+                    round_line_segments_to_pixels,
+                    "Align thin lines and rectangle strokes to pixel grid",
+                )
+                .on_hover_text("Makes hairlines crisp at fractional pixels_per_point.");
 
                 ui.checkbox(debug_ignore_clip_rects, "Ignore clip rectangles");
                 ui.checkbox(debug_paint_clip_rects, "Paint clip rectangles");
@@ -2,7 +2,7 @@
 //! are sometimes painted behind or in front of other things.
 
 use crate::{Id, *};
-use epaint::{ClippedShape, Shape};
+use epaint::{emath::TSTransform, ClippedShape, Shape};
 
 /// Different layer categories
 #[derive(Clone, Copy, Debug, Hash, Eq, PartialEq, Ord, PartialOrd)]
@@ -158,6 +158,47 @@ impl PaintList {
             shape.translate(delta);
         }
     }
+
+    /// Multiply the colors of each [`Shape`] with this opacity, in-place
+    pub fn multiply_opacity(&mut self, opacity: f32) {
+        for ClippedShape { shape, .. } in &mut self.0 {
+            epaint::shape_transform::adjust_colors(shape, &|color| {
+                *color = color.gamma_multiply(opacity);
+            });
+        }
+    }
+
+    /// Transform each [`Shape`] and clip rectangle by this much, in-place
+    pub fn transform(&mut self, transform: TSTransform) {
+        for ClippedShape { clip_rect, shape } in &mut self.0 {
+            *clip_rect = transform * *clip_rect;
+            shape.transform(transform);
+        }
+    }
+}
+
+/// The shapes painted in one layer during a frame.
+///
+/// See [`crate::Context::capture_shapes`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct LayerShapes {
+    /// The layer the shapes were painted in.
+    pub layer_id: LayerId,
+
+    /// The shapes, in the order they are painted (back to front).
+    pub shapes: Vec<ClippedShape>,
+}
+
+/// The tessellated shapes of a layer.
+///
+/// See [`crate::Context::tessellate_layers`].
+#[derive(Clone, Debug)]
+pub struct LayerPrimitives {
+    /// The layer the shapes were painted in.
+    pub layer_id: LayerId,
+
+    /// The meshes and callbacks, in the order they are painted (back to front).
+    pub primitives: Vec<ClippedPrimitive>,
 }
 
 #[derive(Clone, Default)]
@@ -170,10 +211,16 @@ impl GraphicLayers {
             .or_default()
     }
 
-    pub fn drain(&mut self, area_order: &[LayerId]) -> Vec<ClippedShape> {
+    /// Take all the shapes, layer by layer, in paint order (back to front).
+    pub fn drain(
+        &mut self,
+        area_order: &[LayerId],
+        transforms: &ahash::HashMap<LayerId, TSTransform>,
+        opacities: &ahash::HashMap<LayerId, f32>,
+    ) -> Vec<LayerShapes> {
         crate::profile_function!();
 
-        let mut all_shapes: Vec<_> = Default::default();
+        let mut all_layers: Vec<LayerShapes> = Default::default();
 
         for &order in &Order::ALL {
             let order_map = &mut self.0[order as usize];
@@ -183,21 +230,39 @@ impl GraphicLayers {
             // Free it to save memory:
             order_map.retain(|_, list| !list.is_empty());
 
+            for (layer_id, list) in order_map.iter_mut() {
+                let layer_id = LayerId::new(order, *layer_id);
+                if let Some(transform) = transforms.get(&layer_id) {
+                    list.transform(*transform);
+                }
+                if let Some(opacity) = opacities.get(&layer_id) {
+                    list.multiply_opacity(*opacity);
+                }
+            }
+
             // First do the layers part of area_order:
             for layer_id in area_order {
                 if layer_id.order == order {
                     if let Some(list) = order_map.get_mut(&layer_id.id) {
-                        all_shapes.append(&mut list.0);
+                        all_layers.push(LayerShapes {
+                            layer_id: *layer_id,
+                            shapes: std::mem::take(&mut list.0),
+                        });
                     }
                 }
             }
 
             // Also draw areas that are missing in `area_order`:
-            for shapes in order_map.values_mut() {
-                all_shapes.append(&mut shapes.0);
+            for (id, list) in order_map.iter_mut() {
+                if !list.is_empty() {
+                    all_layers.push(LayerShapes {
+                        layer_id: LayerId::new(order, *id),
+                        shapes: std::mem::take(&mut list.0),
+                    });
+                }
             }
         }
 
-        all_shapes
+        all_layers
     }
 }
@@ -343,7 +343,10 @@ mod animation_manager;
 pub mod containers;
 mod context;
 mod data;
+mod drag_and_drop;
 mod frame_state;
+#[cfg(feature = "gallery")]
+pub mod gallery;
 pub(crate) mod grid;
 pub mod gui_zoom;
 mod id;
@@ -352,11 +355,13 @@ pub mod introspection;
 pub mod layers;
 mod layout;
 pub mod load;
+mod localization;
 mod memory;
 pub mod menu;
 pub mod os;
 mod painter;
 pub(crate) mod placer;
+mod quality_governor;
 mod response;
 mod sense;
 pub mod style;
@@ -364,6 +369,7 @@ mod ui;
 pub mod util;
 pub mod viewport;
 pub mod widget_text;
+mod widget_tree;
 pub mod widgets;
 
 #[cfg(feature = "callstack")]
@@ -390,32 +396,35 @@ pub use epaint::{
     text::{FontData, FontDefinitions, FontFamily, FontId, FontTweak},
     textures::{TextureFilter, TextureOptions, TexturesDelta},
     ClippedPrimitive, ColorImage, FontImage, ImageData, Mesh, PaintCallback, PaintCallbackInfo,
-    Rounding, Shape, Stroke, TextureHandle, TextureId,
+    Rounding, ScissorRect, Shape, Stroke, StrokeKind, TextureHandle, TextureId,
 };
 
 pub mod text {
     pub use crate::text_edit::CCursorRange;
     pub use epaint::text::{
-        cursor::CCursor, FontData, FontDefinitions, FontFamily, Fonts, Galley, LayoutJob,
-        LayoutSection, TextFormat, TextWrapping, TAB_SIZE,
+        cursor::CCursor, ElisionPosition, FontData, FontDefinitions, FontFamily, Fonts, Galley,
+        LayoutJob, LayoutSection, TextFormat, TextWrapping, TAB_SIZE,
     };
 }
 
 pub use {
     containers::*,
-    context::{Context, RequestRepaintInfo},
+    context::{Context, RepaintCause, RequestRepaintInfo},
     data::{
         input::*,
         output::{
-            self, CursorIcon, FullOutput, OpenUrl, PlatformOutput, UserAttentionType, WidgetInfo,
+            self, BlurBehind, CursorIcon, FullOutput, OpenUrl, PlatformOutput, UserAttentionType,
+            WidgetInfo,
         },
     },
+    drag_and_drop::DragAndDrop,
     grid::Grid,
     id::{Id, IdMap},
     input_state::{InputState, MultiTouchInfo, PointerState},
-    layers::{LayerId, Order},
+    layers::{LayerId, LayerPrimitives, LayerShapes, Order},
     layout::*,
     load::SizeHint,
+    localization::Localizer,
     memory::{Memory, Options},
     painter::Painter,
     response::{InnerResponse, Response},
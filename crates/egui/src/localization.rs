@@ -0,0 +1,46 @@
+//! Translation of the strings that egui shows on its own, see [`crate::Context::set_localizer`].
+
+/// Translates the strings that egui shows on its own,
+/// e.g. the "Reset" buttons, the tooltips of the color picker and of the style settings,
+/// and the error messages of a [`crate::NumberField`].
+///
+/// The strings are passed in English, and are shown as-is when they aren't translated.
+/// Some contain placeholders in braces, e.g. `"Loading {uri}…"`, which should be kept in the translation.
+///
+/// Set it with [`crate::Context::set_localizer`].
+///
+/// ```
+/// struct Swedish;
+///
+/// impl egui::Localizer for Swedish {
+///     fn translate(&self, text: &str) -> Option<String> {
+///         match text {
+///             "Reset" => Some("Återställ".to_owned()),
+///             "Loading {uri}…" => Some("Laddar {uri}…".to_owned()),
+///             _ => None,
+///         }
+///     }
+/// }
+///
+/// let ctx = egui::Context::default();
+/// ctx.set_localizer(Swedish);
+/// assert_eq!(ctx.translate("Reset"), "Återställ");
+/// assert_eq!(ctx.translate("Close"), "Close");
+/// ```
+pub trait Localizer: Send + Sync {
+    /// The translation of a built-in English string, or `None` to keep it in English.
+    fn translate(&self, text: &str) -> Option<String>;
+
+    /// The translation of a string that depends on a count,
+    /// given as its English singular and plural forms, e.g. `"{count} item"` and `"{count} items"`.
+    ///
+    /// Return the translated form to use for `count`, still with the `{count}` placeholder,
+    /// or `None` to keep it in English.
+    ///
+    /// The default implementation picks the form using the English rule,
+    /// and translates it with [`Self::translate`].
+    /// Override this for languages with other plural rules.
+    fn translate_plural(&self, count: u64, one: &str, other: &str) -> Option<String> {
+        self.translate(if count == 1 { one } else { other })
+    }
+}
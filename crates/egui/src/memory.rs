@@ -1,9 +1,13 @@
 #![warn(missing_docs)] // Let's keep this file well-documented.` to memory.rs
 
-use epaint::{emath::Rangef, vec2, Vec2};
+use epaint::{
+    emath::{Rangef, TSTransform},
+    vec2, Vec2,
+};
 
 use crate::{
     area,
+    id::IdSet,
     window::{self, WindowInteraction},
     EventFilter, Id, IdMap, InputState, LayerId, Pos2, Rect, Style, ViewportId, ViewportIdMap,
     ViewportIdSet,
@@ -90,6 +94,12 @@ pub struct Memory {
     #[cfg_attr(feature = "persistence", serde(skip))]
     everything_is_visible: bool,
 
+    /// Human-readable names of [`Id`]s, used when diagnosing [`Id`] clashes.
+    ///
+    /// Only filled in if [`Options::retain_id_names`] is set.
+    #[cfg_attr(feature = "persistence", serde(skip))]
+    id_names: IdMap<String>,
+
     // -------------------------------------------------
     // Per-viewport:
     areas: ViewportIdMap<Areas>,
@@ -115,6 +125,7 @@ impl Default for Memory {
             areas: Default::default(),
             popup: Default::default(),
             everything_is_visible: Default::default(),
+            id_names: Default::default(),
         };
         slf.interactions.entry(slf.viewport_id).or_default();
         slf.areas.entry(slf.viewport_id).or_default();
@@ -216,6 +227,37 @@ pub struct Options {
     ///
     /// By default this is `true` in debug builds.
     pub warn_on_id_clash: bool,
+
+    /// Remember the names given to [`Id`]s with [`Memory::set_id_name`],
+    /// so that [`Id`] clashes can be reported by name rather than by hash.
+    ///
+    /// The names are kept for the lifetime of the [`crate::Context`].
+    ///
+    /// By default this is `true` in debug builds.
+    pub retain_id_names: bool,
+
+    /// If `true`, egui hashes what is painted each frame and sets [`crate::FullOutput::unchanged`]
+    /// when it is identical to the previous frame.
+    ///
+    /// Custom integrations can use this to skip presenting static frames, e.g. to save battery.
+    /// `eframe` and `egui_glow` don't (yet), so setting this has no effect with them.
+    /// The hashing has a small cost every frame, so this is `false` by default.
+    pub detect_unchanged_frames: bool,
+
+    /// If set, egui reduces the tessellation quality while frames take longer than this many seconds,
+    /// and restores it once they are well within the budget again.
+    ///
+    /// The reduced quality turns off feathering (anti-aliasing),
+    /// and uses fewer segments for circles, rounded corners and shadows.
+    ///
+    /// The frame time is measured as the time between frames while egui is repainting continuously,
+    /// so it includes painting and waiting for vsync.
+    /// A budget of `1.0 / 30.0` is a good choice for a 60 Hz display.
+    ///
+    /// See also [`crate::Context::is_quality_reduced`].
+    ///
+    /// Default: `None`.
+    pub frame_time_budget: Option<f32>,
 }
 
 impl Default for Options {
@@ -228,6 +270,9 @@ impl Default for Options {
             screen_reader: false,
             preload_font_glyphs: true,
             warn_on_id_clash: cfg!(debug_assertions),
+            retain_id_names: cfg!(debug_assertions),
+            detect_unchanged_frames: false,
+            frame_time_budget: None,
         }
     }
 }
@@ -265,6 +310,12 @@ pub(crate) struct Interaction {
     /// Any interest in catching clicks this frame?
     /// Cleared to false at start of each frame.
     pub drag_interest: bool,
+
+    /// When did the pointer start hovering the widgets that were hovered last frame?
+    hover_start_times_prev_frame: IdMap<f64>,
+
+    /// When did the pointer start hovering the widgets that are hovered this frame?
+    hover_start_times: IdMap<f64>,
 }
 
 /// Keeps tracks of what widget has keyboard focus
@@ -276,6 +327,9 @@ pub(crate) struct Focus {
     /// What had keyboard focus previous frame?
     id_previous_frame: Option<Id>,
 
+    /// The layer of the widget that lost the focus to Escape this frame.
+    layer_escaped_from: Option<LayerId>,
+
     /// Give focus to this widget next frame
     id_next_frame: Option<Id>,
 
@@ -294,6 +348,24 @@ pub(crate) struct Focus {
 
     /// A cache of widget ids that are interested in focus with their corresponding rectangles.
     focus_widgets_cache: IdMap<Rect>,
+
+    /// The layers that keep the keyboard focus to themselves, innermost last.
+    traps: Vec<FocusTrap>,
+}
+
+/// A layer that keeps the keyboard focus to itself, see [`Memory::trap_focus`].
+#[derive(Clone, Debug)]
+struct FocusTrap {
+    layer_id: LayerId,
+
+    /// What had keyboard focus when the trap was set. Gets the focus back when the trap is gone.
+    restore_focus: Option<Id>,
+
+    /// The widgets in the trap that have been interested in focus.
+    widgets: IdSet,
+
+    /// Was the trap set this frame?
+    is_used: bool,
 }
 
 /// The widget with focus.
@@ -301,6 +373,9 @@ pub(crate) struct Focus {
 struct FocusWidget {
     pub id: Id,
     pub filter: EventFilter,
+
+    /// The layer of the widget, once it has been shown.
+    pub layer_id: Option<LayerId>,
 }
 
 impl FocusWidget {
@@ -308,6 +383,7 @@ impl FocusWidget {
         Self {
             id,
             filter: Default::default(),
+            layer_id: None,
         }
     }
 }
@@ -318,6 +394,21 @@ impl Interaction {
         self.click_id.is_some() || self.drag_id.is_some()
     }
 
+    /// Note that the widget is hovered this frame, remembering when the hovering started.
+    pub fn set_hovered(&mut self, id: Id, time: f64) {
+        let start_time = self
+            .hover_start_times_prev_frame
+            .get(&id)
+            .copied()
+            .unwrap_or(time);
+        self.hover_start_times.insert(id, start_time);
+    }
+
+    /// When did the pointer start hovering the widget, if it is hovered this frame?
+    pub fn hover_start_time(&self, id: Id) -> Option<f64> {
+        self.hover_start_times.get(&id).copied()
+    }
+
     fn begin_frame(
         &mut self,
         prev_input: &crate::input_state::InputState,
@@ -325,6 +416,7 @@ impl Interaction {
     ) {
         self.click_interest = false;
         self.drag_interest = false;
+        self.hover_start_times_prev_frame = std::mem::take(&mut self.hover_start_times);
 
         if !prev_input.pointer.could_any_button_be_click() {
             self.click_id = None;
@@ -346,8 +438,19 @@ impl Focus {
         self.focused_widget.as_ref().map(|w| w.id)
     }
 
+    /// The layer of the widget with keyboard focus, if known.
+    ///
+    /// This includes the layer of a widget that lost the focus to Escape this frame.
+    pub fn focused_layer(&self) -> Option<LayerId> {
+        match &self.focused_widget {
+            Some(focused_widget) => focused_widget.layer_id,
+            None => self.layer_escaped_from,
+        }
+    }
+
     fn begin_frame(&mut self, new_input: &crate::data::input::RawInput) {
         self.id_previous_frame = self.focused();
+        self.layer_escaped_from = None;
         if let Some(id) = self.id_next_frame.take() {
             self.focused_widget = Some(FocusWidget::new(id));
         }
@@ -383,7 +486,9 @@ impl Focus {
                             }
                         }
                         crate::Key::Escape => {
-                            self.focused_widget = None;
+                            if let Some(focused_widget) = self.focused_widget.take() {
+                                self.layer_escaped_from = focused_widget.layer_id;
+                            }
                             Some(FocusDirection::None)
                         }
                         _ => None,
@@ -408,6 +513,23 @@ impl Focus {
     }
 
     pub(crate) fn end_frame(&mut self, used_ids: &IdMap<Rect>) {
+        let mut closed_traps = vec![];
+        self.traps.retain_mut(|trap| {
+            if std::mem::take(&mut trap.is_used) {
+                true
+            } else {
+                closed_traps.push(trap.clone());
+                false
+            }
+        });
+        for trap in closed_traps.into_iter().rev() {
+            // Give the focus back, unless the user has moved it somewhere else:
+            let focus_was_in_trap = self.focused().map_or(true, |id| trap.widgets.contains(&id));
+            if focus_was_in_trap {
+                self.focused_widget = trap.restore_focus.map(FocusWidget::new);
+            }
+        }
+
         if self.focus_direction.is_cardinal() {
             if let Some(found_widget) = self.find_widget_in_direction(used_ids) {
                 self.focused_widget = Some(FocusWidget::new(found_widget));
@@ -429,7 +551,45 @@ impl Focus {
         self.id_previous_frame == Some(id)
     }
 
-    fn interested_in_focus(&mut self, id: Id) {
+    fn trap(&self) -> Option<&FocusTrap> {
+        self.traps.last()
+    }
+
+    fn trap_focus(&mut self, layer_id: LayerId) {
+        if let Some(trap) = self.traps.iter_mut().find(|trap| trap.layer_id == layer_id) {
+            trap.is_used = true;
+        } else {
+            self.traps.push(FocusTrap {
+                layer_id,
+                restore_focus: self.focused(),
+                widgets: Default::default(),
+                is_used: true,
+            });
+            // Don't let the widgets behind the trap keep the focus, or be reached with the arrow keys:
+            self.focused_widget = None;
+            self.focus_widgets_cache.clear();
+        }
+    }
+
+    /// `layer_id` is `None` if unknown.
+    fn interested_in_focus(&mut self, id: Id, layer_id: Option<LayerId>) {
+        if let Some(trap) = self.traps.last_mut() {
+            let is_in_trap = match layer_id {
+                Some(layer_id) => layer_id == trap.layer_id,
+                None => trap.widgets.contains(&id),
+            };
+            if !is_in_trap {
+                return; // Tab and the arrow keys stay within the trap.
+            }
+            trap.widgets.insert(id);
+        }
+
+        if let Some(focused_widget) = &mut self.focused_widget {
+            if focused_widget.id == id && layer_id.is_some() {
+                focused_widget.layer_id = layer_id;
+            }
+        }
+
         #[cfg(feature = "accesskit")]
         {
             if self.id_requested_by_accesskit == Some(id.accesskit_id()) {
@@ -650,14 +810,29 @@ impl Memory {
         self.interaction().focus.focused()
     }
 
+    /// Which layer has the widget with keyboard focus?
+    ///
+    /// If Escape took the focus away this frame, this is the layer that had it,
+    /// so that the cancel button of that layer can react (see [`crate::Button::cancel_button`]).
+    ///
+    /// `None` if no widget has focus, or if the widget hasn't been shown since it got the focus.
+    pub fn focused_layer(&self) -> Option<LayerId> {
+        self.interaction().focus.focused_layer()
+    }
+
     /// Set an event filter for a widget.
     ///
     /// This allows you to control whether the widget will loose focus
     /// when the user presses tab, arrow keys, or escape.
+    /// The keys in the filter are left to the widget,
+    /// while the other keys move the keyboard focus as usual.
     ///
     /// You must first give focus to the widget before calling this.
+    /// Call it every frame the widget has focus, starting with the frame it gained focus,
+    /// so that the filter is in place for the keys pressed the next frame.
+    /// The filter is reset when the focus moves to another widget.
     pub fn set_focus_lock_filter(&mut self, id: Id, event_filter: EventFilter) {
-        if self.had_focus_last_frame(id) && self.has_focus(id) {
+        if self.has_focus(id) {
             if let Some(focused) = &mut self.interaction_mut().focus.focused_widget {
                 if focused.id == id {
                     focused.filter = event_filter;
@@ -688,6 +863,18 @@ impl Memory {
         self.interaction_mut().focus.focused_widget = Some(FocusWidget::new(id));
     }
 
+    /// Give keyboard focus to the next widget that is interested in it,
+    /// as if the user had pressed tab.
+    pub(crate) fn focus_next_interested_widget(&mut self) {
+        self.interaction_mut().focus.give_to_next = true;
+    }
+
+    /// Stop the arrow keys and tab from moving the keyboard focus this frame,
+    /// e.g. because a menu used them for its own navigation.
+    pub(crate) fn stop_focus_navigation(&mut self) {
+        self.interaction_mut().focus.reset_focus();
+    }
+
     /// Surrender keyboard focus for a specific widget.
     /// See also [`crate::Response::surrender_focus`].
     #[inline(always)]
@@ -705,9 +892,56 @@ impl Memory {
     /// e.g. before deciding which type of underlying widget to use,
     /// as in the [`crate::DragValue`] widget, so a widget can be focused
     /// and rendered correctly in a single frame.
+    ///
+    /// While a focus trap is set (see [`Self::trap_focus`]), this is ignored unless the widget
+    /// is already known to be in the trap. Use [`Self::interested_in_focus_on_layer`] if you know the layer.
     #[inline(always)]
     pub fn interested_in_focus(&mut self, id: Id) {
-        self.interaction_mut().focus.interested_in_focus(id);
+        self.interaction_mut().focus.interested_in_focus(id, None);
+    }
+
+    /// Like [`Self::interested_in_focus`], for a widget on the given layer.
+    ///
+    /// The layer decides if the widget can get the focus while a focus trap is set, see [`Self::trap_focus`].
+    #[inline(always)]
+    pub fn interested_in_focus_on_layer(&mut self, id: Id, layer_id: LayerId) {
+        self.interaction_mut()
+            .focus
+            .interested_in_focus(id, Some(layer_id));
+    }
+
+    /// Keep the keyboard focus within this layer, e.g. a modal dialog or a popup menu.
+    ///
+    /// While the trap is set, tab and the arrow keys only move the focus between
+    /// the widgets of the layer, and keyboard shortcuts are only handled by the layer
+    /// (see [`Self::is_outside_focus_trap`]).
+    /// Whatever had the focus when the trap was set loses it,
+    /// and gets it back once the trap is gone.
+    ///
+    /// Call this every frame the layer is shown.
+    /// The menus and combo boxes of egui already do this,
+    /// but [`crate::popup::popup_below_widget`] and friends don't, since they are also used for
+    /// e.g. suggestions below a text field, which should keep the focus.
+    ///
+    /// If several layers set a trap, the last one to be set wins.
+    pub fn trap_focus(&mut self, layer_id: LayerId) {
+        self.interaction_mut().focus.trap_focus(layer_id);
+    }
+
+    /// The layer which is currently keeping the keyboard focus to itself, if any.
+    ///
+    /// See [`Self::trap_focus`].
+    pub fn focus_trap(&self) -> Option<LayerId> {
+        self.interaction().focus.trap().map(|trap| trap.layer_id)
+    }
+
+    /// Is there a focus trap which this layer is outside of?
+    ///
+    /// If so, widgets on this layer should ignore the keyboard, e.g. their shortcuts.
+    /// See [`Self::trap_focus`].
+    pub fn is_outside_focus_trap(&self, layer_id: LayerId) -> bool {
+        self.focus_trap()
+            .map_or(false, |trap_layer_id| trap_layer_id != layer_id)
     }
 
     /// Stop editing of active [`TextEdit`](crate::TextEdit) (if any).
@@ -831,6 +1065,26 @@ impl Memory {
     }
 }
 
+/// ## Id names
+/// Human-readable names for [`Id`]s, for debugging.
+impl Memory {
+    /// Give the [`Id`] a human-readable name, shown when the [`Id`] clashes with another.
+    ///
+    /// Does nothing unless [`Options::retain_id_names`] is set.
+    ///
+    /// Windows and collapsing headers name their [`Id`]s automatically.
+    pub fn set_id_name(&mut self, id: Id, name: &str) {
+        if self.options.retain_id_names && self.id_name(id) != Some(name) {
+            self.id_names.insert(id, name.to_owned());
+        }
+    }
+
+    /// The name given to the [`Id`] with [`Self::set_id_name`], if any.
+    pub fn id_name(&self, id: Id) -> Option<&str> {
+        self.id_names.get(&id).map(String::as_str)
+    }
+}
+
 // ----------------------------------------------------------------------------
 
 /// Keeps track of [`Area`](crate::containers::area::Area)s, which are free-floating [`Ui`](crate::Ui)s.
@@ -853,6 +1107,23 @@ pub struct Areas {
     /// So if you close three windows and then reopen them all in one frame,
     /// they will all be sent to the top, but keep their previous internal order.
     wants_to_be_on_top: ahash::HashSet<LayerId>,
+
+    /// Transforms of whole layers, set with [`crate::Context::transform_layer`].
+    #[cfg_attr(feature = "serde", serde(skip))]
+    layer_transforms: ahash::HashMap<LayerId, TSTransform>,
+
+    /// Opacity of whole layers, set with [`crate::Context::set_layer_opacity`].
+    #[cfg_attr(feature = "serde", serde(skip))]
+    layer_opacities: ahash::HashMap<LayerId, f32>,
+
+    /// Background canvases (see [`crate::Area::canvas`]) put themselves in here every frame.
+    /// At the end of the frame, they are sorted below the other layers of the same [`Order`](crate::Order).
+    wants_to_be_on_bottom: ahash::HashSet<LayerId>,
+
+    /// Child layers (see [`crate::Area::child_of`]) put themselves in here every frame, mapped to their parent.
+    /// At the end of the frame, children are sorted right above their parent.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    parents: ahash::HashMap<LayerId, LayerId>,
 }
 
 impl Areas {
@@ -883,6 +1154,9 @@ impl Areas {
             if self.is_visible(layer) {
                 if let Some(state) = self.areas.get(&layer.id) {
                     let mut rect = state.rect();
+                    if let Some(transform) = self.layer_transforms.get(layer) {
+                        rect = *transform * rect;
+                    }
                     if state.interactable {
                         // Allow us to resize by dragging just outside the window:
                         rect = rect.expand(resize_interact_radius_side);
@@ -912,6 +1186,15 @@ impl Areas {
             .collect()
     }
 
+    /// The top-most visible [`crate::Order::Middle`] layer, i.e. the window on top.
+    pub(crate) fn top_visible_window(&self) -> Option<LayerId> {
+        self.order
+            .iter()
+            .rev()
+            .find(|layer| layer.order == crate::Order::Middle && self.is_visible(layer))
+            .copied()
+    }
+
     pub(crate) fn visible_windows(&self) -> Vec<&area::State> {
         self.visible_layer_ids()
             .iter()
@@ -929,24 +1212,373 @@ impl Areas {
         }
     }
 
+    pub(crate) fn layer_transform(&self, layer_id: LayerId) -> Option<TSTransform> {
+        self.layer_transforms.get(&layer_id).copied()
+    }
+
+    pub(crate) fn layer_transforms(&self) -> &ahash::HashMap<LayerId, TSTransform> {
+        &self.layer_transforms
+    }
+
+    pub(crate) fn set_layer_transform(&mut self, layer_id: LayerId, transform: TSTransform) {
+        if transform.is_identity() {
+            self.layer_transforms.remove(&layer_id);
+        } else {
+            self.layer_transforms.insert(layer_id, transform);
+        }
+    }
+
+    pub(crate) fn layer_opacity(&self, layer_id: LayerId) -> Option<f32> {
+        self.layer_opacities.get(&layer_id).copied()
+    }
+
+    pub(crate) fn layer_opacities(&self) -> &ahash::HashMap<LayerId, f32> {
+        &self.layer_opacities
+    }
+
+    pub(crate) fn set_layer_opacity(&mut self, layer_id: LayerId, opacity: f32) {
+        if opacity >= 1.0 || !opacity.is_finite() {
+            self.layer_opacities.remove(&layer_id);
+        } else {
+            self.layer_opacities.insert(layer_id, opacity.max(0.0));
+        }
+    }
+
+    /// Keep the given layer below all other layers in its [`Order`](crate::Order).
+    ///
+    /// Must be called every frame.
+    pub(crate) fn move_to_bottom(&mut self, layer_id: LayerId) {
+        self.visible_current_frame.insert(layer_id);
+        self.wants_to_be_on_bottom.insert(layer_id);
+
+        if !self.order.iter().any(|x| *x == layer_id) {
+            self.order.insert(0, layer_id);
+        }
+    }
+
+    /// Keep the `child` layer right above the `parent` layer.
+    ///
+    /// Must be called every frame.
+    pub(crate) fn set_parent(&mut self, child: LayerId, parent: LayerId) {
+        if child != parent {
+            self.parents.insert(child, parent);
+        }
+    }
+
     pub(crate) fn end_frame(&mut self) {
         let Self {
             visible_last_frame,
             visible_current_frame,
             order,
             wants_to_be_on_top,
+            wants_to_be_on_bottom,
+            parents,
             ..
         } = self;
 
         std::mem::swap(visible_last_frame, visible_current_frame);
         visible_current_frame.clear();
-        order.sort_by_key(|layer| (layer.order, wants_to_be_on_top.contains(layer)));
+
+        // Bringing a child to the top brings its ancestors along:
+        let mut ancestors_on_top = vec![];
+        for layer in wants_to_be_on_top.iter() {
+            let mut layer = *layer;
+            // Bounded, to protect against cycles:
+            for _ in 0..parents.len() {
+                let Some(parent) = parents.get(&layer) else {
+                    break;
+                };
+                ancestors_on_top.push(*parent);
+                layer = *parent;
+            }
+        }
+        wants_to_be_on_top.extend(ancestors_on_top);
+
+        order.sort_by_key(|layer| {
+            (
+                layer.order,
+                !wants_to_be_on_bottom.contains(layer),
+                wants_to_be_on_top.contains(layer),
+            )
+        });
+
+        if !parents.is_empty() {
+            sort_children_above_parents(order, parents);
+        }
+
         wants_to_be_on_top.clear();
+        wants_to_be_on_bottom.clear();
+        parents.clear();
+    }
+}
+
+/// Move every child layer to right above its parent, keeping the relative order of siblings.
+fn sort_children_above_parents(
+    order: &mut Vec<LayerId>,
+    parents: &ahash::HashMap<LayerId, LayerId>,
+) {
+    fn push_with_children(
+        layer: LayerId,
+        children: &ahash::HashMap<LayerId, Vec<LayerId>>,
+        new_order: &mut Vec<LayerId>,
+        added: &mut ahash::HashSet<LayerId>,
+    ) {
+        if added.insert(layer) {
+            new_order.push(layer);
+            for child in children.get(&layer).into_iter().flatten() {
+                push_with_children(*child, children, new_order, added);
+            }
+        }
+    }
+
+    let in_order: ahash::HashSet<LayerId> = order.iter().copied().collect();
+    let mut children: ahash::HashMap<LayerId, Vec<LayerId>> = Default::default();
+    for layer in order.iter() {
+        if let Some(parent) = parents.get(layer) {
+            // Children can't leave their own `Order`:
+            if parent.order == layer.order && in_order.contains(parent) {
+                children.entry(*parent).or_default().push(*layer);
+            }
+        }
+    }
+
+    let mut new_order = Vec::with_capacity(order.len());
+    let mut added = ahash::HashSet::default();
+    for layer in order.iter() {
+        let is_child = parents
+            .get(layer)
+            .map_or(false, |p| children.contains_key(p));
+        if !is_child {
+            push_with_children(*layer, &children, &mut new_order, &mut added);
+        }
+    }
+
+    // Layers in a parent cycle never had a root to be added under:
+    for layer in order.iter() {
+        if !added.contains(layer) {
+            new_order.push(*layer);
+        }
     }
+
+    *order = new_order;
 }
 
 // ----------------------------------------------------------------------------
 
+#[test]
+fn focus_lock_filter_from_the_frame_focus_is_gained() {
+    use crate::{Context, Event, Key, Modifiers, RawInput};
+
+    fn run(ctx: &Context, keys: &[Key], add_contents: &mut dyn FnMut(&mut crate::Ui)) {
+        let events = keys
+            .iter()
+            .map(|&key| Event::Key {
+                key,
+                pressed: true,
+                repeat: false,
+                modifiers: Modifiers::NONE,
+            })
+            .collect();
+        let input = RawInput {
+            events,
+            ..Default::default()
+        };
+        let _ = ctx.run(input, |ctx| {
+            crate::CentralPanel::default().show(ctx, |ui| add_contents(ui));
+        });
+    }
+
+    let ctx = Context::default();
+    let ids = std::cell::Cell::new([Id::NULL; 2]);
+    let mut add_contents = |ui: &mut crate::Ui| {
+        let first = ui.button("first");
+        let second = ui.button("second");
+        if first.has_focus() {
+            // The first widget uses the arrow keys itself:
+            let filter = EventFilter {
+                arrows: true,
+                ..Default::default()
+            };
+            ui.memory_mut(|mem| mem.set_focus_lock_filter(first.id, filter));
+        }
+        ids.set([first.id, second.id]);
+    };
+
+    run(&ctx, &[], &mut add_contents);
+    run(&ctx, &[Key::Tab], &mut add_contents);
+    assert_eq!(ctx.memory(|mem| mem.focus()), Some(ids.get()[0]));
+
+    // The arrow keys pressed right after the focus was gained don't move the focus…
+    run(&ctx, &[Key::ArrowDown], &mut add_contents);
+    run(&ctx, &[], &mut add_contents);
+    assert_eq!(ctx.memory(|mem| mem.focus()), Some(ids.get()[0]));
+
+    // …but tab does:
+    run(&ctx, &[Key::Tab], &mut add_contents);
+    run(&ctx, &[], &mut add_contents);
+    assert_eq!(ctx.memory(|mem| mem.focus()), Some(ids.get()[1]));
+
+    // The second widget has no filter, so it lets the arrow keys move the focus:
+    run(&ctx, &[Key::ArrowUp], &mut add_contents);
+    run(&ctx, &[], &mut add_contents);
+    assert_eq!(ctx.memory(|mem| mem.focus()), Some(ids.get()[0]));
+}
+
+#[test]
+fn popup_of_popup_stays_above_its_parent() {
+    use crate::{Area, Context, Id, LayerId, Order};
+
+    let ctx = Context::default();
+    let menu = LayerId::new(Order::Foreground, Id::new("menu"));
+    let popup = LayerId::new(Order::Foreground, Id::new("popup"));
+    let run = |bring_menu_to_top: bool| {
+        let _ = ctx.run(Default::default(), |ctx| {
+            if bring_menu_to_top {
+                ctx.memory_mut(|mem| mem.areas_mut().move_to_top(menu));
+            }
+            Area::new(menu.id).order(Order::Foreground).show(ctx, |ui| {
+                Area::new(popup.id).popup_of(ui).show(ctx, |ui| {
+                    ui.label("Popup");
+                });
+            });
+        });
+    };
+    run(false);
+    run(true);
+
+    let order = ctx.memory(|mem| mem.areas().order().to_vec());
+    let position = |layer_id| order.iter().position(|l| *l == layer_id);
+    assert!(position(menu) < position(popup));
+}
+
+#[test]
+fn child_area_stays_within_its_parent() {
+    use crate::{pos2, vec2, Area, CentralPanel, Context, Id, Layout, Rect};
+
+    let ctx = Context::default();
+    let parent_rect = Rect::from_min_size(pos2(100.0, 100.0), vec2(100.0, 100.0));
+    let child_rect = |pos, size| {
+        for _ in 0..2 {
+            let _ = ctx.run(Default::default(), |ctx| {
+                CentralPanel::default().show(ctx, |ui| {
+                    let parent = ui.child_ui(parent_rect, Layout::default());
+                    Area::new(Id::new("child"))
+                        .child_of(&parent)
+                        .current_pos(pos)
+                        .show(ctx, |ui| {
+                            ui.allocate_exact_size(size, crate::Sense::hover())
+                        });
+                });
+            });
+        }
+        ctx.memory(|mem| mem.areas().get(Id::new("child")).unwrap().rect())
+    };
+
+    let small = vec2(50.0, 50.0);
+    assert_eq!(child_rect(pos2(0.0, 0.0), small).min, parent_rect.min);
+    assert_eq!(child_rect(pos2(500.0, 500.0), small).max, parent_rect.max);
+
+    // Too large for the parent:
+    let large = vec2(300.0, 300.0);
+    assert_eq!(child_rect(pos2(0.0, 0.0), large).min, parent_rect.min);
+    assert_eq!(child_rect(pos2(500.0, 500.0), large).min, parent_rect.min);
+}
+
+#[test]
+fn focus_trap_keeps_tab_within_the_layer_and_restores_focus() {
+    use crate::{Area, Context, Event, Key, Modifiers, RawInput};
+
+    let ctx = Context::default();
+    let ids = std::cell::Cell::new([Id::NULL; 4]);
+    let run = |keys: &[Key], show_popup: bool| {
+        let events = keys
+            .iter()
+            .map(|&key| Event::Key {
+                key,
+                pressed: true,
+                repeat: false,
+                modifiers: Modifiers::NONE,
+            })
+            .collect();
+        let input = RawInput {
+            events,
+            ..Default::default()
+        };
+        let _ = ctx.run(input, |ctx| {
+            let mut new_ids = ids.get();
+            crate::CentralPanel::default().show(ctx, |ui| {
+                new_ids[0] = ui.button("background 1").id;
+                new_ids[1] = ui.button("background 2").id;
+            });
+            if show_popup {
+                Area::new(Id::new("popup")).show(ctx, |ui| {
+                    let layer_id = ui.layer_id();
+                    ui.memory_mut(|mem| mem.trap_focus(layer_id));
+                    new_ids[2] = ui.button("popup 1").id;
+                    new_ids[3] = ui.button("popup 2").id;
+                });
+            }
+            ids.set(new_ids);
+        });
+    };
+
+    run(&[], false);
+    run(&[Key::Tab], false);
+    let background = ids.get()[0];
+    assert_eq!(ctx.memory(|mem| mem.focus()), Some(background));
+
+    // Opening the popup takes the focus away from the background:
+    run(&[], true);
+    run(&[], true);
+    let [_, _, popup_first, popup_second] = ids.get();
+    assert_eq!(ctx.memory(|mem| mem.focus()), None);
+
+    // Tab cycles through the popup (which is itself focusable) only:
+    let popup = Id::new("popup").with("move");
+    let mut focused = vec![];
+    for _ in 0..4 {
+        run(&[Key::Tab], true);
+        run(&[], true);
+        focused.push(ctx.memory(|mem| mem.focus()));
+    }
+    assert_eq!(focused, [popup, popup_first, popup_second, popup].map(Some));
+
+    // Closing the popup gives the focus back:
+    run(&[], false);
+    assert_eq!(ctx.memory(|mem| mem.focus()), Some(background));
+}
+
+#[test]
+fn text_edit_keeps_focus_when_its_suggestion_popup_opens() {
+    let ctx = crate::Context::default();
+    let mut text = String::new();
+    let mut text_edit_id = Id::NULL;
+    let mut run = |first_frame: bool| {
+        let _ = ctx.run(Default::default(), |ctx| {
+            crate::CentralPanel::default().show(ctx, |ui| {
+                let response = ui.text_edit_singleline(&mut text);
+                text_edit_id = response.id;
+                let popup_id = Id::new("suggestions");
+                if first_frame {
+                    response.request_focus();
+                } else if response.has_focus() {
+                    ui.memory_mut(|mem| mem.open_popup(popup_id));
+                }
+                crate::popup::popup_below_widget(ui, popup_id, &response, |ui| {
+                    let _ = ui.button("suggestion");
+                });
+            });
+        });
+    };
+
+    run(true);
+    for _ in 0..3 {
+        run(false);
+    }
+    assert!(ctx.memory(|mem| mem.is_popup_open(Id::new("suggestions"))));
+    assert_eq!(ctx.memory(|mem| mem.focus()), Some(text_edit_id));
+}
+
 #[test]
 fn memory_impl_send_sync() {
     fn assert_send_sync<T: Send + Sync>() {}
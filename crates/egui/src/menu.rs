@@ -1,5 +1,9 @@
 //! Menu bar functionality (very basic so far).
 //!
+//! Menus can be used with the keyboard: a focused menu button opens with enter, space or the down arrow.
+//! The up and down arrows move between the items, right and left enter and leave sub-menus,
+//! enter activates an item, escape closes the menu, and typing a letter jumps to the next item starting with it.
+//!
 //! Usage:
 //! ```
 //! fn show_menu(ui: &mut egui::Ui) {
@@ -144,6 +148,8 @@ pub(crate) fn menu_ui<'c, R>(
         menu_state.rect.min
     };
 
+    ctx.frame_state_mut(|fs| fs.menu_open_this_frame = true);
+
     let area = Area::new(menu_id)
         .order(Order::Foreground)
         .fixed_pos(pos)
@@ -151,8 +157,16 @@ pub(crate) fn menu_ui<'c, R>(
         .interactable(true);
 
     area.show(ctx, |ui| {
+        let layer_id = ui.layer_id();
+        ui.memory_mut(|mem| mem.trap_focus(layer_id));
         set_menu_style(ui.style_mut());
 
+        let keyboard_nav = {
+            let mut menu_state = menu_state_arc.write();
+            let has_focus = menu_state.has_focus;
+            MenuKeyboardNav::begin(ui, has_focus, &mut menu_state.focus_first_item)
+        };
+
         let frame = Frame::menu(ui.style()).show(ui, |ui| {
             const DEFAULT_MENU_WIDTH: f32 = 150.0; // TODO(emilk): add to ui.spacing
             ui.set_max_width(DEFAULT_MENU_WIDTH);
@@ -161,7 +175,11 @@ pub(crate) fn menu_ui<'c, R>(
                 .inner
         });
 
-        menu_state_arc.write().rect = frame.response.rect;
+        let has_focus = keyboard_nav.end(ui, frame.response.rect);
+
+        let mut menu_state = menu_state_arc.write();
+        menu_state.rect = frame.response.rect;
+        menu_state.has_focus = has_focus;
 
         frame.inner
     })
@@ -247,6 +265,10 @@ impl MenuRootManager {
         if let Some(root) = self.inner.as_mut() {
             let (menu_response, inner_response) = root.show(response, add_contents);
             if MenuResponse::Close == menu_response {
+                if root.menu_state.read().focus_in_hierarchy() {
+                    // E.g. an item was activated with the keyboard:
+                    response.ctx.memory_mut(|mem| mem.request_focus(root.id));
+                }
                 self.inner = None;
             }
             inner_response
@@ -321,8 +343,11 @@ impl MenuRoot {
             return MenuResponse::Close;
         } else if (response.clicked() && !root.is_menu_open(id))
             || (response.hovered() && root.is_some())
+            || (response.has_focus()
+                && !root.is_menu_open(id)
+                && response.ctx.input(|i| i.key_pressed(Key::ArrowDown)))
         {
-            // menu not open and button clicked
+            // menu not open and button clicked (or down arrow pressed while focused)
             // or button hovered while other menu is open
             let mut pos = response.rect.left_bottom();
             if let Some(root) = root.inner.as_mut() {
@@ -366,7 +391,16 @@ impl MenuRoot {
     ) -> MenuResponse {
         let response = response.interact(Sense::click());
         response.ctx.input(|input| {
+            if input.key_pressed(Key::Escape) && root.as_ref().map_or(false, |root| root.id == id) {
+                return MenuResponse::Close;
+            }
+
             let pointer = &input.pointer;
+            if response.long_touched() {
+                if let Some(pos) = pointer.interact_pos() {
+                    return MenuResponse::Create(pos, id);
+                }
+            }
             if pointer.any_pressed() {
                 if let Some(pos) = pointer.interact_pos() {
                     let mut destroy = false;
@@ -388,12 +422,32 @@ impl MenuRoot {
         })
     }
 
-    fn handle_menu_response(root: &mut MenuRootManager, menu_response: MenuResponse) {
+    fn handle_menu_response(
+        response: &Response,
+        root: &mut MenuRootManager,
+        menu_response: MenuResponse,
+    ) {
         match menu_response {
             MenuResponse::Create(pos, id) => {
-                root.inner = Some(MenuRoot::new(pos, id));
+                let new_root = MenuRoot::new(pos, id);
+                if response.has_focus() {
+                    // Opened with the keyboard, so let the keyboard continue into the menu:
+                    new_root.menu_state.write().focus_first_item = true;
+                    response.ctx.memory_mut(|mem| mem.stop_focus_navigation());
+                }
+                root.inner = Some(new_root);
+            }
+            MenuResponse::Close => {
+                if let Some(old_root) = &root.inner {
+                    if old_root.menu_state.read().focus_in_hierarchy() {
+                        // E.g. closed with escape. Give the focus back to where it came from:
+                        response
+                            .ctx
+                            .memory_mut(|mem| mem.request_focus(old_root.id));
+                    }
+                }
+                root.inner = None;
             }
-            MenuResponse::Close => root.inner = None,
             MenuResponse::Stay => {}
         }
     }
@@ -401,13 +455,13 @@ impl MenuRoot {
     /// Respond to secondary (right) clicks.
     pub fn context_click_interaction(response: &Response, root: &mut MenuRootManager, id: Id) {
         let menu_response = Self::context_interaction(response, root, id);
-        Self::handle_menu_response(root, menu_response);
+        Self::handle_menu_response(response, root, menu_response);
     }
 
     // Responds to primary clicks.
     pub fn stationary_click_interaction(response: &Response, root: &mut MenuRootManager, id: Id) {
         let menu_response = Self::stationary_interaction(response, root, id);
-        Self::handle_menu_response(root, menu_response);
+        Self::handle_menu_response(response, root, menu_response);
     }
 }
 
@@ -534,10 +588,36 @@ impl SubMenu {
         self.parent_state
             .write()
             .submenu_button_interaction(ui, sub_id, &button);
+
+        // Enter the submenu with the keyboard:
+        if button.has_focus() && (button.clicked() || ui.input(|i| i.key_pressed(Key::ArrowRight)))
+        {
+            let mut parent_state = self.parent_state.write();
+            parent_state.open_submenu(sub_id, button.rect.right_top());
+            if let Some(sub_menu) = parent_state.submenu(sub_id) {
+                sub_menu.write().focus_first_item = true;
+            }
+            ui.memory_mut(|mem| mem.stop_focus_navigation());
+        }
+
         let inner = self
             .parent_state
             .write()
             .show_submenu(ui.ctx(), sub_id, add_contents);
+
+        // Leave the submenu with the keyboard:
+        let mut parent_state = self.parent_state.write();
+        let submenu_has_focus = parent_state
+            .submenu(sub_id)
+            .map_or(false, |sub_menu| sub_menu.read().has_focus);
+        if submenu_has_focus && ui.input(|i| i.key_pressed(Key::ArrowLeft)) {
+            parent_state.close_submenu();
+            ui.memory_mut(|mem| {
+                mem.request_focus(button.id);
+                mem.stop_focus_navigation();
+            });
+        }
+
         InnerResponse::new(inner, button)
     }
 }
@@ -554,6 +634,12 @@ pub(crate) struct MenuState {
 
     /// Used to hash different [`Id`]s for sub-menus
     entry_count: usize,
+
+    /// Give keyboard focus to the first item, because the menu was opened with the keyboard.
+    focus_first_item: bool,
+
+    /// Does this menu (not counting sub-menus) contain the widget with keyboard focus?
+    has_focus: bool,
 }
 
 impl MenuState {
@@ -563,6 +649,8 @@ impl MenuState {
             sub_menu: None,
             response: MenuResponse::Stay,
             entry_count: 0,
+            focus_first_item: false,
+            has_focus: false,
         }
     }
 
@@ -618,11 +706,32 @@ impl MenuState {
         } else if !open && button.hovered() {
             let pos = button.rect.right_top();
             self.open_submenu(sub_id, pos);
-        } else if open && !button.hovered() && !self.hovering_current_submenu(&pointer) {
+        } else if open
+            && !button.hovered()
+            && !button.has_focus()
+            && !self.hovering_current_submenu(&pointer)
+            && !self.keyboard_in_current_submenu()
+        {
             self.close_submenu();
         }
     }
 
+    /// Is the user navigating the current submenu with the keyboard?
+    fn keyboard_in_current_submenu(&self) -> bool {
+        self.current_submenu().map_or(false, |sub_menu| {
+            let sub_menu = sub_menu.read();
+            sub_menu.focus_first_item || sub_menu.focus_in_hierarchy()
+        })
+    }
+
+    /// Does this menu or any of its sub-menus contain the widget with keyboard focus?
+    fn focus_in_hierarchy(&self) -> bool {
+        self.has_focus
+            || self
+                .current_submenu()
+                .map_or(false, |sub_menu| sub_menu.read().focus_in_hierarchy())
+    }
+
     /// Check if `dir` points from `pos` towards left side of `rect`.
     fn points_at_left_of_rect(pos: Pos2, dir: Vec2, rect: Rect) -> bool {
         let vel_a = dir.angle();
@@ -690,3 +799,104 @@ impl MenuState {
         self.sub_menu = None;
     }
 }
+
+// ----------------------------------------------------------------------------
+
+/// Keyboard navigation inside a menu or popup.
+///
+/// Moves the focus to the first item when the menu was opened with the keyboard,
+/// and jumps to the next item starting with a typed letter.
+/// Moving between the items with the arrow keys is handled by the normal focus navigation.
+pub(crate) struct MenuKeyboardNav {
+    typed_char: Option<char>,
+    parent_labels: Option<Vec<(Id, String)>>,
+}
+
+impl MenuKeyboardNav {
+    /// Call before showing the contents of the menu.
+    ///
+    /// `has_focus` is whether the menu contained the focused widget last frame.
+    /// `focus_first_item` is cleared once the first item has been given the focus.
+    pub fn begin(ui: &Ui, has_focus: bool, focus_first_item: &mut bool) -> Self {
+        let ctx = ui.ctx();
+        if *focus_first_item {
+            // Menus are invisible (and not interactive) the first frame they are shown,
+            // and the key that opened the menu would also click the first item, so maybe wait a frame.
+            let wait = !ui.is_visible()
+                || ctx.input(|i| i.key_pressed(Key::Enter) || i.key_pressed(Key::Space));
+            if wait {
+                ctx.request_repaint();
+            } else {
+                ctx.memory_mut(|mem| mem.focus_next_interested_widget());
+                *focus_first_item = false;
+            }
+        }
+
+        let typed_char = if has_focus {
+            ctx.input(|i| {
+                i.events.iter().find_map(|event| match event {
+                    Event::Text(text) => {
+                        let mut chars = text.chars();
+                        let c = chars.next()?;
+                        (chars.next().is_none() && !c.is_whitespace()).then_some(c)
+                    }
+                    _ => None,
+                })
+            })
+        } else {
+            None
+        };
+
+        // Sub-menus are shown while showing the parent menu, so we need to stash the labels of the parent.
+        let parent_labels = ctx.frame_state_mut(|fs| {
+            std::mem::replace(&mut fs.widget_labels, typed_char.map(|_| vec![]))
+        });
+
+        Self {
+            typed_char,
+            parent_labels,
+        }
+    }
+
+    /// Call after showing the contents of the menu, which covers `rect`.
+    ///
+    /// Returns `true` if the menu contains the focused widget.
+    pub fn end(self, ui: &Ui, rect: Rect) -> bool {
+        let ctx = ui.ctx();
+        let Self {
+            typed_char,
+            parent_labels,
+        } = self;
+
+        let labels =
+            ctx.frame_state_mut(|fs| std::mem::replace(&mut fs.widget_labels, parent_labels));
+
+        if let (Some(typed_char), Some(labels)) = (typed_char, labels) {
+            let starts_with_typed_char = |label: &str| {
+                label
+                    .trim_start()
+                    .chars()
+                    .next()
+                    .map_or(false, |c| c.to_lowercase().eq(typed_char.to_lowercase()))
+            };
+
+            // Start searching after the currently focused item, so that typing the same letter again cycles:
+            let focused = ctx.memory(|mem| mem.focus());
+            let start = labels
+                .iter()
+                .position(|(id, _)| Some(*id) == focused)
+                .map_or(0, |i| i + 1);
+            if let Some((id, _)) = labels[start..]
+                .iter()
+                .chain(&labels[..start])
+                .find(|(_, label)| starts_with_typed_char(label))
+            {
+                ctx.memory_mut(|mem| mem.request_focus(*id));
+            }
+        }
+
+        ctx.memory(|mem| mem.focus())
+            .and_then(|id| ctx.frame_state(|fs| fs.used_ids.get(&id).copied()))
+            .map_or(false, |focused_rect| rect.contains_rect(focused_rect))
+    }
+}
@@ -6,7 +6,7 @@ use crate::{
     Color32, Context, FontId,
 };
 use epaint::{
-    text::{Fonts, Galley},
+    text::{Fonts, Galley, LayoutJob},
     CircleShape, RectShape, Rounding, Shape, Stroke,
 };
 
@@ -28,6 +28,11 @@ pub struct Painter {
     /// If set, all shapes will have their colors modified to be closer to this.
     /// This is used to implement grayed out interfaces.
     fade_to_color: Option<Color32>,
+
+    /// If set, all shapes will have their colors modified with [`Color32::gamma_multiply`] with
+    /// this value as the factor.
+    /// This is used to make interfaces semi-transparent.
+    opacity_factor: f32,
 }
 
 impl Painter {
@@ -38,6 +43,7 @@ impl Painter {
             layer_id,
             clip_rect,
             fade_to_color: None,
+            opacity_factor: 1.0,
         }
     }
 
@@ -49,6 +55,7 @@ impl Painter {
             layer_id,
             clip_rect: self.clip_rect,
             fade_to_color: None,
+            opacity_factor: self.opacity_factor,
         }
     }
 
@@ -62,6 +69,7 @@ impl Painter {
             layer_id: self.layer_id,
             clip_rect: rect.intersect(self.clip_rect),
             fade_to_color: self.fade_to_color,
+            opacity_factor: self.opacity_factor,
         }
     }
 
@@ -75,8 +83,37 @@ impl Painter {
         self.fade_to_color = fade_to_color;
     }
 
+    /// Set the opacity (alpha multiplier) of everything painted by this painter from this point forward.
+    ///
+    /// `opacity` must be between 0.0 and 1.0, where 0.0 means fully transparent (i.e., invisible)
+    /// and 1.0 means fully opaque.
+    ///
+    /// See also: [`Self::opacity`] and [`Self::multiply_opacity`].
+    pub fn set_opacity(&mut self, opacity: f32) {
+        if opacity.is_finite() {
+            self.opacity_factor = opacity.clamp(0.0, 1.0);
+        }
+    }
+
+    /// Like [`Self::set_opacity`], but multiplies the given value with the current opacity.
+    ///
+    /// See also: [`Self::set_opacity`] and [`Self::opacity`].
+    pub fn multiply_opacity(&mut self, opacity: f32) {
+        if opacity.is_finite() {
+            self.opacity_factor *= opacity.clamp(0.0, 1.0);
+        }
+    }
+
+    /// Read the current opacity of the underlying painter.
+    ///
+    /// See also: [`Self::set_opacity`] and [`Self::multiply_opacity`].
+    #[inline]
+    pub fn opacity(&self) -> f32 {
+        self.opacity_factor
+    }
+
     pub(crate) fn is_visible(&self) -> bool {
-        self.fade_to_color != Some(Color32::TRANSPARENT)
+        self.fade_to_color != Some(Color32::TRANSPARENT) && self.opacity_factor > 0.0
     }
 
     /// If `false`, nothing added to the painter will be visible
@@ -91,6 +128,7 @@ impl Painter {
             layer_id: self.layer_id,
             clip_rect: rect.intersect(self.clip_rect),
             fade_to_color: self.fade_to_color,
+            opacity_factor: self.opacity_factor,
         }
     }
 }
@@ -161,13 +199,16 @@ impl Painter {
         if let Some(fade_to_color) = self.fade_to_color {
             tint_shape_towards(shape, fade_to_color);
         }
+        if self.opacity_factor < 1.0 {
+            multiply_opacity(shape, self.opacity_factor);
+        }
     }
 
     /// It is up to the caller to make sure there is room for this.
     /// Can be used for free painting.
     /// NOTE: all coordinates are screen coordinates!
     pub fn add(&self, shape: impl Into<Shape>) -> ShapeIdx {
-        if self.fade_to_color == Some(Color32::TRANSPARENT) {
+        if !self.is_visible() {
             self.paint_list(|l| l.add(self.clip_rect, Shape::Noop))
         } else {
             let mut shape = shape.into();
@@ -180,10 +221,10 @@ impl Painter {
     ///
     /// Calling this once is generally faster than calling [`Self::add`] multiple times.
     pub fn extend<I: IntoIterator<Item = Shape>>(&self, shapes: I) {
-        if self.fade_to_color == Some(Color32::TRANSPARENT) {
+        if !self.is_visible() {
             return;
         }
-        if self.fade_to_color.is_some() {
+        if self.fade_to_color.is_some() || self.opacity_factor < 1.0 {
             let shapes = shapes.into_iter().map(|mut shape| {
                 self.transform_shape(&mut shape);
                 shape
@@ -196,7 +237,7 @@ impl Painter {
 
     /// Modify an existing [`Shape`].
     pub fn set(&self, idx: ShapeIdx, shape: impl Into<Shape>) {
-        if self.fade_to_color == Some(Color32::TRANSPARENT) {
+        if !self.is_visible() {
             return;
         }
         let mut shape = shape.into();
@@ -393,6 +434,47 @@ impl Painter {
         rect
     }
 
+    /// Lay out and paint some text, wrapping it at the given width.
+    ///
+    /// The rows of the text are aligned horizontally according to the anchor,
+    /// so `Align2::CENTER_TOP` paints centered text below the given position.
+    ///
+    /// To use a [`crate::TextStyle`], resolve it with e.g. `TextStyle::Body.resolve(ui.style())`.
+    ///
+    /// Returns where the text ended up.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let rect = ui.max_rect();
+    /// ui.painter().text_wrapped(
+    ///     rect.center_top(),
+    ///     egui::Align2::CENTER_TOP,
+    ///     "A long caption that is wrapped to fit the width of the ui",
+    ///     egui::TextStyle::Body.resolve(ui.style()),
+    ///     ui.visuals().text_color(),
+    ///     rect.width(),
+    /// );
+    /// # });
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn text_wrapped(
+        &self,
+        pos: Pos2,
+        anchor: Align2,
+        text: impl ToString,
+        font_id: FontId,
+        text_color: Color32,
+        wrap_width: f32,
+    ) -> Rect {
+        let mut job = LayoutJob::simple(text.to_string(), font_id, text_color, wrap_width);
+        job.halign = anchor.x();
+        let galley = self.fonts(|f| f.layout_job(job));
+        let rect = anchor.anchor_rect(Rect::from_min_size(pos, galley.size()));
+        // An aligned galley is positioned relative to its alignment point:
+        self.galley(rect.min - galley.rect.min.to_vec2(), galley);
+        rect
+    }
+
     /// Will wrap text at the given width and line break at `\n`.
     ///
     /// Paint the results with [`Self::galley`].
@@ -450,3 +532,9 @@ fn tint_shape_towards(shape: &mut Shape, target: Color32) {
         *color = crate::ecolor::tint_color_towards(*color, target);
     });
 }
+
+fn multiply_opacity(shape: &mut Shape, opacity: f32) {
+    epaint::shape_transform::adjust_colors(shape, &|color| {
+        *color = color.gamma_multiply(opacity);
+    });
+}
@@ -188,6 +188,14 @@ impl Placer {
         }
     }
 
+    /// Let the next cell of a grid layout span this many columns.
+    /// Otherwise does nothing.
+    pub(crate) fn span_columns(&mut self, columns: usize) {
+        if let Some(grid) = &mut self.grid {
+            grid.span_columns(columns);
+        }
+    }
+
     /// Set row height in horizontal wrapping layout.
     pub(crate) fn set_row_height(&mut self, height: f32) {
         self.layout.set_row_height(&mut self.region, height);
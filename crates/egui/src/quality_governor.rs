@@ -0,0 +1,135 @@
+use epaint::{emath, TessellationOptions};
+
+/// How many segments to use for circles, rounded corners and shadows when the quality is reduced.
+///
+/// See [`TessellationOptions::circle_detail`].
+pub(crate) const REDUCED_CIRCLE_DETAIL: f32 = 0.25;
+
+/// Restore the full quality when the frame time has been below this fraction of the budget…
+const RESTORE_FRACTION: f32 = 0.5;
+
+/// …for this many seconds.
+const RESTORE_DELAY: f32 = 1.0;
+
+/// Lowers the tessellation quality while frames take longer than [`crate::Options::frame_time_budget`].
+#[derive(Clone, Debug, Default)]
+pub(crate) struct QualityGovernor {
+    /// Exponential moving average of the measured frame time, in seconds.
+    smoothed_frame_time: Option<f32>,
+
+    /// Are we currently reducing the quality?
+    reduced: bool,
+
+    /// For how long the frame time has been well within budget while the quality is reduced.
+    time_within_budget: f32,
+}
+
+impl QualityGovernor {
+    /// Call once per frame.
+    ///
+    /// `frame_time` is `None` if it could not be measured,
+    /// e.g. for the first frame after egui has been sleeping.
+    /// There was no hurry then, so the full quality is restored.
+    pub fn on_frame(&mut self, budget: Option<f32>, frame_time: Option<f32>) {
+        let (Some(budget), Some(frame_time)) = (budget, frame_time) else {
+            *self = Default::default();
+            return;
+        };
+
+        let smoothed = self.smoothed_frame_time.map_or(frame_time, |smoothed| {
+            emath::lerp(smoothed..=frame_time, 0.1)
+        });
+        self.smoothed_frame_time = Some(smoothed);
+
+        if !self.reduced {
+            if budget < smoothed {
+                self.reduced = true;
+                self.time_within_budget = 0.0;
+            }
+        } else if smoothed < RESTORE_FRACTION * budget {
+            self.time_within_budget += frame_time;
+            if RESTORE_DELAY <= self.time_within_budget {
+                self.reduced = false;
+            }
+        } else {
+            self.time_within_budget = 0.0;
+        }
+    }
+
+    /// Call at the end of a frame after which egui will sleep, i.e. no repaint has been requested.
+    ///
+    /// The full quality is restored, so that the last frame is shown at the full quality while idle.
+    pub fn on_idle(&mut self) {
+        *self = Default::default();
+    }
+
+    pub fn is_reduced(&self) -> bool {
+        self.reduced
+    }
+
+    /// The options to actually tessellate with.
+    pub fn tessellation_options(&self, options: TessellationOptions) -> TessellationOptions {
+        if self.reduced {
+            TessellationOptions {
+                feathering: false,
+                circle_detail: options.circle_detail.min(REDUCED_CIRCLE_DETAIL),
+                ..options
+            }
+        } else {
+            options
+        }
+    }
+}
+
+#[test]
+fn quality_is_reduced_and_restored() {
+    let budget = Some(1.0 / 30.0);
+    let mut governor = QualityGovernor::default();
+
+    for _ in 0..10 {
+        governor.on_frame(budget, Some(1.0 / 60.0));
+    }
+    assert!(!governor.is_reduced());
+
+    for _ in 0..10 {
+        governor.on_frame(budget, Some(1.0 / 10.0));
+    }
+    assert!(governor.is_reduced());
+    assert!(!governor.tessellation_options(Default::default()).feathering);
+
+    // Frames that are merely within budget are not enough to restore the quality:
+    for _ in 0..100 {
+        governor.on_frame(budget, Some(1.0 / 40.0));
+    }
+    assert!(governor.is_reduced());
+
+    for _ in 0..200 {
+        governor.on_frame(budget, Some(1.0 / 120.0));
+    }
+    assert!(!governor.is_reduced());
+
+    governor.on_frame(None, Some(1.0));
+    assert!(!governor.is_reduced());
+}
+
+#[test]
+fn quality_is_restored_when_idle() {
+    let budget = Some(1.0 / 30.0);
+
+    let mut governor = QualityGovernor::default();
+    for _ in 0..10 {
+        governor.on_frame(budget, Some(1.0 / 10.0));
+    }
+    assert!(governor.is_reduced());
+    governor.on_idle();
+    assert!(!governor.is_reduced());
+
+    // The first frame after sleeping can't be measured:
+    let mut governor = QualityGovernor::default();
+    for _ in 0..10 {
+        governor.on_frame(budget, Some(1.0 / 10.0));
+    }
+    assert!(governor.is_reduced());
+    governor.on_frame(budget, None);
+    assert!(!governor.is_reduced());
+}
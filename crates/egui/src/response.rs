@@ -1,3 +1,5 @@
+use std::{any::Any, sync::Arc};
+
 use crate::{
     emath::{Align, Pos2, Rect, Vec2},
     menu, Context, CursorIcon, Id, LayerId, PointerButton, Sense, Ui, WidgetText,
@@ -73,6 +75,10 @@ pub struct Response {
     #[doc(hidden)]
     pub is_pointer_button_down_on: bool,
 
+    /// A finger has been held still on this widget long enough to count as a long-press.
+    #[doc(hidden)]
+    pub long_touched: bool,
+
     /// Where the pointer (mouse/touch) were when when this widget was clicked or dragged.
     /// `None` if the widget is not being interacted with.
     #[doc(hidden)]
@@ -84,6 +90,12 @@ pub struct Response {
     /// Always `false` for something like a [`Button`](crate::Button).
     #[doc(hidden)]
     pub changed: bool,
+
+    /// The size the widget would need to show all of its content, if larger than [`Self::rect`].
+    ///
+    /// e.g. the size of the full text of a truncated [`Label`](crate::Label).
+    #[doc(hidden)]
+    pub intrinsic_size: Option<Vec2>,
 }
 
 impl std::fmt::Debug for Response {
@@ -103,8 +115,10 @@ impl std::fmt::Debug for Response {
             dragged,
             drag_released,
             is_pointer_button_down_on,
+            long_touched,
             interact_pointer_pos,
             changed,
+            intrinsic_size,
         } = self;
         f.debug_struct("Response")
             .field("layer_id", layer_id)
@@ -120,8 +134,10 @@ impl std::fmt::Debug for Response {
             .field("dragged", dragged)
             .field("drag_released", drag_released)
             .field("is_pointer_button_down_on", is_pointer_button_down_on)
+            .field("long_touched", long_touched)
             .field("interact_pointer_pos", interact_pointer_pos)
             .field("changed", changed)
+            .field("intrinsic_size", intrinsic_size)
             .finish()
     }
 }
@@ -151,6 +167,42 @@ impl Response {
         self.clicked[PointerButton::Secondary as usize]
     }
 
+    /// Returns true on the frame a finger has been held still on this widget
+    /// for long enough to count as a long-press.
+    ///
+    /// This is the touch-screen equivalent of a right-click,
+    /// so it also counts as a [`Self::secondary_clicked`], and opens [`Self::context_menu`]s.
+    ///
+    /// Note that the widget must be sensing clicks with [`Sense::click`].
+    #[inline(always)]
+    pub fn long_touched(&self) -> bool {
+        self.long_touched
+    }
+
+    /// For how long has the pointer been hovering this widget, in seconds?
+    ///
+    /// `None` if the widget is not hovered.
+    pub fn hover_duration(&self) -> Option<f64> {
+        if !self.hovered {
+            return None;
+        }
+        let start_time = self
+            .ctx
+            .memory(|mem| mem.interaction().hover_start_time(self.id))?;
+        Some(self.ctx.input(|i| i.time) - start_time)
+    }
+
+    /// For how long has a pointer button been held down on this widget, in seconds?
+    ///
+    /// `None` if no pointer button is down on the widget.
+    pub fn press_duration(&self) -> Option<f64> {
+        if !self.is_pointer_button_down_on {
+            return None;
+        }
+        self.ctx
+            .input(|i| Some(i.time - i.pointer.press_start_time()?))
+    }
+
     /// Returns true if this widget was clicked this frame by the middle mouse button.
     pub fn middle_clicked(&self) -> bool {
         self.clicked[PointerButton::Middle as usize]
@@ -307,10 +359,58 @@ impl Response {
         self.drag_released() && self.ctx.input(|i| i.pointer.button_released(button))
     }
 
+    /// If the user started dragging this widget this frame, store the payload for drag-and-drop.
+    ///
+    /// The payload can then be picked up by a drop target with [`Self::dnd_hover_payload`]
+    /// or [`Self::dnd_release_payload`], even in another window.
+    /// See also [`Ui::dnd_drag_source`] and [`crate::DragAndDrop`].
+    pub fn dnd_set_drag_payload<Payload: Any + Send + Sync>(&self, payload: Payload) {
+        if self.drag_started() {
+            crate::DragAndDrop::set_payload(&self.ctx, payload);
+        }
+
+        if self.hovered() && !self.sense.click {
+            // Things that can be drag-dropped should use the Grab cursor icon,
+            // but if the thing is _also_ clickable, that can be annoying.
+            self.ctx.set_cursor_icon(CursorIcon::Grab);
+        }
+    }
+
+    /// Drag-and-drop: Return what is being held over this widget, if any.
+    ///
+    /// Only returns something if the pointer is over this widget
+    /// and the payload is of the requested type.
+    ///
+    /// This works across layers (e.g. from one window to another),
+    /// so long as this widget is not covered by another layer.
+    pub fn dnd_hover_payload<Payload: Any + Send + Sync>(&self) -> Option<Arc<Payload>> {
+        if self.ctx.rect_contains_pointer(self.layer_id, self.rect) {
+            crate::DragAndDrop::payload(&self.ctx)
+        } else {
+            None
+        }
+    }
+
+    /// Drag-and-drop: Return what is being dropped onto this widget, if any.
+    ///
+    /// Only returns something if the pointer was released over this widget this frame
+    /// and the payload is of the requested type.
+    pub fn dnd_release_payload<Payload: Any + Send + Sync>(&self) -> Option<Arc<Payload>> {
+        if self.ctx.input(|i| i.pointer.any_released()) {
+            self.dnd_hover_payload()
+        } else {
+            None
+        }
+    }
+
     /// If dragged, how many points were we dragged and in what direction?
     pub fn drag_delta(&self) -> Vec2 {
         if self.dragged() {
-            self.ctx.input(|i| i.pointer.delta())
+            let delta = self.ctx.input(|i| i.pointer.delta());
+            match self.ctx.layer_transform(self.layer_id) {
+                Some(transform) => delta / transform.scaling,
+                None => delta,
+            }
         } else {
             Vec2::ZERO
         }
@@ -326,7 +426,8 @@ impl Response {
     /// None if the pointer is outside the response area.
     pub fn hover_pos(&self) -> Option<Pos2> {
         if self.hovered() {
-            self.ctx.input(|i| i.pointer.hover_pos())
+            let pos = self.ctx.input(|i| i.pointer.hover_pos())?;
+            Some(self.ctx.layer_pos_from_global(self.layer_id, pos))
         } else {
             None
         }
@@ -366,6 +467,38 @@ impl Response {
         self.changed = true;
     }
 
+    /// The smallest size the widget needs to show all of its content.
+    ///
+    /// This is usually the size of [`Self::rect`], but can be larger if the content didn't fit,
+    /// e.g. for a truncated [`Label`](crate::Label).
+    ///
+    /// Overflowing widgets can be highlighted with [`crate::style::DebugOptions::show_overflow`].
+    #[inline]
+    pub fn intrinsic_size(&self) -> Vec2 {
+        self.intrinsic_size
+            .map_or(self.rect.size(), |size| size.max(self.rect.size()))
+    }
+
+    /// Report that the widget needs this size to show all of its content,
+    /// because it did not get all the space it wanted.
+    ///
+    /// See [`Self::intrinsic_size`].
+    #[inline]
+    pub fn set_intrinsic_size(&mut self, intrinsic_size: Vec2) {
+        self.intrinsic_size = Some(intrinsic_size);
+    }
+
+    /// Did the content of the widget not fit in [`Self::rect`]?
+    ///
+    /// See [`Self::intrinsic_size`].
+    #[inline]
+    pub fn overflows(&self) -> bool {
+        let size = self.rect.size();
+        self.intrinsic_size.map_or(false, |intrinsic| {
+            intrinsic.x > size.x || intrinsic.y > size.y
+        })
+    }
+
     /// Show this UI if the widget was hovered (i.e. a tooltip).
     ///
     /// The text will not be visible if the widget is not enabled.
@@ -580,6 +713,16 @@ impl Response {
         } else {
             None
         };
+        self.ctx.frame_state_mut(|fs| {
+            if self.sense.focusable {
+                if let Some(labels) = &mut fs.widget_labels {
+                    labels.push((self.id, make_info().label.unwrap_or_default()));
+                }
+            }
+            if let Some(widget_tree) = &mut fs.widget_tree {
+                widget_tree.set_widget_info(self.id, self.rect, make_info());
+            }
+        });
         if let Some(event) = event {
             self.output_event(event);
         } else {
@@ -750,8 +893,10 @@ impl Response {
             drag_released: self.drag_released || other.drag_released,
             is_pointer_button_down_on: self.is_pointer_button_down_on
                 || other.is_pointer_button_down_on,
+            long_touched: self.long_touched || other.long_touched,
             interact_pointer_pos: self.interact_pointer_pos.or(other.interact_pointer_pos),
             changed: self.changed || other.changed,
+            intrinsic_size: self.intrinsic_size.or(other.intrinsic_size),
         }
     }
 }
@@ -57,9 +57,26 @@ impl std::fmt::Display for TextStyle {
 }
 
 impl TextStyle {
+    /// Look up this [`TextStyle`] in [`Style::text_styles`],
+    /// returning `None` if it isn't there (e.g. a [`Self::Name`] that was never registered).
+    pub fn try_resolve(&self, style: &Style) -> Option<FontId> {
+        style.text_styles.get(self).cloned()
+    }
+
     /// Look up this [`TextStyle`] in [`Style::text_styles`].
+    ///
+    /// A [`Self::Name`] that isn't there (e.g. because it was removed in the style editor)
+    /// falls back to [`Self::Body`].
+    /// Panics if the fallback isn't there either. See also [`Self::try_resolve`].
     pub fn resolve(&self, style: &Style) -> FontId {
-        style.text_styles.get(self).cloned().unwrap_or_else(|| {
+        if let Some(font_id) = style.text_styles.get(self) {
+            return font_id.clone();
+        }
+        let fallback = match self {
+            Self::Name(_) => style.text_styles.get(&Self::Body),
+            _ => None,
+        };
+        fallback.cloned().unwrap_or_else(|| {
             panic!(
                 "Failed to find {:?} in Style::text_styles. Available styles:\n{:#?}",
                 self,
@@ -181,6 +198,12 @@ pub struct Style {
     /// The style to use for [`DragValue`] text.
     pub drag_value_text_style: TextStyle,
 
+    /// How numbers are shown and typed in, e.g. with which decimal separator.
+    ///
+    /// Used by [`DragValue`], [`Slider`] and [`crate::NumberField`]
+    /// unless they have a custom formatter or parser.
+    pub number_format: NumberFormat,
+
     /// If set, labels buttons wtc will use this to determine whether or not
     /// to wrap the text at the right edge of the [`Ui`] they are in.
     /// By default this is `None`.
@@ -243,6 +266,35 @@ impl Style {
     pub fn text_styles(&self) -> Vec<TextStyle> {
         self.text_styles.keys().cloned().collect()
     }
+
+    /// Add a named text style (or change an existing one), and return it.
+    ///
+    /// The returned [`TextStyle::Name`] can then be used by widgets,
+    /// e.g. with [`crate::RichText::text_style`] or [`Self::override_text_style`].
+    ///
+    /// Usually you register your text styles once at startup, with [`crate::Context::style_mut`].
+    /// A [`crate::Ui`] copies the style when it is created, so for use in the current frame
+    /// you can register the style with [`crate::Ui::style_mut`] as well:
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// use egui::{FontId, RichText};
+    ///
+    /// let caption = ui
+    ///     .style_mut()
+    ///     .register_text_style("Caption", FontId::proportional(10.0));
+    /// ui.label(RichText::new("Figure 1").text_style(caption));
+    /// # });
+    /// ```
+    pub fn register_text_style(
+        &mut self,
+        name: impl Into<std::sync::Arc<str>>,
+        font_id: FontId,
+    ) -> TextStyle {
+        let text_style = TextStyle::Name(name.into());
+        self.text_styles.insert(text_style.clone(), font_id);
+        text_style
+    }
 }
 
 /// Controls the sizes and distances between widgets.
@@ -275,6 +327,15 @@ pub struct Spacing {
     /// Anything clickable should be (at least) this size.
     pub interact_size: Vec2, // TODO(emilk): rename min_interact_size ?
 
+    /// Minimum size of the area that senses clicks and drags for each widget.
+    ///
+    /// Widgets smaller than this (like the collapse triangle and close button of a window)
+    /// still look the same, but can be hit anywhere within this size around their center.
+    /// This makes them easier to hit on touch screens.
+    ///
+    /// Zero by default. Something like 32x32 is good for touch screens.
+    pub min_touch_target: Vec2,
+
     /// Default width of a [`Slider`].
     pub slider_width: f32,
 
@@ -359,6 +420,21 @@ pub struct ScrollStyle {
     /// Only makes sense for non-floating scroll bars.
     pub bar_outer_margin: f32,
 
+    /// Rounding of the scroll bar background and handle.
+    ///
+    /// If `None`, the rounding of the widget visuals is used.
+    pub bar_rounding: Option<Rounding>,
+
+    /// Hide floating scroll bars after this many seconds without any scrolling
+    /// or interaction with the scroll bars.
+    ///
+    /// The bars fade out, and fade back in as soon as the user scrolls again.
+    /// This is good for touch screens, where there is no hovering to reveal the bars.
+    ///
+    /// `None` means the scroll bars never auto-hide (default).
+    /// This is only for floating scroll bars.
+    pub auto_hide_delay: Option<f32>,
+
     /// The thin width of floating scroll bars that the user is NOT hovering.
     ///
     /// When the user hovers the scroll bars they expand to [`Self::bar_width`].
@@ -433,6 +509,8 @@ impl ScrollStyle {
             handle_min_length: 12.0,
             bar_inner_margin: 4.0,
             bar_outer_margin: 0.0,
+            bar_rounding: None,
+            auto_hide_delay: None,
             floating_width: 2.0,
             floating_allocated_width: 0.0,
 
@@ -514,6 +592,8 @@ impl ScrollStyle {
             handle_min_length,
             bar_inner_margin,
             bar_outer_margin,
+            bar_rounding,
+            auto_hide_delay,
             floating_width,
             floating_allocated_width,
 
@@ -557,6 +637,37 @@ impl ScrollStyle {
             ui.label("Outer margin");
         });
 
+        ui.horizontal(|ui| {
+            let mut custom_rounding = bar_rounding.is_some();
+            ui.checkbox(&mut custom_rounding, "Custom rounding");
+            match (custom_rounding, bar_rounding.is_some()) {
+                (true, false) => *bar_rounding = Some(Rounding::same(*bar_width / 2.0)),
+                (false, true) => *bar_rounding = None,
+                _ => {}
+            }
+        });
+        if let Some(bar_rounding) = bar_rounding {
+            rounding_ui(ui, bar_rounding);
+        }
+
+        if *floating {
+            ui.horizontal(|ui| {
+                let mut auto_hide = auto_hide_delay.is_some();
+                ui.checkbox(&mut auto_hide, "Auto-hide after");
+                if auto_hide {
+                    let delay = auto_hide_delay.get_or_insert(1.0);
+                    ui.add(
+                        DragValue::new(delay)
+                            .speed(0.05)
+                            .clamp_range(0.0..=10.0)
+                            .suffix(" s"),
+                    );
+                } else {
+                    *auto_hide_delay = None;
+                }
+            });
+        }
+
         ui.horizontal(|ui| {
             ui.label("Color:");
             ui.selectable_value(foreground_color, false, "Background");
@@ -698,6 +809,145 @@ impl std::ops::Add for Margin {
 
 // ----------------------------------------------------------------------------
 
+/// How numbers are shown and typed in, see [`Style::number_format`].
+///
+/// The default is how Rust formats and parses numbers, e.g. `1234.5`.
+///
+/// ```
+/// let german = egui::style::NumberFormat {
+///     decimal_separator: ',',
+///     thousands_separator: Some('.'),
+/// };
+/// assert_eq!(german.format("-1234567.5"), "-1.234.567,5");
+/// assert_eq!(german.parse("1.234,5"), Some(1234.5));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct NumberFormat {
+    /// Put between the integer part and the decimals, e.g. `','` in many European languages.
+    ///
+    /// Default: `'.'`.
+    pub decimal_separator: char,
+
+    /// If set, put between every group of three digits of the integer part, e.g. `Some(',')` for `1,000,000`.
+    ///
+    /// Ignored when parsing, so the user may type numbers with or without it.
+    ///
+    /// Default: `None`.
+    pub thousands_separator: Option<char>,
+}
+
+impl Default for NumberFormat {
+    fn default() -> Self {
+        Self {
+            decimal_separator: '.',
+            thousands_separator: None,
+        }
+    }
+}
+
+impl NumberFormat {
+    /// Apply this format to a number formatted by Rust, e.g. by [`emath::format_with_decimals_in_range`].
+    pub fn format(&self, number: &str) -> String {
+        let (sign, unsigned) = match number.strip_prefix('-') {
+            Some(unsigned) => ("-", unsigned),
+            None => ("", number),
+        };
+        let integer_len = unsigned
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(unsigned.len());
+        let (integer, rest) = unsigned.split_at(integer_len);
+
+        let mut formatted = String::with_capacity(number.len() + integer_len / 3);
+        formatted.push_str(sign);
+        for (i, digit) in integer.chars().enumerate() {
+            if let Some(thousands_separator) = self.thousands_separator {
+                if i > 0 && (integer_len - i) % 3 == 0 {
+                    formatted.push(thousands_separator);
+                }
+            }
+            formatted.push(digit);
+        }
+        match rest.strip_prefix('.') {
+            Some(decimals) => {
+                formatted.push(self.decimal_separator);
+                formatted.push_str(decimals);
+            }
+            None => formatted.push_str(rest),
+        }
+        formatted
+    }
+
+    /// Parse a number typed in with this format.
+    ///
+    /// Any thousands separators are ignored.
+    pub fn parse(&self, text: &str) -> Option<f64> {
+        let text: String = text
+            .trim()
+            .chars()
+            .filter(|c| Some(*c) != self.thousands_separator)
+            .map(|c| if c == self.decimal_separator { '.' } else { c })
+            .collect();
+        text.parse().ok()
+    }
+
+    pub fn ui(&mut self, ui: &mut Ui) {
+        ui.horizontal(|ui| {
+            // The two separators must differ, or numbers can't be parsed.
+            let Self {
+                decimal_separator,
+                thousands_separator,
+            } = self;
+
+            let current_thousands_separator = *thousands_separator;
+            crate::ComboBox::from_id_source("decimal_separator")
+                .selected_text(decimal_separator.to_string())
+                .width(32.0)
+                .show_ui(ui, |ui| {
+                    for separator in ['.', ','] {
+                        ui.add_enabled_ui(Some(separator) != current_thousands_separator, |ui| {
+                            ui.selectable_value(
+                                decimal_separator,
+                                separator,
+                                separator.to_string(),
+                            );
+                        });
+                    }
+                })
+                .response
+                .on_hover_text(ui.ctx().translate("Decimal separator"));
+
+            let separator_name = |separator: Option<char>| match separator {
+                None => "None".to_owned(),
+                Some(' ') => "Space".to_owned(),
+                Some('\u{202F}') => "Thin space".to_owned(),
+                Some(separator) => separator.to_string(),
+            };
+            let current_decimal_separator = *decimal_separator;
+            crate::ComboBox::from_id_source("thousands_separator")
+                .selected_text(separator_name(*thousands_separator))
+                .show_ui(ui, |ui| {
+                    for separator in [None, Some(','), Some('.'), Some(' '), Some('\u{202F}')] {
+                        ui.add_enabled_ui(separator != Some(current_decimal_separator), |ui| {
+                            ui.selectable_value(
+                                thousands_separator,
+                                separator,
+                                separator_name(separator),
+                            );
+                        });
+                    }
+                })
+                .response
+                .on_hover_text(ui.ctx().translate("Thousands separator"));
+
+            ui.label(self.format("1234567.89"));
+        });
+    }
+}
+
+// ----------------------------------------------------------------------------
+
 /// How and when interaction happens.
 #[derive(Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
@@ -786,6 +1036,17 @@ pub struct Visuals {
 
     pub popup_shadow: Shadow,
 
+    /// Ask the integration to blur what is behind windows, menus, popups and panels,
+    /// for a frosted-glass look.
+    ///
+    /// This only makes sense together with a [`Self::window_fill`] and [`Self::panel_fill`]
+    /// that are somewhat translucent.
+    /// The regions to blur are listed in [`crate::FullOutput::blur_behind`],
+    /// and it is up to the integration to blur them, so this does nothing on most integrations.
+    ///
+    /// Default: `false`.
+    pub blur_behind: bool,
+
     pub resize_corner_size: f32,
 
     /// The color and width of the text cursor
@@ -794,6 +1055,18 @@ pub struct Visuals {
     /// show where the text cursor would be if you clicked
     pub text_cursor_preview: bool,
 
+    /// Should the text cursor of a focused [`TextEdit`] blink?
+    ///
+    /// The blinking only causes a repaint each time the cursor is shown or hidden,
+    /// so it does not force continuous repainting.
+    pub text_cursor_blink: bool,
+
+    /// How long the blinking text cursor is shown for, in seconds.
+    pub text_cursor_on_duration: f32,
+
+    /// How long the blinking text cursor is hidden for, in seconds.
+    pub text_cursor_off_duration: f32,
+
     /// Allow child widgets to be just on the border and still have a stroke with some thickness
     pub clip_rect_margin: f32,
 
@@ -1020,6 +1293,17 @@ pub struct DebugOptions {
 
     /// Show what widget blocks the interaction of another widget.
     pub show_blocking_widget: bool,
+
+    /// Show the frame number, and why each frame is painted: new input,
+    /// or which code called [`crate::Context::request_repaint`] (see [`crate::Context::repaint_causes`]).
+    ///
+    /// Useful for finding out why an app keeps repainting.
+    pub show_repaint_causes: bool,
+
+    /// Highlight content that doesn't fit in the space it was given:
+    /// truncated text (see [`crate::Response::intrinsic_size`]),
+    /// and widgets that are clipped away because they stick out of their parent [`crate::Ui`].
+    pub show_overflow: bool,
 }
 
 #[cfg(debug_assertions)]
@@ -1035,6 +1319,8 @@ impl Default for DebugOptions {
             show_resize: false,
             show_interactive_widgets: false,
             show_blocking_widget: false,
+            show_repaint_causes: false,
+            show_overflow: false,
         }
     }
 }
@@ -1062,6 +1348,7 @@ impl Default for Style {
             override_text_style: None,
             text_styles: default_text_styles(),
             drag_value_text_style: TextStyle::Button,
+            number_format: NumberFormat::default(),
             wrap: None,
             spacing: Spacing::default(),
             interaction: Interaction::default(),
@@ -1083,6 +1370,7 @@ impl Default for Spacing {
             button_padding: vec2(4.0, 1.0),
             indent: 18.0, // match checkbox/radio-button with `button_padding.x + icon_width + icon_spacing`
             interact_size: vec2(40.0, 18.0),
+            min_touch_target: Vec2::ZERO,
             slider_width: 100.0,
             combo_width: 100.0,
             text_edit_width: 280.0,
@@ -1133,9 +1421,13 @@ impl Visuals {
             panel_fill: Color32::from_gray(27),
 
             popup_shadow: Shadow::small_dark(),
+            blur_behind: false,
             resize_corner_size: 12.0,
             text_cursor: Stroke::new(2.0, Color32::from_rgb(192, 222, 255)),
             text_cursor_preview: false,
+            text_cursor_blink: true,
+            text_cursor_on_duration: 0.5,
+            text_cursor_off_duration: 0.5,
             clip_rect_margin: 3.0, // should be at least half the size of the widest frame stroke + max WidgetVisuals::expansion
             button_frame: true,
             collapsing_header_frame: false,
@@ -1315,6 +1607,7 @@ impl Style {
             override_text_style,
             text_styles,
             drag_value_text_style,
+            number_format,
             wrap: _,
             spacing,
             interaction,
@@ -1370,6 +1663,10 @@ impl Style {
                 });
             ui.end_row();
 
+            ui.label("Number format:");
+            number_format.ui(ui);
+            ui.end_row();
+
             ui.label("Animation duration:");
             ui.add(
                 Slider::new(animation_time, 0.0..=1.0)
@@ -1388,9 +1685,9 @@ impl Style {
         ui.collapsing("🐛 Debug", |ui| debug.ui(ui));
 
         ui.checkbox(explanation_tooltips, "Explanation tooltips")
-            .on_hover_text(
+            .on_hover_text(ui.ctx().translate(
                 "Show explanatory text when hovering DragValue:s and other egui widgets",
-            );
+            ));
 
         ui.vertical_centered(|ui| reset_button(ui, self));
     }
@@ -1398,13 +1695,51 @@ impl Style {
 
 fn text_styles_ui(ui: &mut Ui, text_styles: &mut BTreeMap<TextStyle, FontId>) -> Response {
     ui.vertical(|ui| {
+        let mut removed = None;
         crate::Grid::new("text_styles").show(ui, |ui| {
             for (text_style, font_id) in &mut *text_styles {
                 ui.label(RichText::new(text_style.to_string()).font(font_id.clone()));
                 crate::introspection::font_id_ui(ui, font_id);
+                if let TextStyle::Name(_) = text_style {
+                    if ui
+                        .small_button("🗑")
+                        .on_hover_text(ui.ctx().translate("Remove this text style"))
+                        .clicked()
+                    {
+                        removed = Some(text_style.clone());
+                    }
+                }
                 ui.end_row();
             }
         });
+        if let Some(removed) = removed {
+            text_styles.remove(&removed);
+        }
+
+        ui.horizontal(|ui| {
+            let id = ui.id().with("new_text_style_name");
+            let mut name: String = ui.data_mut(|d| d.get_temp(id)).unwrap_or_default();
+            ui.add(TextEdit::singleline(&mut name).hint_text("Name"));
+            let trimmed = name.trim();
+            let is_new = !trimmed.is_empty()
+                && !text_styles
+                    .keys()
+                    .any(|text_style| text_style.to_string() == trimmed);
+            if ui
+                .add_enabled(is_new, crate::Button::new("Add text style"))
+                .clicked()
+            {
+                let font_id = text_styles
+                    .get(&TextStyle::Body)
+                    .cloned()
+                    .unwrap_or_default();
+                text_styles.insert(TextStyle::Name(trimmed.into()), font_id);
+                ui.data_mut(|d| d.remove::<String>(id));
+            } else {
+                ui.data_mut(|d| d.insert_temp(id, name));
+            }
+        });
+
         crate::reset_button_with(ui, text_styles, default_text_styles());
     })
     .response
@@ -1419,6 +1754,7 @@ impl Spacing {
             button_padding,
             indent,
             interact_size,
+            min_touch_target,
             slider_width,
             combo_width,
             text_edit_width,
@@ -1438,7 +1774,16 @@ impl Spacing {
 
         ui.add(slider_vec2(button_padding, 0.0..=20.0, "Button padding"));
         ui.add(slider_vec2(interact_size, 4.0..=60.0, "Interact size"))
-            .on_hover_text("Minimum size of an interactive widget");
+            .on_hover_text(ui.ctx().translate("Minimum size of an interactive widget"));
+        ui.add(slider_vec2(
+            min_touch_target,
+            0.0..=60.0,
+            "Min touch target",
+        ))
+        .on_hover_text(
+            ui.ctx()
+                .translate("Minimum size of the area that senses clicks of a widget"),
+        );
         ui.horizontal(|ui| {
             ui.add(DragValue::new(indent).clamp_range(0.0..=100.0));
             ui.label("Indent");
@@ -1524,25 +1869,25 @@ fn margin_ui(ui: &mut Ui, text: &str, margin: &mut Margin) {
                     .clamp_range(margin_range.clone())
                     .prefix("L: "),
             )
-            .on_hover_text("Left margin");
+            .on_hover_text(ui.ctx().translate("Left margin"));
             ui.add(
                 DragValue::new(&mut margin.right)
                     .clamp_range(margin_range.clone())
                     .prefix("R: "),
             )
-            .on_hover_text("Right margin");
+            .on_hover_text(ui.ctx().translate("Right margin"));
             ui.add(
                 DragValue::new(&mut margin.top)
                     .clamp_range(margin_range.clone())
                     .prefix("T: "),
             )
-            .on_hover_text("Top margin");
+            .on_hover_text(ui.ctx().translate("Top margin"));
             ui.add(
                 DragValue::new(&mut margin.bottom)
                     .clamp_range(margin_range)
                     .prefix("B: "),
             )
-            .on_hover_text("Bottom margin");
+            .on_hover_text(ui.ctx().translate("Bottom margin"));
         }
     });
 }
@@ -1626,16 +1971,16 @@ impl WidgetVisuals {
             expansion,
         } = self;
         ui_color(ui, weak_bg_fill, "optional background fill")
-            .on_hover_text("For buttons, combo-boxes, etc");
+            .on_hover_text(ui.ctx().translate("For buttons, combo-boxes, etc"));
         ui_color(ui, mandatory_bg_fill, "mandatory background fill")
-            .on_hover_text("For checkboxes, sliders, etc");
+            .on_hover_text(ui.ctx().translate("For checkboxes, sliders, etc"));
         stroke_ui(ui, bg_stroke, "background stroke");
 
         rounding_ui(ui, rounding);
 
         stroke_ui(ui, fg_stroke, "foreground stroke (text)");
         ui.add(Slider::new(expansion, -5.0..=5.0).text("expansion"))
-            .on_hover_text("make shapes this much larger");
+            .on_hover_text(ui.ctx().translate("make shapes this much larger"));
     }
 }
 
@@ -1655,7 +2000,7 @@ impl Visuals {
         if self.dark_mode {
             if ui
                 .add(Button::new("☀").frame(false))
-                .on_hover_text("Switch to light mode")
+                .on_hover_text(ui.ctx().translate("Switch to light mode"))
                 .clicked()
             {
                 return Some(Self::light());
@@ -1663,7 +2008,7 @@ impl Visuals {
         } else {
             if ui
                 .add(Button::new("🌙").frame(false))
-                .on_hover_text("Switch to dark mode")
+                .on_hover_text(ui.ctx().translate("Switch to dark mode"))
                 .clicked()
             {
                 return Some(Self::dark());
@@ -1696,9 +2041,14 @@ impl Visuals {
 
             popup_shadow,
 
+            blur_behind,
+
             resize_corner_size,
             text_cursor,
             text_cursor_preview,
+            text_cursor_blink,
+            text_cursor_on_duration,
+            text_cursor_off_duration,
             clip_rect_margin,
             button_frame,
             collapsing_header_frame,
@@ -1717,11 +2067,16 @@ impl Visuals {
             ui_color(ui, &mut widgets.inactive.weak_bg_fill, "Buttons");
             ui_color(ui, window_fill, "Windows");
             ui_color(ui, panel_fill, "Panels");
-            ui_color(ui, faint_bg_color, "Faint accent").on_hover_text(
+            ui.checkbox(blur_behind, "Blur behind windows and panels")
+                .on_hover_text(
+                    ui.ctx()
+                        .translate("For translucent fills. Only supported by some integrations."),
+                );
+            ui_color(ui, faint_bg_color, "Faint accent").on_hover_text(ui.ctx().translate(
                 "Used for faint accentuation of interactive things, like striped grids.",
-            );
+            ));
             ui_color(ui, extreme_bg_color, "Extreme")
-                .on_hover_text("Background of plots and paintings");
+                .on_hover_text(ui.ctx().translate("Background of plots and paintings"));
         });
 
         ui.collapsing("Window", |ui| {
@@ -1763,6 +2118,25 @@ impl Visuals {
 
         ui.add(Slider::new(resize_corner_size, 0.0..=20.0).text("resize_corner_size"));
         ui.checkbox(text_cursor_preview, "Preview text cursor on hover");
+        ui.horizontal(|ui| {
+            ui.checkbox(text_cursor_blink, "Blink text cursor");
+            ui.add_enabled_ui(*text_cursor_blink, |ui| {
+                ui.add(
+                    DragValue::new(text_cursor_on_duration)
+                        .prefix("on: ")
+                        .suffix(" s")
+                        .speed(0.01)
+                        .clamp_range(0.0..=2.0),
+                );
+                ui.add(
+                    DragValue::new(text_cursor_off_duration)
+                        .prefix("off: ")
+                        .suffix(" s")
+                        .speed(0.01)
+                        .clamp_range(0.0..=2.0),
+                );
+            });
+        });
         ui.add(Slider::new(clip_rect_margin, 0.0..=20.0).text("clip_rect_margin"));
 
         ui.checkbox(button_frame, "Button has a frame");
@@ -1789,7 +2163,10 @@ impl Visuals {
             });
 
         ui.checkbox(image_loading_spinners, "Image loading spinners")
-            .on_hover_text("Show a spinner when an Image is loading");
+            .on_hover_text(
+                ui.ctx()
+                    .translate("Show a spinner when an Image is loading"),
+            );
 
         ui.vertical_centered(|ui| reset_button(ui, self));
     }
@@ -1807,6 +2184,8 @@ impl DebugOptions {
             show_resize,
             show_interactive_widgets,
             show_blocking_widget,
+            show_repaint_causes,
+            show_overflow,
         } = self;
 
         {
@@ -1839,6 +2218,16 @@ impl DebugOptions {
             "Show which widget blocks the interaction of another widget",
         );
 
+        ui.checkbox(
+            show_repaint_causes,
+            "Show the frame number and what caused each repaint",
+        );
+
+        ui.checkbox(
+            show_overflow,
+            "Show truncated text and widgets clipped by their parent",
+        );
+
         ui.vertical_centered(|ui| reset_button(ui, self));
     }
 }
@@ -1918,3 +2307,31 @@ impl HandleShape {
         });
     }
 }
+
+#[test]
+fn removed_text_style_falls_back_to_body() {
+    let mut style = Style::default();
+    let caption = style.register_text_style("Caption", FontId::proportional(10.0));
+    assert_eq!(caption.resolve(&style), FontId::proportional(10.0));
+    assert_eq!(
+        caption.try_resolve(&style),
+        Some(FontId::proportional(10.0))
+    );
+
+    style.text_styles.remove(&caption);
+    assert_eq!(caption.try_resolve(&style), None);
+    assert_eq!(caption.resolve(&style), TextStyle::Body.resolve(&style));
+}
+
+#[test]
+fn number_format_thousands_separator() {
+    let format = NumberFormat {
+        thousands_separator: Some(' '),
+        ..Default::default()
+    };
+    assert_eq!(format.format("0"), "0");
+    assert_eq!(format.format("999"), "999");
+    assert_eq!(format.format("1234"), "1 234");
+    assert_eq!(format.format("-56789.5"), "-56 789.5");
+    assert_eq!(format.format("1234567"), "1 234 567");
+}
@@ -59,6 +59,16 @@ pub struct Ui {
     /// and all widgets will assume a gray style.
     enabled: bool,
 
+    /// See [`Self::is_sizing_pass`].
+    sizing_pass: bool,
+
+    /// Is this [`Ui`] inside a [`ScrollArea`] that scrolls in these directions?
+    /// Then content outside the clip rect is scrolled out of view rather than clipped away.
+    scrolling: Vec2b,
+
+    /// See [`Self::interaction_priority`].
+    interaction_priority: i32,
+
     /// Indicates whether this Ui belongs to a Menu.
     menu_state: Option<Arc<RwLock<MenuState>>>,
 }
@@ -73,6 +83,11 @@ impl Ui {
     /// [`SidePanel`], [`TopBottomPanel`], [`CentralPanel`], [`Window`] or [`Area`].
     pub fn new(ctx: Context, layer_id: LayerId, id: Id, max_rect: Rect, clip_rect: Rect) -> Self {
         let style = ctx.style();
+        ctx.frame_state_mut(|fs| {
+            if let Some(widget_tree) = &mut fs.widget_tree {
+                widget_tree.add_ui(None, id, layer_id, max_rect);
+            }
+        });
         Ui {
             id,
             next_auto_id_source: id.with("auto").value(),
@@ -80,6 +95,9 @@ impl Ui {
             style,
             placer: Placer::new(max_rect, Layout::default()),
             enabled: true,
+            sizing_pass: false,
+            scrolling: Vec2b::FALSE,
+            interaction_priority: 0,
             menu_state: None,
         }
     }
@@ -100,13 +118,22 @@ impl Ui {
         let next_auto_id_source = Id::new(self.next_auto_id_source).with("child").value();
         self.next_auto_id_source = self.next_auto_id_source.wrapping_add(1);
         let menu_state = self.menu_state();
+        let id = self.id.with(id_source);
+        self.ctx().frame_state_mut(|fs| {
+            if let Some(widget_tree) = &mut fs.widget_tree {
+                widget_tree.add_ui(Some(self.id), id, self.layer_id(), max_rect);
+            }
+        });
         Ui {
-            id: self.id.with(id_source),
+            id,
             next_auto_id_source,
             painter: self.painter.clone(),
             style: self.style.clone(),
             placer: Placer::new(max_rect, layout),
             enabled: self.enabled,
+            sizing_pass: self.sizing_pass,
+            scrolling: self.scrolling,
+            interaction_priority: self.interaction_priority,
             menu_state,
         }
     }
@@ -252,6 +279,10 @@ impl Ui {
     /// Calling `set_visible(false)` will cause all further widgets to be invisible,
     /// yet still allocate space.
     ///
+    /// The widgets are laid out exactly as if they were visible,
+    /// so toggling the visibility of a part of the ui does not move the widgets around it.
+    /// To hide a single widget, use [`Self::add_visible`].
+    ///
     /// The widgets will not be interactive (`set_visible(false)` implies `set_enabled(false)`).
     ///
     /// Calling `set_visible(true)` has no effect.
@@ -276,6 +307,137 @@ impl Ui {
         }
     }
 
+    /// Make the widgets in this [`Ui`] semi-transparent.
+    ///
+    /// `opacity` must be between 0.0 and 1.0, where 0.0 means fully transparent (i.e., invisible)
+    /// and 1.0 means fully opaque.
+    ///
+    /// This is useful for fading in and out a whole panel or HUD,
+    /// e.g. with [`Context::animate_bool`].
+    ///
+    /// ### Example
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// ui.group(|ui| {
+    ///     ui.set_opacity(0.5);
+    ///     if ui.button("Now you see me").clicked() {
+    ///         /* … */
+    ///     }
+    /// });
+    /// # });
+    /// ```
+    ///
+    /// To fade a whole layer (e.g. a window), use [`Context::set_layer_opacity`] instead.
+    ///
+    /// See also: [`Self::opacity`] and [`Self::multiply_opacity`].
+    pub fn set_opacity(&mut self, opacity: f32) {
+        self.painter.set_opacity(opacity);
+    }
+
+    /// Like [`Self::set_opacity`], but multiplies the given value with the current opacity.
+    ///
+    /// See also: [`Self::set_opacity`] and [`Self::opacity`].
+    pub fn multiply_opacity(&mut self, opacity: f32) {
+        self.painter.multiply_opacity(opacity);
+    }
+
+    /// Read the current opacity of the underlying painter.
+    ///
+    /// See also: [`Self::set_opacity`] and [`Self::multiply_opacity`].
+    #[inline]
+    pub fn opacity(&self) -> f32 {
+        self.painter.opacity()
+    }
+
+    /// Is this an invisible pass used only to measure the size of the contents?
+    ///
+    /// During a sizing pass, widgets should take up as little space as they need,
+    /// instead of filling the available space.
+    /// For instance, separators have zero length and justified layouts are not justified.
+    ///
+    /// Containers can use the measured size to lay out the contents properly the next frame.
+    /// [`Area`]s (and thus [`Window`]s, menus and popups) and [`Grid`]s do a sizing pass the first frame they are shown.
+    ///
+    /// See also [`Self::set_sizing_pass`].
+    #[inline]
+    pub fn is_sizing_pass(&self) -> bool {
+        self.sizing_pass
+    }
+
+    /// Make this [`Ui`] and all its children do a sizing pass (see [`Self::is_sizing_pass`]).
+    ///
+    /// The widgets will be invisible and non-interactive.
+    /// This also requests a repaint, so that the contents can be shown with the measured size right away.
+    ///
+    /// ### Example
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let is_first_frame = true;
+    /// ui.scope(|ui| {
+    ///     if is_first_frame {
+    ///         ui.set_sizing_pass();
+    ///     }
+    ///     ui.label("Measure me");
+    ///     ui.separator(); // zero length during the sizing pass
+    /// });
+    /// # });
+    /// ```
+    pub fn set_sizing_pass(&mut self) {
+        self.sizing_pass = true;
+        self.set_visible(false);
+        self.ctx().request_repaint();
+    }
+
+    /// Which widget wins the pointer when widgets overlap within the same layer?
+    ///
+    /// The rule is: the widget with the highest priority wins,
+    /// and among widgets with the same priority, the one added last (i.e. painted on top) wins.
+    /// The other widgets under the pointer are not hovered, and so can't be clicked or dragged either.
+    ///
+    /// The priority is `0` by default, and is inherited by child [`Ui`]s.
+    /// Overlapping widgets in different layers are decided by the layer order instead, see [`Area`].
+    ///
+    /// See also [`Self::set_interaction_priority`].
+    #[inline]
+    pub fn interaction_priority(&self) -> i32 {
+        self.interaction_priority
+    }
+
+    /// Let the widgets of this [`Ui`] and its children win the pointer over overlapping widgets
+    /// with a lower priority, even those added later.
+    ///
+    /// See [`Self::interaction_priority`] for the rule.
+    ///
+    /// ### Example
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let list_rect = ui.available_rect_before_wrap();
+    /// // A floating button that should win over the list below it,
+    /// // no matter in what order they are added:
+    /// let button_rect = egui::Rect::from_min_size(
+    ///     list_rect.right_bottom() - egui::vec2(40.0, 40.0),
+    ///     egui::vec2(32.0, 32.0),
+    /// );
+    /// ui.scope(|ui| {
+    ///     ui.set_interaction_priority(1);
+    ///     ui.put(button_rect, egui::Button::new("+"));
+    /// });
+    /// for i in 0..10 {
+    ///     ui.selectable_label(false, format!("Item {i}"));
+    /// }
+    /// # });
+    /// ```
+    #[inline]
+    pub fn set_interaction_priority(&mut self, priority: i32) {
+        self.interaction_priority = priority;
+    }
+
+    /// Mark this [`Ui`] and its children as the content of a [`ScrollArea`] scrolling in these directions.
+    pub(crate) fn set_scrolling(&mut self, scrolling: Vec2b) {
+        self.scrolling.x |= scrolling.x;
+        self.scrolling.y |= scrolling.y;
+    }
+
     /// Read the [`Layout`].
     #[inline]
     pub fn layout(&self) -> &Layout {
@@ -315,6 +477,40 @@ impl Ui {
         self.fonts(|f| f.row_height(&style.resolve(self.style())))
     }
 
+    /// The width of `text` in this text style, laid out without wrapping.
+    ///
+    /// See also [`Self::numeric_widget_width`].
+    pub fn text_width(&self, text: &str, style: &TextStyle) -> f32 {
+        let font_id = style.resolve(self.style());
+        self.fonts(|f| {
+            f.layout_no_wrap(text.to_owned(), font_id, Color32::TEMPORARY_COLOR)
+                .size()
+                .x
+        })
+    }
+
+    /// The width of a [`DragValue`] showing numbers as wide as `template`, e.g. `"-999.99"`.
+    ///
+    /// Use this to size numeric columns in tables and grids so they line up,
+    /// without any magic constants.
+    /// This includes the button padding, so it also fits a single-line [`TextEdit`]
+    /// with the same font and margin as a [`DragValue`].
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut values = [1.0, -12.5, 300.25];
+    /// let size = egui::vec2(ui.numeric_widget_width("-999.99"), ui.spacing().interact_size.y);
+    /// for value in &mut values {
+    ///     ui.add_sized(size, egui::DragValue::new(value).fixed_decimals(2));
+    /// }
+    /// # });
+    /// ```
+    pub fn numeric_widget_width(&self, template: &str) -> f32 {
+        let text_width = self.text_width(template, &self.style().drag_value_text_style);
+        let width = text_width + 2.0 * self.spacing().button_padding.x;
+        width.at_least(self.spacing().interact_size.x)
+    }
+
     /// Screen-space rectangle for clipping what we paint in this ui.
     /// This is used, for instance, to avoid painting outside a window that is smaller than its contents.
     #[inline]
@@ -624,15 +820,20 @@ impl Ui {
 /// # Interaction
 impl Ui {
     /// Check for clicks, drags and/or hover on a specific region of this [`Ui`].
+    ///
+    /// If several widgets overlap, only one of them is hovered,
+    /// see [`Self::interaction_priority`].
     pub fn interact(&self, rect: Rect, id: Id, sense: Sense) -> Response {
+        self.record_widget(rect, id, sense);
         self.ctx().interact(
             self.clip_rect(),
-            self.spacing().item_spacing,
+            self.spacing(),
             self.layer_id(),
             id,
             rect,
             sense,
             self.enabled,
+            self.interaction_priority,
         )
     }
 
@@ -648,10 +849,20 @@ impl Ui {
         id: Id,
         sense: Sense,
     ) -> Response {
+        self.record_widget(rect, id, sense);
         self.ctx()
             .interact_with_hovered(self.layer_id(), id, rect, sense, self.enabled, hovered)
     }
 
+    /// Add a widget of this [`Ui`] to the widget tree, see [`Context::copy_widget_tree`].
+    fn record_widget(&self, rect: Rect, id: Id, sense: Sense) {
+        self.ctx().frame_state_mut(|fs| {
+            if let Some(widget_tree) = &mut fs.widget_tree {
+                widget_tree.add_widget(self.id, id, rect, sense);
+            }
+        });
+    }
+
     /// Is the pointer (mouse/touch) above this rectangle in this [`Ui`]?
     ///
     /// The `clip_rect` and layer of this [`Ui`] will be respected, so, for instance,
@@ -708,6 +919,36 @@ impl Ui {
         (rect, response)
     }
 
+    /// Like [`Self::allocate_exact_size`], but without checking for any interaction, not even hover.
+    ///
+    /// This is cheaper, for widgets that are only painted.
+    /// The returned [`Response`] is never hovered, clicked, focused etc.
+    pub(crate) fn allocate_exact_size_inert(&mut self, desired_size: Vec2) -> (Rect, Response) {
+        let (id, rect) = self.allocate_space(desired_size);
+        let response = Response {
+            ctx: self.ctx().clone(),
+            layer_id: self.layer_id(),
+            id,
+            rect,
+            sense: Sense::hover(),
+            enabled: self.enabled,
+            hovered: false,
+            highlighted: false,
+            clicked: Default::default(),
+            double_clicked: Default::default(),
+            triple_clicked: Default::default(),
+            dragged: false,
+            drag_released: false,
+            is_pointer_button_down_on: false,
+            long_touched: false,
+            interact_pointer_pos: None,
+            changed: false,
+            intrinsic_size: None,
+        };
+        let rect = self.placer.align_size_within_rect(desired_size, rect);
+        (rect, response)
+    }
+
     /// Allocate at least as much space as needed, and interact with that rect.
     ///
     /// The returned [`Rect`] will be the same size as `Response::rect`.
@@ -782,13 +1023,24 @@ impl Ui {
         (id, rect)
     }
 
+    /// Place a widget of the given size within the space allocated for it.
+    ///
+    /// During a sizing pass we don't justify, so that we measure the size the contents need.
+    fn justify_and_align(&self, frame_rect: Rect, desired_size: Vec2) -> Rect {
+        if self.sizing_pass {
+            self.placer.align_size_within_rect(desired_size, frame_rect)
+        } else {
+            self.placer.justify_and_align(frame_rect, desired_size)
+        }
+    }
+
     /// Reserve this much space and move the cursor.
     /// Returns where to put the widget.
     fn allocate_space_impl(&mut self, desired_size: Vec2) -> Rect {
         let item_spacing = self.spacing().item_spacing;
         let frame_rect = self.placer.next_space(desired_size, item_spacing);
         egui_assert!(!frame_rect.any_nan());
-        let widget_rect = self.placer.justify_and_align(frame_rect, desired_size);
+        let widget_rect = self.justify_and_align(frame_rect, desired_size);
 
         self.placer
             .advance_after_rects(frame_rect, widget_rect, item_spacing);
@@ -882,7 +1134,7 @@ impl Ui {
         crate::egui_assert!(desired_size.x >= 0.0 && desired_size.y >= 0.0);
         let item_spacing = self.spacing().item_spacing;
         let frame_rect = self.placer.next_space(desired_size, item_spacing);
-        let child_rect = self.placer.justify_and_align(frame_rect, desired_size);
+        let child_rect = self.justify_and_align(frame_rect, desired_size);
 
         let mut child_ui = self.child_ui(child_rect, layout);
         let ret = add_contents(&mut child_ui);
@@ -1348,6 +1600,22 @@ impl Ui {
         TextEdit::singleline(text).ui(self)
     }
 
+    /// A text field for typing in a number.
+    ///
+    /// Text that isn't a number is shown as an error, and reverted when the field loses focus.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut my_f32: f32 = 0.0;
+    /// ui.number_field(&mut my_f32);
+    /// # });
+    /// ```
+    ///
+    /// See also [`NumberField`] and [`ValidatedField`].
+    pub fn number_field<Num: emath::Numeric>(&mut self, value: &mut Num) -> Response {
+        NumberField::new(value).ui(self)
+    }
+
     /// A [`TextEdit`] for multiple lines. Pressing enter key will create a new line.
     ///
     /// See also [`TextEdit`].
@@ -1703,6 +1971,45 @@ impl Ui {
         self.scope_dyn(Box::new(add_contents), Id::new(id_source))
     }
 
+    /// Like [`Self::push_id`], but the [`Id`]s of everything inside only depend on the given key,
+    /// and not on where in the parent [`Ui`] it is added.
+    ///
+    /// With [`Self::push_id`], widgets without an explicit id (e.g. a [`TextEdit`] or a [`DragValue`])
+    /// still get an automatic id based on the position in the parent, see [`Self::next_auto_id`].
+    /// Use this instead when showing a list that can be reordered, with a key that follows each item,
+    /// so that all the state of each item (which [`CollapsingHeader`]s are open, scroll positions,
+    /// the cursor of a [`TextEdit`], which widget has keyboard focus, …) moves along with it.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// struct Item { key: u64, notes: String }
+    /// # let mut items: Vec<Item> = vec![];
+    /// for item in &mut items {
+    ///     ui.push_stable_id(item.key, |ui| {
+    ///         ui.collapsing("Notes", |ui| {
+    ///             ui.text_edit_multiline(&mut item.notes);
+    ///         });
+    ///     });
+    /// }
+    /// if ui.button("Reverse").clicked() {
+    ///     items.reverse(); // Each item keeps its state
+    /// }
+    /// # });
+    /// ```
+    pub fn push_stable_id<R>(
+        &mut self,
+        key: impl Hash,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> InnerResponse<R> {
+        self.scope_dyn(
+            Box::new(|ui: &mut Ui| {
+                ui.next_auto_id_source = ui.id.with("auto").value();
+                add_contents(ui)
+            }),
+            Id::new(key),
+        )
+    }
+
     /// Create a scoped child ui.
     ///
     /// You can use this to temporarily change the [`Style`] of a sub-region, for instance:
@@ -1719,6 +2026,28 @@ impl Ui {
         self.scope_dyn(Box::new(add_contents), Id::new("child"))
     }
 
+    /// Show the value of a [`crate::util::Deferred`] once it has arrived,
+    /// and a spinner until then.
+    ///
+    /// Returns `None` in the inner response while the value hasn't arrived.
+    /// See [`crate::util::Deferred`] for an example.
+    pub fn deferred<T, R>(
+        &mut self,
+        deferred: &crate::util::Deferred<T>,
+        add_contents: impl FnOnce(&mut Ui, &T) -> R,
+    ) -> InnerResponse<Option<R>> {
+        self.scope(|ui| match deferred.ready() {
+            Some(value) => Some(add_contents(ui, value)),
+            None => {
+                ui.horizontal(|ui| {
+                    ui.spinner();
+                    ui.weak(ui.ctx().translate("Loading…"));
+                });
+                None
+            }
+        })
+    }
+
     fn scope_dyn<'c, R>(
         &mut self,
         add_contents: Box<dyn FnOnce(&mut Ui) -> R + 'c>,
@@ -2045,6 +2374,14 @@ impl Ui {
             .end_row(self.spacing().item_spacing, &self.painter().clone());
     }
 
+    /// Let the next cell of a [`Grid`] span this many columns.
+    ///
+    /// The cell is as wide as all the columns it spans, and only widens the last of them if it needs more space.
+    /// Does nothing outside a [`Grid`].
+    pub fn span_columns(&mut self, columns: usize) {
+        self.placer.span_columns(columns);
+    }
+
     /// Set row height in horizontal wrapping layout.
     pub fn set_row_height(&mut self, height: f32) {
         self.placer.set_row_height(height);
@@ -2192,6 +2529,135 @@ impl Ui {
 
 // ----------------------------------------------------------------------------
 
+/// # Drag-and-drop
+impl Ui {
+    /// Create something that can be drag-and-dropped.
+    ///
+    /// The `id` needs to be globally unique.
+    /// The payload is what will be dropped if the user starts dragging.
+    ///
+    /// In contrast to [`Response::dnd_set_drag_payload`],
+    /// this function will paint the widget at the mouse cursor while the user is dragging.
+    ///
+    /// See also [`Self::dnd_drop_zone`] and [`crate::DragAndDrop`].
+    pub fn dnd_drag_source<Payload, R>(
+        &mut self,
+        id: Id,
+        payload: Payload,
+        add_contents: impl FnOnce(&mut Self) -> R,
+    ) -> InnerResponse<R>
+    where
+        Payload: std::any::Any + Send + Sync,
+    {
+        let is_being_dragged = self.memory(|mem| mem.is_being_dragged(id));
+
+        if is_being_dragged {
+            self.ctx().set_cursor_icon(CursorIcon::Grabbing);
+
+            // Paint the body to a new layer:
+            let layer_id = LayerId::new(Order::Tooltip, id);
+            let InnerResponse { inner, response } = self.with_layer_id(layer_id, add_contents);
+
+            // Now we move the visuals of the body to where the mouse is.
+            // Normally you need to decide a location for a widget first,
+            // because otherwise that widget cannot interact with the mouse.
+            // However, a dragged component cannot be interacted with anyway
+            // (anything with `Order::Tooltip` always gets an empty [`Response`])
+            // So this is fine!
+            if let Some(pointer_pos) = self.ctx().pointer_interact_pos() {
+                let delta = pointer_pos - response.rect.center();
+                self.ctx().translate_layer(layer_id, delta);
+            }
+
+            InnerResponse::new(inner, response)
+        } else {
+            let InnerResponse { inner, response } = self.scope(add_contents);
+
+            // Check for drags:
+            let dnd_response = self.interact(response.rect, id, Sense::drag());
+            dnd_response.dnd_set_drag_payload(payload);
+
+            InnerResponse::new(inner, dnd_response | response)
+        }
+    }
+
+    /// Surround the given ui with a frame which
+    /// changes colors when you can drop something onto it.
+    ///
+    /// Returns the dropped item, if it was released this frame.
+    ///
+    /// The given frame is used for its margins, but its color is ignored.
+    ///
+    /// ```
+    /// # egui::__run_test_ctx(|ctx| {
+    /// #[derive(Clone, Copy)]
+    /// struct Item(usize);
+    ///
+    /// egui::Window::new("Source").show(ctx, |ui| {
+    ///     let id = egui::Id::new("item").with(0);
+    ///     ui.dnd_drag_source(id, Item(0), |ui| ui.label("Drag me!"));
+    /// });
+    ///
+    /// egui::Window::new("Target").show(ctx, |ui| {
+    ///     let frame = egui::Frame::default().inner_margin(4.0);
+    ///     let (_, dropped_payload) = ui.dnd_drop_zone::<Item, ()>(frame, |ui| {
+    ///         ui.label("Drop it here");
+    ///     });
+    ///     if let Some(item) = dropped_payload {
+    ///         // The user dropped `item` onto this window.
+    ///     }
+    /// });
+    /// # });
+    /// ```
+    ///
+    /// See also [`Self::dnd_drag_source`] and [`crate::DragAndDrop`].
+    pub fn dnd_drop_zone<Payload, R>(
+        &mut self,
+        frame: Frame,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> (InnerResponse<R>, Option<Arc<Payload>>)
+    where
+        Payload: std::any::Any + Send + Sync,
+    {
+        let is_anything_being_dragged = DragAndDrop::has_any_payload(self.ctx());
+        let can_accept_what_is_being_dragged =
+            DragAndDrop::has_payload_of_type::<Payload>(self.ctx());
+
+        let mut frame = frame.begin(self);
+        let inner = add_contents(&mut frame.content_ui);
+
+        // NOTE: we can't use `response.hovered()` here, because it is always `false`
+        // while another widget (the drag source) is being dragged.
+        let is_hovering = self.rect_contains_pointer(frame.paint_rect());
+
+        let style = if is_anything_being_dragged && can_accept_what_is_being_dragged && is_hovering
+        {
+            self.visuals().widgets.active
+        } else {
+            self.visuals().widgets.inactive
+        };
+
+        let mut fill = style.bg_fill;
+        let mut stroke = style.bg_stroke;
+
+        if is_anything_being_dragged && !can_accept_what_is_being_dragged {
+            // When dragging something else, show that it can't be dropped here:
+            fill = self.visuals().gray_out(fill);
+            stroke.color = self.visuals().gray_out(stroke.color);
+        }
+
+        frame.frame.fill = fill;
+        frame.frame.stroke = stroke;
+
+        let response = frame.end(self);
+        let payload = response.dnd_release_payload::<Payload>();
+
+        (InnerResponse { inner, response }, payload)
+    }
+}
+
+// ----------------------------------------------------------------------------
+
 /// # Debug stuff
 impl Ui {
     /// Shows where the next widget is going to be placed
@@ -2199,6 +2665,44 @@ impl Ui {
     pub fn debug_paint_cursor(&self) {
         self.placer.debug_paint_cursor(&self.painter, "next");
     }
+
+    /// If [`crate::style::DebugOptions::show_overflow`] is set,
+    /// highlight the widget at `rect` if its content didn't fit,
+    /// i.e. if it needed `intrinsic_size`, or if it is clipped by this [`Ui`].
+    #[cfg(debug_assertions)]
+    pub(crate) fn debug_paint_overflow(&self, rect: Rect, intrinsic_size: Vec2) {
+        if !self.style().debug.show_overflow || !rect.is_finite() {
+            return;
+        }
+
+        let color = self.visuals().error_fg_color;
+
+        // Content sticking out of the clip rect is hidden, unless it can be scrolled into view:
+        let clip_rect = self.clip_rect().expand(0.5);
+        let clipped = (0..2).any(|d| {
+            !self.scrolling[d] && (rect.min[d] < clip_rect.min[d] || clip_rect.max[d] < rect.max[d])
+        });
+        if clipped && self.is_visible() && !self.sizing_pass {
+            // Use the debug-painter so we can show what was clipped away:
+            let painter = self.ctx().debug_painter();
+            painter.rect_stroke(rect, 0.0, (1.0, color));
+            painter.rect_filled(
+                rect.intersect(self.clip_rect()),
+                0.0,
+                color.gamma_multiply(0.2),
+            );
+        }
+
+        let truncated = intrinsic_size.x > rect.width() || intrinsic_size.y > rect.height();
+        if truncated {
+            self.painter.rect_stroke(rect, 0.0, (1.0, color));
+            self.ctx().debug_painter().rect_stroke(
+                Rect::from_min_size(rect.min, intrinsic_size),
+                0.0,
+                (1.0, color.gamma_multiply(0.5)),
+            );
+        }
+    }
 }
 
 #[cfg(debug_assertions)]
@@ -2211,6 +2715,8 @@ impl Drop for Ui {
 /// Show this rectangle to the user if certain debug options are set.
 #[cfg(debug_assertions)]
 fn register_rect(ui: &Ui, rect: Rect) {
+    ui.debug_paint_overflow(rect, rect.size());
+
     let debug = ui.style().debug;
 
     let show_callstacks = debug.debug_on_hover
@@ -2315,3 +2821,53 @@ fn ui_impl_send_sync() {
     fn assert_send_sync<T: Send + Sync>() {}
     assert_send_sync::<Ui>();
 }
+
+#[test]
+fn push_stable_id_does_not_depend_on_position() {
+    let ctx = Context::default();
+    let auto_ids = |keys: &[u64]| {
+        let mut auto_ids = vec![];
+        let _ = ctx.run(Default::default(), |ctx| {
+            crate::CentralPanel::default().show(ctx, |ui| {
+                for key in keys {
+                    let auto_id = ui.push_stable_id(key, |ui| ui.next_auto_id()).inner;
+                    auto_ids.push((*key, auto_id));
+                }
+            });
+        });
+        auto_ids.sort_by_key(|(key, _)| *key);
+        auto_ids
+    };
+    assert_eq!(auto_ids(&[1, 2, 3]), auto_ids(&[3, 1, 2]));
+}
+
+#[test]
+fn interaction_priority_decides_between_overlapping_widgets() {
+    let hovered = |first_priority: i32| {
+        let ctx = Context::default();
+        let rect = Rect::from_min_size(pos2(100.0, 100.0), vec2(50.0, 50.0));
+        let input = RawInput {
+            events: vec![Event::PointerMoved(rect.center())],
+            ..Default::default()
+        };
+        let mut hovered = [false; 2];
+        for _ in 0..2 {
+            let _ = ctx.run(input.clone(), |ctx| {
+                crate::CentralPanel::default().show(ctx, |ui| {
+                    let first = ui.scope(|ui| {
+                        ui.set_interaction_priority(first_priority);
+                        ui.interact(rect, Id::new("first"), Sense::click())
+                    });
+                    let second = ui.interact(rect, Id::new("second"), Sense::click());
+                    hovered = [first.inner.hovered(), second.hovered()];
+                });
+            });
+        }
+        hovered
+    };
+
+    // The widget added last wins…
+    assert_eq!(hovered(0), [false, true]);
+    // …unless the other one has a higher priority:
+    assert_eq!(hovered(1), [true, false]);
+}
@@ -0,0 +1,91 @@
+//! Values that arrive in a later frame, e.g. from a network request.
+
+use std::sync::{Arc, OnceLock};
+
+use crate::{Context, ViewportId};
+
+/// A value that is produced elsewhere, e.g. by a network request or on another thread,
+/// and that can be shown once it has arrived.
+///
+/// Create one with [`Deferred::new`], keep it in your app state,
+/// and show it with [`crate::Ui::deferred`], which shows a spinner until the value is ready.
+/// When the value is sent with the [`DeferredSender`], a repaint is requested,
+/// so the value is shown without waiting for any user input.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// let (sender, weather) = egui::util::Deferred::new(ui.ctx());
+/// std::thread::spawn(move || {
+///     // Fetch the weather over the network…
+///     sender.send(String::from("Sunny"));
+/// });
+///
+/// // Later, every frame:
+/// ui.deferred(&weather, |ui, weather| {
+///     ui.label(format!("The weather is {weather}"));
+/// });
+/// # });
+/// ```
+pub struct Deferred<T> {
+    value: Arc<OnceLock<T>>,
+}
+
+impl<T> Deferred<T> {
+    /// A value to be sent later with the returned [`DeferredSender`].
+    ///
+    /// The `ctx` is used to request a repaint when the value is sent.
+    pub fn new(ctx: &Context) -> (DeferredSender<T>, Self) {
+        let value = Arc::new(OnceLock::new());
+        let sender = DeferredSender {
+            value: value.clone(),
+            ctx: ctx.clone(),
+            viewport_id: ctx.viewport_id(),
+        };
+        (sender, Self { value })
+    }
+
+    /// A value that is ready right away.
+    pub fn from_value(value: T) -> Self {
+        Self {
+            value: Arc::new(OnceLock::from(value)),
+        }
+    }
+
+    /// Has the value arrived?
+    #[inline]
+    pub fn is_ready(&self) -> bool {
+        self.value.get().is_some()
+    }
+
+    /// The value, once it has arrived.
+    #[inline]
+    pub fn ready(&self) -> Option<&T> {
+        self.value.get()
+    }
+}
+
+impl<T> std::fmt::Debug for Deferred<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Deferred")
+            .field("is_ready", &self.is_ready())
+            .finish()
+    }
+}
+
+/// Sends the value of a [`Deferred`], see [`Deferred::new`].
+///
+/// This can be sent to another thread.
+pub struct DeferredSender<T> {
+    value: Arc<OnceLock<T>>,
+    ctx: Context,
+    viewport_id: ViewportId,
+}
+
+impl<T> DeferredSender<T> {
+    /// Make the value ready and request a repaint of the viewport the [`Deferred`] was created in.
+    pub fn send(self, value: T) {
+        if self.value.set(value).is_ok() {
+            self.ctx.request_repaint_of(self.viewport_id);
+        }
+    }
+}
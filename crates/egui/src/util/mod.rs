@@ -1,10 +1,12 @@
 //! Miscellaneous tools used by the rest of egui.
 
 pub mod cache;
+pub mod deferred;
 pub(crate) mod fixed_cache;
 pub mod id_type_map;
 pub mod undoer;
 
+pub use deferred::{Deferred, DeferredSender};
 pub use id_type_map::IdTypeMap;
 
 pub use epaint::emath::History;
@@ -0,0 +1,192 @@
+//! Recording of the widget hierarchy of a frame, see [`crate::Context::copy_widget_tree`].
+
+use std::fmt::Write as _;
+
+use crate::*;
+
+/// A [`Ui`] or a widget in a [`WidgetTree`].
+#[derive(Clone, Debug)]
+struct WidgetTreeNode {
+    id: Id,
+
+    /// Only set for the root [`Ui`]s of the layers.
+    layer_id: Option<LayerId>,
+
+    /// `None` for [`Ui`]s.
+    widget: Option<WidgetTreeWidget>,
+
+    /// For [`Ui`]s this is the `max_rect` they were created with.
+    rect: Rect,
+
+    /// The final rect of a [`Ui`], if it was interacted with by its parent.
+    ui_rect: Option<Rect>,
+
+    children: Vec<usize>,
+}
+
+#[derive(Clone, Debug)]
+struct WidgetTreeWidget {
+    sense: Sense,
+    info: Option<WidgetInfo>,
+}
+
+/// The [`Ui`]s and widgets of a frame, in the order they were added.
+///
+/// Recorded for a frame when requested with [`Context::copy_widget_tree`].
+#[derive(Clone, Debug, Default)]
+pub(crate) struct WidgetTree {
+    nodes: Vec<WidgetTreeNode>,
+    roots: Vec<usize>,
+
+    /// The last [`Ui`] created with each [`Id`].
+    ///
+    /// Sibling [`Ui`]s can share the same [`Id`] (e.g. with [`Ui::child_ui`]),
+    /// but they are usually filled in one at a time.
+    uis: IdMap<usize>,
+
+    /// The last widget with each [`Id`].
+    widgets: IdMap<usize>,
+}
+
+impl WidgetTree {
+    fn push(&mut self, parent: Option<Id>, node: WidgetTreeNode) -> usize {
+        let index = self.nodes.len();
+        match parent.and_then(|parent| self.uis.get(&parent).copied()) {
+            Some(parent) => self.nodes[parent].children.push(index),
+            None => self.roots.push(index),
+        }
+        self.nodes.push(node);
+        index
+    }
+
+    /// A [`Ui`] was created, with `parent` being the [`Id`] of its parent [`Ui`], if any.
+    pub fn add_ui(&mut self, parent: Option<Id>, id: Id, layer_id: LayerId, max_rect: Rect) {
+        let node = WidgetTreeNode {
+            id,
+            layer_id: parent.is_none().then_some(layer_id),
+            widget: None,
+            rect: max_rect,
+            ui_rect: None,
+            children: vec![],
+        };
+        let index = self.push(parent, node);
+        self.uis.insert(id, index);
+    }
+
+    /// A widget was interacted with in the [`Ui`] with the [`Id`] `parent`.
+    pub fn add_widget(&mut self, parent: Id, id: Id, rect: Rect, sense: Sense) {
+        if let Some(&ui) = self.uis.get(&id) {
+            // A parent interacting with the rect of a child `Ui`, e.g. in `Ui::allocate_ui`:
+            self.nodes[ui].ui_rect = Some(rect);
+            return;
+        }
+
+        let node = WidgetTreeNode {
+            id,
+            layer_id: None,
+            widget: Some(WidgetTreeWidget { sense, info: None }),
+            rect,
+            ui_rect: None,
+            children: vec![],
+        };
+        let index = self.push(Some(parent), node);
+        self.widgets.insert(id, index);
+    }
+
+    /// A widget described itself with [`Response::widget_info`].
+    pub fn set_widget_info(&mut self, id: Id, rect: Rect, info: WidgetInfo) {
+        let index = match self.widgets.get(&id) {
+            Some(index) => *index,
+            None if self.uis.contains_key(&id) => return,
+            None => {
+                // Interacted with directly through the `Context`, so we don't know the parent.
+                self.add_widget(Id::NULL, id, rect, Sense::hover());
+                self.widgets[&id]
+            }
+        };
+        if let Some(widget) = &mut self.nodes[index].widget {
+            widget.info = Some(info);
+        }
+    }
+
+    /// The rect of a widget or [`Ui`].
+    ///
+    /// For [`Ui`]s that is their final rect if known, else the bounds of their contents,
+    /// or their `max_rect` if they are empty.
+    fn content_rect(&self, index: usize) -> Rect {
+        let node = &self.nodes[index];
+        if let Some(ui_rect) = node.ui_rect {
+            ui_rect
+        } else if node.widget.is_some() || node.children.is_empty() {
+            node.rect
+        } else {
+            node.children.iter().fold(Rect::NOTHING, |rect, &child| {
+                rect.union(self.content_rect(child))
+            })
+        }
+    }
+
+    fn format_node(&self, out: &mut String, index: usize, depth: usize) {
+        let node = &self.nodes[index];
+        let indent = "  ".repeat(depth);
+        let rect = self.content_rect(index);
+
+        match &node.widget {
+            None => {
+                write!(out, "{indent}Ui {:?} {rect:?}", node.id).ok();
+                if let Some(layer_id) = node.layer_id {
+                    write!(out, " layer: {}", layer_id.short_debug_format()).ok();
+                }
+            }
+            Some(WidgetTreeWidget { sense, info }) => {
+                let typ = info
+                    .as_ref()
+                    .map_or_else(|| "Widget".to_owned(), |info| format!("{:?}", info.typ));
+                write!(out, "{indent}{typ} {:?} {rect:?}", node.id).ok();
+                if let Some(info) = info {
+                    if let Some(label) = &info.label {
+                        write!(out, " label: {label:?}").ok();
+                    }
+                    if let Some(text) = &info.current_text_value {
+                        write!(out, " text: {text:?}").ok();
+                    }
+                    if let Some(selected) = info.selected {
+                        write!(out, " selected: {selected}").ok();
+                    }
+                    if let Some(value) = info.value {
+                        write!(out, " value: {value}").ok();
+                    }
+                    if !info.enabled {
+                        out.push_str(" disabled");
+                    }
+                }
+                let Sense {
+                    click,
+                    drag,
+                    focusable,
+                } = *sense;
+                for (sensed, name) in [(click, "click"), (drag, "drag"), (focusable, "focusable")] {
+                    if sensed {
+                        write!(out, " {name}").ok();
+                    }
+                }
+            }
+        }
+        out.push('\n');
+
+        for &child in &node.children {
+            self.format_node(out, child, depth + 1);
+        }
+    }
+}
+
+impl std::fmt::Display for WidgetTree {
+    /// One line per [`Ui`] or widget, indented by their nesting.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut out = String::new();
+        for &root in &self.roots {
+            self.format_node(&mut out, root, 0);
+        }
+        f.write_str(&out)
+    }
+}
@@ -0,0 +1,254 @@
+use crate::*;
+
+/// Pick black or white text, whichever is most readable on the given fill.
+fn contrast_color(fill: Color32) -> Color32 {
+    if Rgba::from(fill).intensity() < 0.5 {
+        Color32::WHITE
+    } else {
+        Color32::BLACK
+    }
+}
+
+/// A small pill-shaped label, e.g. for showing the number of unread items next to a tab.
+///
+/// The badge is vertically centered, so it lines up with text in a horizontal layout.
+///
+/// See also: [`Chip`].
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let unread = 120;
+/// ui.horizontal(|ui| {
+///     ui.label("Inbox");
+///     ui.add(egui::Badge::count(unread)); // shows "99+"
+///     ui.add(egui::Badge::new("beta").fill(egui::Color32::DARK_GREEN));
+/// });
+/// # });
+/// ```
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct Badge {
+    text: WidgetText,
+    fill: Option<Color32>,
+    text_color: Option<Color32>,
+}
+
+impl Badge {
+    pub fn new(text: impl Into<WidgetText>) -> Self {
+        Self {
+            text: text.into(),
+            fill: None,
+            text_color: None,
+        }
+    }
+
+    /// A badge showing a count, e.g. of unread messages.
+    ///
+    /// Counts above `99` are shown as `99+`.
+    pub fn count(count: usize) -> Self {
+        Self::count_with_max(count, 99)
+    }
+
+    /// A badge showing a count, with counts above `max` shown as e.g. `999+`.
+    pub fn count_with_max(count: usize, max: usize) -> Self {
+        if count > max {
+            Self::new(format!("{max}+"))
+        } else {
+            Self::new(count.to_string())
+        }
+    }
+
+    /// Background color.
+    ///
+    /// Default: [`Visuals::selection`] background.
+    #[inline]
+    pub fn fill(mut self, fill: impl Into<Color32>) -> Self {
+        self.fill = Some(fill.into());
+        self
+    }
+
+    /// Text color.
+    ///
+    /// Default: black or white, whichever is most readable on the [`Self::fill`].
+    #[inline]
+    pub fn text_color(mut self, text_color: impl Into<Color32>) -> Self {
+        self.text_color = Some(text_color.into());
+        self
+    }
+}
+
+impl Widget for Badge {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Self {
+            text,
+            fill,
+            text_color,
+        } = self;
+
+        let fill = fill.unwrap_or(ui.visuals().selection.bg_fill);
+        let text_color = text_color.unwrap_or_else(|| contrast_color(fill));
+
+        let galley = text.into_galley(ui, Some(false), f32::INFINITY, TextStyle::Small);
+        let padding = vec2(0.5 * galley.size().y, 1.0);
+        // At least as wide as it is tall, so that a single digit gives a circle:
+        let size = (galley.size() + 2.0 * padding).max(Vec2::splat(galley.size().y + 2.0));
+
+        let (rect, response) = ui.allocate_exact_size(size, Sense::hover());
+        response.widget_info(|| WidgetInfo::labeled(WidgetType::Label, galley.text()));
+
+        if ui.is_rect_visible(rect) {
+            let painter = ui.painter();
+            painter.rect_filled(rect, 0.5 * rect.height(), fill);
+            let text_pos = Align2::CENTER_CENTER
+                .align_size_within_rect(galley.size(), rect)
+                .min;
+            painter.galley_with_color(text_pos, galley.galley, text_color);
+        }
+
+        response
+    }
+}
+
+/// A small pill-shaped status chip, optionally with a close button.
+///
+/// Chips are clickable, e.g. for filters, and line up with text in a horizontal layout.
+///
+/// See also: [`Badge`].
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let mut tags = vec!["rust".to_owned(), "gui".to_owned()];
+/// ui.horizontal_wrapped(|ui| {
+///     tags.retain(|tag| {
+///         let mut open = true;
+///         ui.add(egui::Chip::new(tag.as_str()).open(&mut open));
+///         open
+///     });
+///     ui.add(egui::Chip::new("online").fill(egui::Color32::DARK_GREEN));
+/// });
+/// # });
+/// ```
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct Chip<'a> {
+    text: WidgetText,
+    fill: Option<Color32>,
+    text_color: Option<Color32>,
+    open: Option<&'a mut bool>,
+}
+
+impl<'a> Chip<'a> {
+    pub fn new(text: impl Into<WidgetText>) -> Self {
+        Self {
+            text: text.into(),
+            fill: None,
+            text_color: None,
+            open: None,
+        }
+    }
+
+    /// Background color.
+    ///
+    /// Default: the same as a [`Button`].
+    #[inline]
+    pub fn fill(mut self, fill: impl Into<Color32>) -> Self {
+        self.fill = Some(fill.into());
+        self
+    }
+
+    /// Text color.
+    ///
+    /// Default: the text color of a [`Button`] if there is no [`Self::fill`],
+    /// otherwise black or white, whichever is most readable on the fill.
+    #[inline]
+    pub fn text_color(mut self, text_color: impl Into<Color32>) -> Self {
+        self.text_color = Some(text_color.into());
+        self
+    }
+
+    /// Show a close button ("x") which sets `open` to `false` when clicked.
+    ///
+    /// The returned [`Response`] is then marked as changed.
+    #[inline]
+    pub fn open(mut self, open: &'a mut bool) -> Self {
+        self.open = Some(open);
+        self
+    }
+}
+
+impl<'a> Widget for Chip<'a> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Self {
+            text,
+            fill,
+            text_color,
+            open,
+        } = self;
+
+        let galley = text.into_galley(ui, Some(false), f32::INFINITY, TextStyle::Button);
+        let height = galley.size().y + 4.0;
+        let padding_x = 0.5 * height;
+        let close_size = if open.is_some() {
+            0.5 * galley.size().y
+        } else {
+            0.0
+        };
+        let close_spacing = if open.is_some() { 0.5 * padding_x } else { 0.0 };
+
+        let size = vec2(
+            2.0 * padding_x + galley.size().x + close_spacing + close_size,
+            height,
+        );
+        let (rect, mut response) = ui.allocate_exact_size(size, Sense::click());
+        response.widget_info(|| WidgetInfo::labeled(WidgetType::Button, galley.text()));
+
+        let close_rect = Rect::from_center_size(
+            pos2(rect.right() - padding_x - 0.5 * close_size, rect.center().y),
+            Vec2::splat(close_size),
+        );
+        let close_response = open.as_ref().map(|_| {
+            let close_response = ui.interact(
+                close_rect.expand(0.5 * close_spacing),
+                response.id.with("close"),
+                Sense::click(),
+            );
+            close_response.widget_info(|| {
+                WidgetInfo::labeled(WidgetType::Button, ui.ctx().translate("Close"))
+            });
+            close_response
+        });
+
+        if ui.is_rect_visible(rect) {
+            let visuals = ui.style().interact(&response);
+            let (fill, default_text_color) = match fill {
+                Some(fill) => (fill, contrast_color(fill)),
+                None => (visuals.weak_bg_fill, visuals.text_color()),
+            };
+            let text_color = text_color.unwrap_or(default_text_color);
+
+            let painter = ui.painter();
+            painter.rect(rect, 0.5 * rect.height(), fill, visuals.bg_stroke);
+
+            let text_pos = pos2(
+                rect.left() + padding_x,
+                rect.center().y - 0.5 * galley.size().y,
+            );
+            painter.galley_with_color(text_pos, galley.galley, text_color);
+
+            if let Some(close_response) = &close_response {
+                let stroke_width = ui.style().interact(close_response).fg_stroke.width;
+                let stroke = Stroke::new(stroke_width, text_color);
+                let close_rect = close_rect.expand(ui.style().interact(close_response).expansion);
+                painter.line_segment([close_rect.left_top(), close_rect.right_bottom()], stroke);
+                painter.line_segment([close_rect.right_top(), close_rect.left_bottom()], stroke);
+            }
+        }
+
+        if let (Some(open), Some(close_response)) = (open, close_response) {
+            if close_response.clicked() {
+                *open = false;
+                response.mark_changed();
+            }
+        }
+
+        response
+    }
+}
@@ -23,7 +23,9 @@ pub struct Button<'a> {
     image: Option<Image<'a>>,
     text: Option<WidgetText>,
     shortcut_text: WidgetText,
+    shortcut: Option<KeyboardShortcut>,
     wrap: Option<bool>,
+    wrap_width: Option<f32>,
 
     /// None means default for interact
     fill: Option<Color32>,
@@ -34,6 +36,8 @@ pub struct Button<'a> {
     min_size: Vec2,
     rounding: Option<Rounding>,
     selected: bool,
+    default_button: bool,
+    cancel_button: bool,
 }
 
 impl<'a> Button<'a> {
@@ -58,7 +62,9 @@ impl<'a> Button<'a> {
             text,
             image,
             shortcut_text: Default::default(),
+            shortcut: None,
             wrap: None,
+            wrap_width: None,
             fill: None,
             stroke: None,
             sense: Sense::click(),
@@ -67,6 +73,8 @@ impl<'a> Button<'a> {
             min_size: Vec2::ZERO,
             rounding: None,
             selected: false,
+            default_button: false,
+            cancel_button: false,
         }
     }
 
@@ -83,6 +91,17 @@ impl<'a> Button<'a> {
         self
     }
 
+    /// Wrap the text at this width, in points, instead of at the available width of the [`Ui`].
+    ///
+    /// The width is of the text itself, not including the padding, image and shortcut text.
+    ///
+    /// Unless [`Self::wrap`] is also set, this turns on wrapping.
+    #[inline]
+    pub fn wrap_width(mut self, wrap_width: f32) -> Self {
+        self.wrap_width = Some(wrap_width);
+        self
+    }
+
     /// Override background fill color. Note that this will override any on-hover effects.
     /// Calling this will also turn on the frame.
     #[inline]
@@ -151,12 +170,88 @@ impl<'a> Button<'a> {
         self
     }
 
+    /// Give the button a keyboard shortcut.
+    ///
+    /// The shortcut is shown on the right side of the button (unless you set [`Self::shortcut_text`]),
+    /// and pressing it while the button is shown will click the button,
+    /// unless the button is disabled or a [`crate::TextEdit`] has keyboard focus.
+    ///
+    /// The contents of a closed menu are never run, so to make the shortcut work
+    /// when the menu is closed you need to also check for it yourself,
+    /// e.g. with [`crate::InputState::consume_shortcut`]:
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// use egui::{Key, KeyboardShortcut, Modifiers};
+    /// const SAVE: KeyboardShortcut = KeyboardShortcut::new(Modifiers::COMMAND, Key::S);
+    ///
+    /// let mut save = ui.input_mut(|i| i.consume_shortcut(&SAVE));
+    /// ui.menu_button("File", |ui| {
+    ///     if ui.add(egui::Button::new("Save").shortcut(SAVE)).clicked() {
+    ///         save = true;
+    ///         ui.close_menu();
+    ///     }
+    /// });
+    /// if save {
+    ///     // …
+    /// }
+    /// # });
+    /// ```
+    #[inline]
+    pub fn shortcut(mut self, shortcut: KeyboardShortcut) -> Self {
+        self.shortcut = Some(shortcut);
+        self
+    }
+
     /// If `true`, mark this button as "selected".
     #[inline]
     pub fn selected(mut self, selected: bool) -> Self {
         self.selected = selected;
         self
     }
+
+    /// If `true`, this is the default button of the current window,
+    /// e.g. the "OK" button of a confirmation dialog.
+    ///
+    /// Pressing Enter clicks the button (unless some other widget has keyboard focus),
+    /// and the button is outlined with the accent color.
+    ///
+    /// Only the buttons in the layer with the keyboard focus react to Enter.
+    /// If nothing has focus, it is the window under the mouse pointer
+    /// (or the panels, if the pointer is not over any window),
+    /// and if the pointer is outside of egui, the top-most window.
+    ///
+    /// ```
+    /// # egui::__run_test_ctx(|ctx| {
+    /// # let mut dialog_open = true;
+    /// egui::Window::new("Delete file?").show(ctx, |ui| {
+    ///     ui.horizontal(|ui| {
+    ///         if ui.add(egui::Button::new("Delete").default_button(true)).clicked() {
+    ///             // …
+    ///             dialog_open = false;
+    ///         }
+    ///         if ui.add(egui::Button::new("Cancel").cancel_button(true)).clicked() {
+    ///             dialog_open = false;
+    ///         }
+    ///     });
+    /// });
+    /// # });
+    /// ```
+    #[inline]
+    pub fn default_button(mut self, default_button: bool) -> Self {
+        self.default_button = default_button;
+        self
+    }
+
+    /// If `true`, this is the cancel button of the current window,
+    /// and pressing Escape clicks it.
+    ///
+    /// The same rules as for [`Self::default_button`] apply for which layer reacts to Escape.
+    #[inline]
+    pub fn cancel_button(mut self, cancel_button: bool) -> Self {
+        self.cancel_button = cancel_button;
+        self
+    }
 }
 
 impl Widget for Button<'_> {
@@ -164,8 +259,10 @@ impl Widget for Button<'_> {
         let Button {
             text,
             image,
-            shortcut_text,
+            mut shortcut_text,
+            shortcut,
             wrap,
+            wrap_width,
             fill,
             stroke,
             sense,
@@ -174,10 +271,18 @@ impl Widget for Button<'_> {
             min_size,
             rounding,
             selected,
+            default_button,
+            cancel_button,
         } = self;
 
         let frame = frame.unwrap_or_else(|| ui.visuals().button_frame);
 
+        if let Some(shortcut) = &shortcut {
+            if shortcut_text.is_empty() {
+                shortcut_text = ui.ctx().format_shortcut(shortcut).into();
+            }
+        }
+
         let mut button_padding = if frame {
             ui.spacing().button_padding
         } else {
@@ -210,6 +315,11 @@ impl Widget for Button<'_> {
             text_wrap_width -= 60.0; // Some space for the shortcut text (which we never wrap).
         }
 
+        if let Some(wrap_width) = wrap_width {
+            text_wrap_width = wrap_width;
+        }
+        let wrap = wrap.or(wrap_width.map(|_| true));
+
         let text = text.map(|text| text.into_galley(ui, wrap, text_wrap_width, TextStyle::Button));
         let shortcut_text = (!shortcut_text.is_empty())
             .then(|| shortcut_text.into_galley(ui, Some(false), f32::INFINITY, TextStyle::Button));
@@ -237,6 +347,35 @@ impl Widget for Button<'_> {
         desired_size = desired_size.at_least(min_size);
 
         let (rect, mut response) = ui.allocate_at_least(desired_size, sense);
+
+        let outside_focus_trap = ui.memory(|mem| mem.is_outside_focus_trap(ui.layer_id()));
+
+        if let Some(shortcut) = &shortcut {
+            if ui.is_enabled()
+                && !outside_focus_trap
+                && !ui.ctx().text_edit_has_focus()
+                && ui.input_mut(|i| i.consume_shortcut(shortcut))
+            {
+                response.clicked[PointerButton::Primary as usize] = true;
+            }
+        }
+
+        if (default_button || cancel_button)
+            && ui.is_enabled()
+            && !outside_focus_trap
+            && is_in_current_layer(ui)
+        {
+            let focus = ui.memory(|mem| mem.focus());
+            let enter = default_button
+                && (focus.is_none() || focus == Some(response.id))
+                && ui.input_mut(|i| i.consume_key(Modifiers::NONE, Key::Enter));
+            let escape =
+                cancel_button && ui.input_mut(|i| i.consume_key(Modifiers::NONE, Key::Escape));
+            if enter || escape {
+                response.clicked[PointerButton::Primary as usize] = true;
+            }
+        }
+
         response.widget_info(|| {
             if let Some(text) = &text {
                 WidgetInfo::labeled(WidgetType::Button, text.text())
@@ -258,12 +397,13 @@ impl Widget for Button<'_> {
                 )
             } else if frame {
                 let expansion = Vec2::splat(visuals.expansion);
-                (
-                    expansion,
-                    visuals.rounding,
-                    visuals.weak_bg_fill,
-                    visuals.bg_stroke,
-                )
+                let stroke = if default_button {
+                    let accent = ui.visuals().selection.bg_fill;
+                    Stroke::new(visuals.bg_stroke.width.at_least(1.0), accent)
+                } else {
+                    visuals.bg_stroke
+                };
+                (expansion, visuals.rounding, visuals.weak_bg_fill, stroke)
             } else {
                 Default::default()
             };
@@ -336,6 +476,20 @@ impl Widget for Button<'_> {
     }
 }
 
+/// Is the [`Ui`] in the layer that currently receives Enter and Escape
+/// (see [`Button::default_button`])?
+fn is_in_current_layer(ui: &Ui) -> bool {
+    let ctx = ui.ctx();
+    let current_layer = ctx
+        .memory(|mem| mem.focused_layer())
+        .or_else(|| {
+            let pointer = ctx.input(|i| i.pointer.hover_pos())?;
+            Some(ctx.layer_id_at(pointer).unwrap_or_else(LayerId::background))
+        })
+        .or_else(|| ctx.memory(|mem| mem.areas().top_visible_window()));
+    current_layer.map_or(true, |layer_id| layer_id == ui.layer_id())
+}
+
 // ----------------------------------------------------------------------------
 
 // TODO(emilk): allow checkbox without a text label
@@ -681,3 +835,105 @@ impl<'a> Widget for ImageButton<'a> {
         widgets::image::texture_load_result_response(self.image.source(), &tlr, response)
     }
 }
+
+#[test]
+fn button_shortcut_is_ignored_while_editing_text() {
+    const SHORTCUT: KeyboardShortcut = KeyboardShortcut::new(Modifiers::NONE, Key::F2);
+
+    let ctx = Context::default();
+    let run = |press: bool, add_contents: &mut dyn FnMut(&mut Ui)| {
+        let events = if press {
+            vec![Event::Key {
+                key: SHORTCUT.key,
+                pressed: true,
+                repeat: false,
+                modifiers: SHORTCUT.modifiers,
+            }]
+        } else {
+            vec![]
+        };
+        let input = RawInput {
+            events,
+            ..Default::default()
+        };
+        let _ = ctx.run(input, |ctx| {
+            crate::CentralPanel::default().show(ctx, |ui| add_contents(ui));
+        });
+    };
+
+    let mut clicked = false;
+    run(true, &mut |ui| {
+        clicked = ui.add(Button::new("Save").shortcut(SHORTCUT)).clicked();
+    });
+    assert!(clicked);
+
+    // The contents of a closed menu are not run:
+    run(true, &mut |ui| {
+        ui.menu_button("File", |_ui| panic!("The menu is closed"));
+    });
+
+    let mut text = String::new();
+    let mut add_contents = |ui: &mut Ui| {
+        let response = ui.text_edit_singleline(&mut text);
+        if !response.has_focus() {
+            response.request_focus();
+        }
+        clicked = ui.add(Button::new("Save").shortcut(SHORTCUT)).clicked();
+    };
+    run(false, &mut add_contents);
+    run(true, &mut add_contents);
+    assert!(!clicked);
+}
+
+#[test]
+fn escape_goes_to_the_layer_with_focus_or_pointer() {
+    let ctx = Context::default();
+    let screen_rect = Rect::from_min_size(Pos2::ZERO, vec2(1000.0, 1000.0));
+    let run = |pointer: Pos2, focus_window: bool, escape: bool| -> (bool, bool) {
+        let mut events = vec![Event::PointerMoved(pointer)];
+        if escape {
+            events.push(Event::Key {
+                key: Key::Escape,
+                pressed: true,
+                repeat: false,
+                modifiers: Modifiers::NONE,
+            });
+        }
+        let input = RawInput {
+            screen_rect: Some(screen_rect),
+            events,
+            ..Default::default()
+        };
+        let mut panel_clicked = false;
+        let mut window_clicked = false;
+        let _ = ctx.run(input, |ctx| {
+            crate::CentralPanel::default().show(ctx, |ui| {
+                panel_clicked = ui.add(Button::new("Quit").cancel_button(true)).clicked();
+            });
+            crate::Window::new("Dialog")
+                .fixed_pos(pos2(500.0, 500.0))
+                .show(ctx, |ui| {
+                    let response = ui.add(Button::new("Cancel").cancel_button(true));
+                    if focus_window {
+                        response.request_focus();
+                    }
+                    window_clicked = response.clicked();
+                });
+        });
+        (panel_clicked, window_clicked)
+    };
+
+    // Let the window appear:
+    run(pos2(10.0, 10.0), false, false);
+
+    // The pointer is over the panel, outside of the unrelated window:
+    assert_eq!(run(pos2(10.0, 10.0), false, true), (true, false));
+
+    // The pointer is over the window:
+    assert_eq!(run(pos2(520.0, 520.0), false, true), (false, true));
+
+    // The focus wins over the pointer:
+    run(pos2(10.0, 10.0), true, false);
+    run(pos2(10.0, 10.0), false, false);
+    assert_eq!(run(pos2(10.0, 10.0), false, true), (false, true));
+}
@@ -232,7 +232,11 @@ fn color_text_ui(ui: &mut Ui, color: impl Into<Color32>, alpha: Alpha) {
     ui.horizontal(|ui| {
         let [r, g, b, a] = color.to_array();
 
-        if ui.button("📋").on_hover_text("Click to copy").clicked() {
+        if ui
+            .button("📋")
+            .on_hover_text(ui.ctx().translate("Click to copy"))
+            .clicked()
+        {
             if alpha == Alpha::Opaque {
                 ui.ctx().copy_text(format!("{r}, {g}, {b}"));
             } else {
@@ -242,28 +246,172 @@ fn color_text_ui(ui: &mut Ui, color: impl Into<Color32>, alpha: Alpha) {
 
         if alpha == Alpha::Opaque {
             ui.label(format!("rgb({r}, {g}, {b})"))
-                .on_hover_text("Red Green Blue");
+                .on_hover_text(ui.ctx().translate("Red Green Blue"));
         } else {
-            ui.label(format!("rgba({r}, {g}, {b}, {a})"))
-                .on_hover_text("Red Green Blue with premultiplied Alpha");
+            ui.label(format!("rgba({r}, {g}, {b}, {a})")).on_hover_text(
+                ui.ctx()
+                    .translate("Red Green Blue with premultiplied Alpha"),
+            );
+        }
+    });
+}
+
+/// Hover text showing the color in a few different notations.
+fn color_conversions_text(hsva: HsvaGamma) -> String {
+    let color = Color32::from(hsva);
+    let [r, g, b, a] = color.to_srgba_unmultiplied();
+    let HsvaGamma { h, s, v, .. } = hsva;
+    format!(
+        "hex: {}\nrgba: {r}, {g}, {b}, {a}\nhsv: {:.0}°, {:.0}%, {:.0}%",
+        color.to_hex(),
+        h * 360.0,
+        s * 100.0,
+        v * 100.0
+    )
+}
+
+/// An editable `#RRGGBBAA` field.
+///
+/// While the field has focus we keep the (possibly invalid) text the user is typing in temp storage,
+/// and only write it back to the color once it parses.
+fn color_hex_edit_ui(ui: &mut Ui, hsva: &mut HsvaGamma, alpha: Alpha) {
+    let id = ui.auto_id_with("hex_edit");
+    let color = Color32::from(*hsva);
+
+    let mut text = ui
+        .data(|d| d.get_temp::<String>(id))
+        .unwrap_or_else(|| color.to_hex());
+
+    let response = ui.add(
+        TextEdit::singleline(&mut text)
+            .id(id)
+            .font(TextStyle::Monospace)
+            .desired_width(ui.spacing().interact_size.x * 2.0)
+            .char_limit(9),
+    );
+
+    if response.changed() {
+        if let Ok(new_color) = Color32::from_hex(&text) {
+            let new_color = if alpha == Alpha::Opaque {
+                new_color.to_opaque()
+            } else {
+                new_color
+            };
+            *hsva = HsvaGamma::from(new_color);
+        }
+    }
+
+    if response.has_focus() {
+        ui.data_mut(|d| d.insert_temp(id, text));
+    } else {
+        ui.data_mut(|d| d.remove::<String>(id));
+    }
+
+    response.on_hover_text(ui.ctx().translate("Hex color (#RRGGBB or #RRGGBBAA)"));
+}
+
+/// Hex field plus numeric [`DragValue`]s for RGB(A) and HSV.
+fn color_values_ui(ui: &mut Ui, hsva: &mut HsvaGamma, alpha: Alpha) {
+    ui.horizontal(|ui| {
+        ui.label("Hex");
+        color_hex_edit_ui(ui, hsva, alpha);
+    });
+
+    ui.horizontal(|ui| {
+        ui.label("RGB");
+        let [mut r, mut g, mut b, mut a] = Color32::from(*hsva).to_srgba_unmultiplied();
+        let mut changed = false;
+        changed |= ui
+            .add(DragValue::new(&mut r))
+            .on_hover_text(ui.ctx().translate("Red"))
+            .changed();
+        changed |= ui
+            .add(DragValue::new(&mut g))
+            .on_hover_text(ui.ctx().translate("Green"))
+            .changed();
+        changed |= ui
+            .add(DragValue::new(&mut b))
+            .on_hover_text(ui.ctx().translate("Blue"))
+            .changed();
+        if alpha != Alpha::Opaque && hsva.a >= 0.0 {
+            changed |= ui
+                .add(DragValue::new(&mut a))
+                .on_hover_text(ui.ctx().translate("Alpha"))
+                .changed();
+        }
+        if changed {
+            let new_hsva = HsvaGamma::from(Color32::from_rgba_unmultiplied(r, g, b, a));
+            // Keep the hue if the new color is gray:
+            *hsva = HsvaGamma {
+                h: if new_hsva.s == 0.0 {
+                    hsva.h
+                } else {
+                    new_hsva.h
+                },
+                ..new_hsva
+            };
+        }
+    });
+
+    ui.horizontal(|ui| {
+        ui.label("HSV");
+        let mut h = hsva.h * 360.0;
+        let mut s = hsva.s * 100.0;
+        let mut v = hsva.v * 100.0;
+        let hue = DragValue::new(&mut h)
+            .clamp_range(0.0..=360.0)
+            .fixed_decimals(0)
+            .suffix("°");
+        let saturation = DragValue::new(&mut s)
+            .clamp_range(0.0..=100.0)
+            .fixed_decimals(0)
+            .suffix("%");
+        let value = DragValue::new(&mut v)
+            .clamp_range(0.0..=100.0)
+            .fixed_decimals(0)
+            .suffix("%");
+        if ui
+            .add(hue)
+            .on_hover_text(ui.ctx().translate("Hue"))
+            .changed()
+        {
+            hsva.h = h / 360.0;
+        }
+        if ui
+            .add(saturation)
+            .on_hover_text(ui.ctx().translate("Saturation"))
+            .changed()
+        {
+            hsva.s = s / 100.0;
+        }
+        if ui
+            .add(value)
+            .on_hover_text(ui.ctx().translate("Value"))
+            .changed()
+        {
+            hsva.v = v / 100.0;
         }
     });
 }
 
 fn color_picker_hsvag_2d(ui: &mut Ui, hsva: &mut HsvaGamma, alpha: Alpha) {
     let current_color_size = vec2(ui.spacing().slider_width, ui.spacing().interact_size.y);
-    show_color(ui, *hsva, current_color_size).on_hover_text("Selected color");
+    show_color(ui, *hsva, current_color_size).on_hover_ui(|ui| {
+        ui.label(ui.ctx().translate("Selected color"));
+        ui.monospace(color_conversions_text(*hsva));
+    });
 
     color_text_ui(ui, *hsva, alpha);
+    color_values_ui(ui, hsva, alpha);
 
     if alpha == Alpha::BlendOrAdditive {
         // We signal additive blending by storing a negative alpha (a bit ironic).
         let a = &mut hsva.a;
         let mut additive = *a < 0.0;
         ui.horizontal(|ui| {
-            ui.label("Blending:");
-            ui.radio_value(&mut additive, false, "Normal");
-            ui.radio_value(&mut additive, true, "Additive");
+            ui.label(ui.ctx().translate("Blending:"));
+            ui.radio_value(&mut additive, false, ui.ctx().translate("Normal"));
+            ui.radio_value(&mut additive, true, ui.ctx().translate("Additive"));
 
             if additive {
                 *a = -a.abs();
@@ -287,9 +435,11 @@ fn color_picker_hsvag_2d(ui: &mut Ui, hsva: &mut HsvaGamma, alpha: Alpha) {
             if *a < 0.0 {
                 *a = 0.5; // was additive, but isn't allowed to be
             }
-            color_slider_1d(ui, a, |a| HsvaGamma { a, ..opaque }.into()).on_hover_text("Alpha");
+            color_slider_1d(ui, a, |a| HsvaGamma { a, ..opaque }.into())
+                .on_hover_text(ui.ctx().translate("Alpha"));
         } else if !additive {
-            color_slider_1d(ui, a, |a| HsvaGamma { a, ..opaque }.into()).on_hover_text("Alpha");
+            color_slider_1d(ui, a, |a| HsvaGamma { a, ..opaque }.into())
+                .on_hover_text(ui.ctx().translate("Alpha"));
         }
     }
 
@@ -304,14 +454,16 @@ fn color_picker_hsvag_2d(ui: &mut Ui, hsva: &mut HsvaGamma, alpha: Alpha) {
         }
         .into()
     })
-    .on_hover_text("Hue");
+    .on_hover_text(ui.ctx().translate("Hue"));
 
     if false {
-        color_slider_1d(ui, s, |s| HsvaGamma { s, ..opaque }.into()).on_hover_text("Saturation");
+        color_slider_1d(ui, s, |s| HsvaGamma { s, ..opaque }.into())
+            .on_hover_text(ui.ctx().translate("Saturation"));
     }
 
     if false {
-        color_slider_1d(ui, v, |v| HsvaGamma { v, ..opaque }.into()).on_hover_text("Value");
+        color_slider_1d(ui, v, |v| HsvaGamma { v, ..opaque }.into())
+            .on_hover_text(ui.ctx().translate("Value"));
     }
 
     color_slider_2d(ui, s, v, |s, v| HsvaGamma { s, v, ..opaque }.into());
@@ -350,11 +502,13 @@ pub fn color_edit_button_hsva(ui: &mut Ui, hsva: &mut Hsva, alpha: Alpha) -> Res
     let open = ui.memory(|mem| mem.is_popup_open(popup_id));
     let mut button_response = color_button(ui, (*hsva).into(), open);
     if ui.style().explanation_tooltips {
-        button_response = button_response.on_hover_text("Click to edit color");
+        button_response = button_response.on_hover_text(ui.ctx().translate("Click to edit color"));
     }
 
     if button_response.clicked() {
         ui.memory_mut(|mem| mem.toggle_popup(popup_id));
+        // Remember the color we started with, so we can show it next to the new one:
+        ui.data_mut(|d| d.insert_temp(popup_id, *hsva));
     }
 
     const COLOR_SLIDER_WIDTH: f32 = 210.0;
@@ -362,12 +516,17 @@ pub fn color_edit_button_hsva(ui: &mut Ui, hsva: &mut Hsva, alpha: Alpha) -> Res
     // TODO(emilk): make it easier to show a temporary popup that closes when you click outside it
     if ui.memory(|mem| mem.is_popup_open(popup_id)) {
         let area_response = Area::new(popup_id)
-            .order(Order::Foreground)
+            .popup_of(ui)
             .fixed_pos(button_response.rect.max)
-            .constrain(true)
             .show(ui.ctx(), |ui| {
                 ui.spacing_mut().slider_width = COLOR_SLIDER_WIDTH;
                 Frame::popup(ui.style()).show(ui, |ui| {
+                    let old = ui.data(|d| d.get_temp::<Hsva>(popup_id));
+                    if let Some(old) = old {
+                        if old_new_color_ui(ui, old, hsva) {
+                            button_response.mark_changed();
+                        }
+                    }
                     if color_picker_hsva_2d(ui, hsva, alpha) {
                         button_response.mark_changed();
                     }
@@ -385,6 +544,29 @@ pub fn color_edit_button_hsva(ui: &mut Ui, hsva: &mut Hsva, alpha: Alpha) -> Res
     button_response
 }
 
+/// Shows the color from when the popup was opened next to the current one.
+///
+/// Clicking the old color reverts to it. Returns `true` on change.
+fn old_new_color_ui(ui: &mut Ui, old: Hsva, hsva: &mut Hsva) -> bool {
+    let mut changed = false;
+    ui.horizontal(|ui| {
+        ui.spacing_mut().item_spacing.x = 0.0;
+        let size = vec2(
+            ui.spacing().slider_width / 2.0,
+            ui.spacing().interact_size.y,
+        );
+        let old_response = show_color(ui, old, size)
+            .interact(Sense::click())
+            .on_hover_text(ui.ctx().translate("Old color. Click to revert."));
+        if old_response.clicked() && *hsva != old {
+            *hsva = old;
+            changed = true;
+        }
+        show_color(ui, *hsva, size).on_hover_text(ui.ctx().translate("New color"));
+    });
+    changed
+}
+
 /// Shows a button with the given color.
 /// If the user clicks the button, a full color picker is shown.
 pub fn color_edit_button_srgba(ui: &mut Ui, srgba: &mut Color32, alpha: Alpha) -> Response {
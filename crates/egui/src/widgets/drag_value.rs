@@ -47,6 +47,10 @@ fn set(get_set_value: &mut GetSetValue<'_>, value: f64) {
 
 /// A numeric value that you can change by dragging the number. More compact than a [`Slider`].
 ///
+/// When it has keyboard focus, the value can be typed in,
+/// changed by [`Self::speed`] with the up and down arrow keys, and by ten times that with page up and page down.
+/// With a bounded [`Self::clamp_range`], home and end go to its start and end.
+///
 /// ```
 /// # egui::__run_test_ui(|ui| {
 /// # let mut my_f32: f32 = 0.0;
@@ -401,7 +405,7 @@ impl<'a> Widget for DragValue<'a> {
         // in button mode for just one frame. This is important for
         // screen readers.
         let is_kb_editing = ui.memory_mut(|mem| {
-            mem.interested_in_focus(id);
+            mem.interested_in_focus_on_layer(id, ui.layer_id());
             let is_kb_editing = mem.has_focus(id);
             if mem.gained_focus(id) {
                 mem.drag_value.edit_string = None;
@@ -420,6 +424,7 @@ impl<'a> Widget for DragValue<'a> {
             .at_least(min_decimals);
         let auto_decimals = auto_decimals.clamp(min_decimals, max_decimals);
 
+        let mut jump_to = None;
         let change = ui.input_mut(|input| {
             let mut change = 0.0;
 
@@ -435,6 +440,19 @@ impl<'a> Widget for DragValue<'a> {
                 // problematic.
                 change += input.count_and_consume_key(Modifiers::NONE, Key::ArrowUp) as f64
                     - input.count_and_consume_key(Modifiers::NONE, Key::ArrowDown) as f64;
+                change += 10.0
+                    * (input.count_and_consume_key(Modifiers::NONE, Key::PageUp) as f64
+                        - input.count_and_consume_key(Modifiers::NONE, Key::PageDown) as f64);
+
+                // Home and End only jump to the ends of a bounded range,
+                // and otherwise move the caret as usual.
+                if clamp_range.start().is_finite() && input.consume_key(Modifiers::NONE, Key::Home)
+                {
+                    jump_to = Some(*clamp_range.start());
+                }
+                if clamp_range.end().is_finite() && input.consume_key(Modifiers::NONE, Key::End) {
+                    jump_to = Some(*clamp_range.end());
+                }
             }
 
             #[cfg(feature = "accesskit")]
@@ -464,6 +482,10 @@ impl<'a> Widget for DragValue<'a> {
             value = emath::round_to_decimals(value, auto_decimals);
         }
 
+        if let Some(jump_to) = jump_to {
+            value = jump_to;
+        }
+
         value = clamp_to_range(value, clamp_range.clone());
         if old_value != value {
             set(&mut get_set_value, value);
@@ -476,7 +498,12 @@ impl<'a> Widget for DragValue<'a> {
                 if value == 0.0 {
                     "0".to_owned()
                 } else {
-                    emath::format_with_decimals_in_range(value, auto_decimals..=max_decimals)
+                    ui.style()
+                        .number_format
+                        .format(&emath::format_with_decimals_in_range(
+                            value,
+                            auto_decimals..=max_decimals,
+                        ))
                 }
             }
         };
@@ -511,7 +538,7 @@ impl<'a> Widget for DragValue<'a> {
             if update {
                 let parsed_value = match custom_parser {
                     Some(parser) => parser(&value_text),
-                    None => value_text.parse().ok(),
+                    None => ui.style().number_format.parse(&value_text),
                 };
                 if let Some(parsed_value) = parsed_value {
                     let parsed_value = clamp_to_range(parsed_value, clamp_range.clone());
@@ -0,0 +1,191 @@
+use std::ops::RangeInclusive;
+
+use crate::*;
+
+/// A circular gauge showing a value between a min and a max, e.g. for dashboards.
+///
+/// The value is shown either as a filled arc (default) or with a needle,
+/// optionally with tick marks, and with a text in the center.
+///
+/// See also: [`crate::ProgressBar`].
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let rpm = 3500.0;
+/// ui.add(egui::Gauge::new(rpm).range(0.0..=8000.0).ticks(8).text("RPM"));
+/// # });
+/// ```
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct Gauge {
+    value: f32,
+    range: RangeInclusive<f32>,
+    size: Option<f32>,
+    sweep: f32,
+    ticks: usize,
+    needle: bool,
+    text: Option<WidgetText>,
+    fill: Option<Color32>,
+}
+
+impl Gauge {
+    /// A gauge showing the given value, by default in the range `0.0..=1.0`.
+    pub fn new(value: f32) -> Self {
+        Self {
+            value,
+            range: 0.0..=1.0,
+            size: None,
+            sweep: 270_f32.to_radians(),
+            ticks: 0,
+            needle: false,
+            text: None,
+            fill: None,
+        }
+    }
+
+    /// The values at the start and the end of the arc. Default: `0.0..=1.0`.
+    ///
+    /// Values outside the range are shown at the closest end.
+    #[inline]
+    pub fn range(mut self, range: RangeInclusive<f32>) -> Self {
+        self.range = range;
+        self
+    }
+
+    /// The diameter of the gauge. Default: four times the style's `interact_size.y`.
+    #[inline]
+    pub fn size(mut self, size: f32) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// How much of a full circle the arc covers, in radians.
+    /// The arc is always centered at the top.
+    ///
+    /// Default: 270°.
+    #[inline]
+    pub fn sweep_angle(mut self, sweep: f32) -> Self {
+        self.sweep = sweep.clamp(0.0, std::f32::consts::TAU);
+        self
+    }
+
+    /// Divide the arc into this many parts with tick marks. Default: `0` (no tick marks).
+    #[inline]
+    pub fn ticks(mut self, ticks: usize) -> Self {
+        self.ticks = ticks;
+        self
+    }
+
+    /// Point at the value with a needle instead of filling the arc up to it. Default: `false`.
+    #[inline]
+    pub fn needle(mut self, needle: bool) -> Self {
+        self.needle = needle;
+        self
+    }
+
+    /// The text in the center of the gauge. Default: the value.
+    #[inline]
+    pub fn text(mut self, text: impl Into<WidgetText>) -> Self {
+        self.text = Some(text.into());
+        self
+    }
+
+    /// The color of the filled arc or the needle.
+    #[inline]
+    pub fn fill(mut self, color: Color32) -> Self {
+        self.fill = Some(color);
+        self
+    }
+}
+
+impl Widget for Gauge {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Gauge {
+            value,
+            range,
+            size,
+            sweep,
+            ticks,
+            needle,
+            text,
+            fill,
+        } = self;
+
+        let size = size.unwrap_or_else(|| 4.0 * ui.spacing().interact_size.y);
+        let (rect, response) = ui.allocate_exact_size(Vec2::splat(size), Sense::hover());
+
+        let text = text
+            .unwrap_or_else(|| emath::format_with_decimals_in_range(value as f64, 0..=2).into());
+        response.widget_info(|| WidgetInfo::labeled(WidgetType::Other, text.text()));
+
+        if ui.is_rect_visible(rect) {
+            let visuals = ui.style().visuals.clone();
+            let fill = fill.unwrap_or(visuals.selection.bg_fill);
+
+            let thickness = (0.12 * size).at_least(2.0);
+            let center = rect.center();
+            let radius = size / 2.0 - thickness / 2.0 - 1.0;
+
+            // Angles are clockwise on screen, with the arc centered at the top:
+            let start_angle = -std::f32::consts::FRAC_PI_2 - sweep / 2.0;
+            let end_angle = start_angle + sweep;
+            let t = if range.start() == range.end() {
+                0.0
+            } else {
+                remap_clamp(value, range, 0.0..=1.0)
+            };
+            let value_angle = lerp(start_angle..=end_angle, t);
+            let direction = Vec2::angled;
+            let arc = |angles: RangeInclusive<f32>| {
+                let mut points = vec![];
+                epaint::tessellator::path::add_arc(&mut points, center, radius, angles);
+                points
+            };
+
+            let painter = ui.painter();
+            painter.add(Shape::line(
+                arc(start_angle..=end_angle),
+                Stroke::new(thickness, visuals.extreme_bg_color),
+            ));
+
+            if !needle && t > 0.0 {
+                painter.add(Shape::line(
+                    arc(start_angle..=value_angle),
+                    Stroke::new(thickness, fill),
+                ));
+            }
+
+            if ticks > 0 {
+                let tick_stroke = visuals.widgets.noninteractive.fg_stroke;
+                let inner_radius = radius - thickness / 2.0;
+                for i in 0..=ticks {
+                    let angle = lerp(start_angle..=end_angle, i as f32 / ticks as f32);
+                    painter.line_segment(
+                        [
+                            center + (inner_radius - thickness / 2.0) * direction(angle),
+                            center + inner_radius * direction(angle),
+                        ],
+                        tick_stroke,
+                    );
+                }
+            }
+
+            let text_pos = if needle {
+                let needle_length = radius - thickness;
+                painter.line_segment(
+                    [center, center + needle_length * direction(value_angle)],
+                    Stroke::new(2.0, fill),
+                );
+                painter.circle_filled(center, thickness / 3.0, fill);
+                center + vec2(0.0, radius / 2.0)
+            } else {
+                center
+            };
+
+            let galley = text.into_galley(ui, Some(false), f32::INFINITY, TextStyle::Body);
+            let text_pos = text_pos - galley.size() / 2.0;
+            galley.paint_with_fallback_color(ui.painter(), text_pos, visuals.text_color());
+        }
+
+        response
+    }
+}
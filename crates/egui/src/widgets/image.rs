@@ -48,6 +48,7 @@ pub struct Image<'a> {
     image_options: ImageOptions,
     sense: Sense,
     size: ImageSize,
+    placeholder_size: Option<Vec2>,
     pub(crate) show_loading_spinner: Option<bool>,
 }
 
@@ -73,6 +74,7 @@ impl<'a> Image<'a> {
                 image_options: Default::default(),
                 sense: Sense::hover(),
                 size,
+                placeholder_size: None,
                 show_loading_spinner: None,
             }
         }
@@ -253,6 +255,31 @@ impl<'a> Image<'a> {
         self.show_loading_spinner = Some(show);
         self
     }
+
+    /// The size to assume for the image while it is loading, or if it failed to load.
+    ///
+    /// If you know the size of the image before it has loaded (e.g. from the metadata of a gallery),
+    /// set it here to reserve the final space for the image,
+    /// so that the layout doesn't jump when it has loaded.
+    /// The image is sized the same way with this as with its actual size,
+    /// e.g. with [`Self::fit_to_exact_size`] and [`Self::max_size`].
+    ///
+    /// Default: 24x24 points.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// ui.add(
+    ///     egui::Image::new("https://picsum.photos/seed/1.759706314/1024")
+    ///         .placeholder_size(egui::vec2(1024.0, 1024.0))
+    ///         .max_width(200.0),
+    /// );
+    /// # });
+    /// ```
+    #[inline]
+    pub fn placeholder_size(mut self, size: Vec2) -> Self {
+        self.placeholder_size = Some(size);
+        self
+    }
 }
 
 impl<'a, T: Into<ImageSource<'a>>> From<T> for Image<'a> {
@@ -265,12 +292,21 @@ impl<'a> Image<'a> {
     /// Returns the size the image will occupy in the final UI.
     #[inline]
     pub fn calc_size(&self, available_size: Vec2, original_image_size: Option<Vec2>) -> Vec2 {
-        let original_image_size = original_image_size.unwrap_or(Vec2::splat(24.0)); // Fallback for still-loading textures, or failure to load.
+        // Fallback for still-loading textures, or failure to load:
+        let original_image_size = original_image_size
+            .or(self.placeholder_size)
+            .unwrap_or(Vec2::splat(24.0));
         self.size.calc_size(available_size, original_image_size)
     }
 
+    /// Like [`Self::calc_size`], but `None` if the size of the image isn't known yet,
+    /// and no [`Self::placeholder_size`] is set.
     pub fn load_and_calc_size(&self, ui: &Ui, available_size: Vec2) -> Option<Vec2> {
-        let image_size = self.load_for_size(ui.ctx(), available_size).ok()?.size()?;
+        let image_size = self
+            .load_for_size(ui.ctx(), available_size)
+            .ok()
+            .and_then(|tlr| tlr.size())
+            .or(self.placeholder_size)?;
         Some(self.size.calc_size(available_size, image_size))
     }
 
@@ -583,7 +619,10 @@ pub fn paint_texture_load_result(
             let show_loading_spinner =
                 show_loading_spinner.unwrap_or(ui.visuals().image_loading_spinners);
             if show_loading_spinner {
-                Spinner::new().paint_at(ui, rect);
+                // Keep the spinner round, also in a placeholder that isn't square:
+                let spinner_rect =
+                    Rect::from_center_size(rect.center(), Vec2::splat(rect.size().min_elem()));
+                Spinner::new().paint_at(ui, spinner_rect);
             }
         }
         Err(_) => {
@@ -609,11 +648,14 @@ pub fn texture_load_result_response(
         Ok(TexturePoll::Ready { .. }) => response,
         Ok(TexturePoll::Pending { .. }) => {
             let uri = source.uri().unwrap_or("image");
-            response.on_hover_text(format!("Loading {uri}…"))
+            let text = response.ctx.translate("Loading {uri}…");
+            response.on_hover_text(str::replace(&text, "{uri}", uri))
         }
         Err(err) => {
             let uri = source.uri().unwrap_or("image");
-            response.on_hover_text(format!("Failed loading {uri}: {err}"))
+            let text = response.ctx.translate("Failed loading {uri}: {err}");
+            let text = str::replace(&text, "{uri}", uri);
+            response.on_hover_text(str::replace(&text, "{err}", &err.to_string()))
         }
     }
 }
@@ -764,6 +806,7 @@ pub fn paint_texture_at(
                 rounding: options.rounding,
                 fill: options.tint,
                 stroke: Stroke::NONE,
+                stroke_kind: Default::default(),
                 fill_texture_id: texture.id,
                 uv: options.uv,
             });
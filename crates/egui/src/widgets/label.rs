@@ -1,4 +1,6 @@
-use crate::{widget_text::WidgetTextGalley, *};
+use std::ops::Range;
+
+use crate::{text::ElisionPosition, widget_text::WidgetTextGalley, *};
 
 /// Static text.
 ///
@@ -9,18 +11,35 @@ use crate::{widget_text::WidgetTextGalley, *};
 /// ui.label("Equivalent");
 /// ui.add(egui::Label::new("Equivalent"));
 /// ui.add(egui::Label::new("With Options").wrap(false));
+/// ui.add(egui::Label::new("Wraps at 100 points, however wide the ui is").wrap_width(100.0));
 /// ui.label(egui::RichText::new("With formatting").underline());
 /// # });
 /// ```
 ///
 /// For full control of the text you can use [`crate::text::LayoutJob`]
 /// as argument to [`Self::new`].
+///
+/// Parts of the text can be made into clickable links with [`Self::link`] and [`Self::link_to`].
 #[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
 pub struct Label {
     text: WidgetText,
     wrap: Option<bool>,
+    wrap_width: Option<f32>,
     truncate: bool,
+    elision_position: Option<ElisionPosition>,
     sense: Option<Sense>,
+    hoverable: bool,
+    links: Vec<LabelLink>,
+}
+
+/// A span of a [`Label`] that acts as a link.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct LabelLink {
+    /// Byte range into the text of the label.
+    byte_range: Range<usize>,
+
+    /// If set, clicking the link opens this url.
+    url: Option<String>,
 }
 
 impl Label {
@@ -28,8 +47,12 @@ impl Label {
         Self {
             text: text.into(),
             wrap: None,
+            wrap_width: None,
             truncate: false,
+            elision_position: None,
             sense: None,
+            hoverable: true,
+            links: Vec::new(),
         }
     }
 
@@ -55,6 +78,26 @@ impl Label {
         self
     }
 
+    /// Wrap (or truncate) the text at this width, in points,
+    /// instead of at the available width of the [`Ui`].
+    ///
+    /// This is useful for constraining the text of tooltips and narrow sidebars
+    /// without having to shrink the whole [`Ui`].
+    /// The text is allowed to extend past the available width of the [`Ui`].
+    ///
+    /// Unless [`Self::wrap`] or [`Self::truncate`] is also set, this turns on wrapping.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// ui.add(egui::Label::new("A long explanation that is nicer to read in a narrow column.").wrap_width(120.0));
+    /// # });
+    /// ```
+    #[inline]
+    pub fn wrap_width(mut self, wrap_width: f32) -> Self {
+        self.wrap_width = Some(wrap_width);
+        self
+    }
+
     /// If `true`, the text will stop at the max width of the [`Ui`],
     /// and what doesn't fit will be elided, replaced with `…`.
     ///
@@ -71,6 +114,18 @@ impl Label {
         self
     }
 
+    /// Where to elide the text when it is truncated, e.g. [`ElisionPosition::Middle`]
+    /// to show the end of file names and paths: `verylongfi…name.txt`.
+    ///
+    /// This implies [`Self::truncate`].
+    ///
+    /// Default: [`ElisionPosition::End`], unless set in a [`text::LayoutJob`].
+    #[inline]
+    pub fn elision_position(mut self, elision_position: ElisionPosition) -> Self {
+        self.elision_position = Some(elision_position);
+        self.truncate(true)
+    }
+
     /// Make the label respond to clicks and/or drags.
     ///
     /// By default, a label is inert and does not respond to click or drags.
@@ -90,14 +145,97 @@ impl Label {
         self.sense = Some(sense);
         self
     }
+
+    /// If `false`, the label doesn't even sense hover,
+    /// so the returned [`Response`] is never hovered and [`Response::on_hover_text`] does nothing.
+    ///
+    /// Together with `.wrap(false)` this lets the label skip all interaction and wrapping,
+    /// which is measurably faster for UIs showing tens of thousands of short labels each frame,
+    /// like profilers and big tables.
+    /// (This fast path is not used for labels with links or a [`Self::sense`], or when a screen reader is on.)
+    ///
+    /// Default is `true`.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// for i in 0..1000 {
+    ///     ui.add(egui::Label::new(format!("Row {i}")).hoverable(false).wrap(false));
+    /// }
+    /// # });
+    /// ```
+    #[inline]
+    pub fn hoverable(mut self, hoverable: bool) -> Self {
+        self.hoverable = hoverable;
+        self
+    }
+
+    /// Make the text in the given byte range look and act like a [`Link`].
+    ///
+    /// Use [`Self::show`] to find out which link was clicked.
+    /// Links are numbered in the order they were added.
+    ///
+    /// For text given as a pre-made galley,
+    /// the link is not recolored, but it is still clickable and underlined on hover.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let text = "Press here to reset.";
+    /// let start = text.find("here").unwrap();
+    /// let output = egui::Label::new(text).link(start..start + 4).show(ui);
+    /// if output.clicked_link == Some(0) {
+    ///     /* … */
+    /// }
+    /// # });
+    /// ```
+    #[inline]
+    pub fn link(mut self, byte_range: Range<usize>) -> Self {
+        self.links.push(LabelLink {
+            byte_range,
+            url: None,
+        });
+        self
+    }
+
+    /// Make the text in the given byte range into a hyperlink, which opens the given url when clicked.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let text = "For more, see the documentation.";
+    /// let start = text.find("the documentation").unwrap();
+    /// ui.add(egui::Label::new(text).link_to(start..text.len() - 1, "https://docs.rs/egui"));
+    /// # });
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn link_to(mut self, byte_range: Range<usize>, url: impl ToString) -> Self {
+        self.links.push(LabelLink {
+            byte_range,
+            url: Some(url.to_string()),
+        });
+        self
+    }
+}
+
+/// The output of [`Label::show`].
+pub struct LabelOutput {
+    /// The response of the whole label.
+    pub response: Response,
+
+    /// The index of the link (see [`Label::link`]) that was clicked this frame, if any.
+    pub clicked_link: Option<usize>,
+
+    /// The index of the link that is currently hovered, if any.
+    pub hovered_link: Option<usize>,
 }
 
 impl Label {
     /// Do layout and position the galley in the ui, without painting it or adding widget info.
     pub fn layout_in_ui(self, ui: &mut Ui) -> (Pos2, WidgetTextGalley, Response) {
         let sense = self.sense.unwrap_or_else(|| {
-            // We only want to focus labels if the screen reader is on.
-            if ui.memory(|mem| mem.options.screen_reader) {
+            if !self.links.is_empty() {
+                Sense::click()
+            } else if ui.memory(|mem| mem.options.screen_reader) {
+                // We only want to focus labels if the screen reader is on.
                 Sense::focusable_noninteractive()
             } else {
                 Sense::hover()
@@ -123,11 +261,26 @@ impl Label {
             .text
             .into_text_job(ui.style(), FontSelection::Default, valign);
 
+        if !self.links.is_empty() {
+            let text_color = (!text_job.job_has_color).then(|| ui.visuals().text_color());
+            color_links(
+                &mut text_job.job,
+                &self.links,
+                ui.visuals().hyperlink_color,
+                text_color,
+            );
+            text_job.job_has_color = true;
+        }
+
         let truncate = self.truncate;
-        let wrap = !truncate && self.wrap.unwrap_or_else(|| ui.wrap_text());
-        let available_width = ui.available_width();
+        let wrap = !truncate
+            && self
+                .wrap
+                .unwrap_or_else(|| self.wrap_width.is_some() || ui.wrap_text());
+        let available_width = self.wrap_width.unwrap_or_else(|| ui.available_width());
 
         if wrap
+            && self.wrap_width.is_none()
             && ui.layout().main_dir() == Direction::LeftToRight
             && ui.layout().main_wrap()
             && available_width.is_finite()
@@ -168,6 +321,9 @@ impl Label {
                 text_job.job.wrap.max_width = available_width;
                 text_job.job.wrap.max_rows = 1;
                 text_job.job.wrap.break_anywhere = true;
+                if let Some(elision_position) = self.elision_position {
+                    text_job.job.wrap.elision_position = elision_position;
+                }
             } else if wrap {
                 text_job.job.wrap.max_width = available_width;
             } else {
@@ -184,7 +340,13 @@ impl Label {
             };
 
             let text_galley = ui.fonts(|f| text_job.into_galley(f));
-            let (rect, response) = ui.allocate_exact_size(text_galley.size(), sense);
+            let (rect, mut response) = ui.allocate_exact_size(text_galley.size(), sense);
+            if text_galley.galley.elided {
+                // Remember how much space the full text needs:
+                let mut job = (*text_galley.galley.job).clone();
+                job.wrap = Default::default();
+                response.set_intrinsic_size(ui.fonts(|f| f.layout_job(job)).size());
+            }
             let pos = match text_galley.galley.job.halign {
                 Align::LEFT => rect.left_top(),
                 Align::Center => rect.center_top(),
@@ -195,20 +357,114 @@ impl Label {
     }
 }
 
-impl Widget for Label {
-    fn ui(self, ui: &mut Ui) -> Response {
+impl Label {
+    /// Can we skip interaction and wrapping? See [`Self::hoverable`].
+    fn can_use_fast_path(&self, ui: &Ui) -> bool {
+        !self.hoverable
+            && self.sense.is_none()
+            && self.links.is_empty()
+            && !self.truncate
+            && self.wrap == Some(false)
+            && !ui.memory(|mem| mem.options.screen_reader)
+    }
+
+    /// Lay out and paint an unwrapped label without any interaction.
+    fn show_fast(self, ui: &mut Ui) -> Response {
+        let text_galley = match self.text {
+            WidgetText::Galley(galley) => WidgetTextGalley {
+                galley,
+                galley_has_color: true,
+            },
+            text => {
+                let valign = ui.layout().vertical_align();
+                let mut text_job = text.into_text_job(ui.style(), FontSelection::Default, valign);
+                text_job.job.wrap.max_width = f32::INFINITY;
+                if ui.is_grid() {
+                    text_job.job.halign = Align::LEFT;
+                    text_job.job.justify = false;
+                } else {
+                    text_job.job.halign = ui.layout().horizontal_placement();
+                    text_job.job.justify = ui.layout().horizontal_justify();
+                }
+                ui.fonts(|f| text_job.into_galley(f))
+            }
+        };
+
+        let (rect, response) = ui.allocate_exact_size_inert(text_galley.size());
+        if ui.is_rect_visible(rect) {
+            let pos = match text_galley.galley.job.halign {
+                Align::LEFT => rect.left_top(),
+                Align::Center => rect.center_top(),
+                Align::RIGHT => rect.right_top(),
+            };
+            let override_text_color =
+                (!text_galley.galley_has_color).then(|| ui.visuals().text_color());
+            ui.painter().add(epaint::TextShape {
+                pos,
+                galley: text_galley.galley,
+                override_text_color,
+                underline: Stroke::NONE,
+                angle: 0.0,
+            });
+        }
+        response
+    }
+
+    /// Show the label, returning a [`LabelOutput`] that tells you which link (if any) was clicked.
+    pub fn show(self, ui: &mut Ui) -> LabelOutput {
+        if self.can_use_fast_path(ui) {
+            return LabelOutput {
+                response: self.show_fast(ui),
+                clicked_link: None,
+                hovered_link: None,
+            };
+        }
+
+        let links = self.links.clone();
         let (pos, text_galley, mut response) = self.layout_in_ui(ui);
         response.widget_info(|| WidgetInfo::labeled(WidgetType::Label, text_galley.text()));
 
+        #[cfg(debug_assertions)]
+        ui.debug_paint_overflow(response.rect, response.intrinsic_size());
+
         if text_galley.galley.elided {
             // Show the full (non-elided) text on hover:
             response = response.on_hover_text(text_galley.text());
         }
 
+        let hovered_link = if response.hovered() {
+            response
+                .hover_pos()
+                .and_then(|hover_pos| link_at(&text_galley.galley, &links, hover_pos - pos))
+        } else {
+            None
+        };
+
+        let mut clicked_link = None;
+        if let Some(link_index) = hovered_link {
+            ui.ctx().set_cursor_icon(CursorIcon::PointingHand);
+
+            if response.clicked() || response.middle_clicked() {
+                clicked_link = Some(link_index);
+                if let Some(url) = &links[link_index].url {
+                    let modifiers = ui.ctx().input(|i| i.modifiers);
+                    ui.ctx().open_url(crate::OpenUrl {
+                        url: url.clone(),
+                        new_tab: response.middle_clicked() || modifiers.any(),
+                    });
+                }
+            }
+
+            if let Some(url) = &links[link_index].url {
+                response = response.on_hover_text(url);
+            }
+        }
+
         if ui.is_rect_visible(response.rect) {
             let response_color = ui.style().interact(&response).text_color();
 
-            let underline = if response.has_focus() || response.highlighted() {
+            let underline = if (response.has_focus() || response.highlighted()) && links.is_empty()
+            {
                 Stroke::new(1.0, response_color)
             } else {
                 Stroke::NONE
@@ -220,6 +476,20 @@ impl Widget for Label {
                 Some(response_color)
             };
 
+            if let Some(link_index) = hovered_link {
+                let stroke = Stroke::new(
+                    ui.visuals().widgets.hovered.fg_stroke.width,
+                    ui.visuals().hyperlink_color,
+                );
+                paint_link_underline(
+                    ui.painter(),
+                    pos,
+                    &text_galley.galley,
+                    &links[link_index].byte_range,
+                    stroke,
+                );
+            }
+
             ui.painter().add(epaint::TextShape {
                 pos,
                 galley: text_galley.galley,
@@ -229,6 +499,138 @@ impl Widget for Label {
             });
         }
 
-        response
+        LabelOutput {
+            response,
+            clicked_link,
+            hovered_link,
+        }
+    }
+}
+
+impl Widget for Label {
+    fn ui(self, ui: &mut Ui) -> Response {
+        self.show(ui).response
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// Split the sections of the job at the link boundaries, and color the links.
+///
+/// If `text_color` is set, it replaces [`Color32::TEMPORARY_COLOR`] in the rest of the text.
+fn color_links(
+    job: &mut text::LayoutJob,
+    links: &[LabelLink],
+    link_color: Color32,
+    text_color: Option<Color32>,
+) {
+    let is_in_link = |byte_index: usize| {
+        links
+            .iter()
+            .any(|link| link.byte_range.contains(&byte_index))
+    };
+
+    let mut sections = Vec::with_capacity(job.sections.len());
+    for section in std::mem::take(&mut job.sections) {
+        let mut cuts: Vec<usize> = links
+            .iter()
+            .flat_map(|link| [link.byte_range.start, link.byte_range.end])
+            .filter(|&cut| section.byte_range.start < cut && cut < section.byte_range.end)
+            .collect();
+        cuts.sort_unstable();
+        cuts.dedup();
+        cuts.push(section.byte_range.end);
+
+        let mut start = section.byte_range.start;
+        for end in cuts {
+            let mut format = section.format.clone();
+            if is_in_link(start) {
+                format.color = link_color;
+            } else if let Some(text_color) = text_color {
+                if format.color == Color32::TEMPORARY_COLOR {
+                    format.color = text_color;
+                }
+            }
+            sections.push(text::LayoutSection {
+                leading_space: if start == section.byte_range.start {
+                    section.leading_space
+                } else {
+                    0.0
+                },
+                byte_range: start..end,
+                format,
+            });
+            start = end;
+        }
+    }
+    job.sections = sections;
+}
+
+/// Calls `add_glyph` with the byte offset and row index of each glyph in the galley.
+fn for_each_glyph(galley: &Galley, mut add_glyph: impl FnMut(usize, usize, &epaint::text::Glyph)) {
+    let mut byte_offset = 0;
+    for (row_index, row) in galley.rows.iter().enumerate() {
+        for glyph in &row.glyphs {
+            add_glyph(byte_offset, row_index, glyph);
+            byte_offset += glyph.chr.len_utf8();
+        }
+        if row.ends_with_newline {
+            byte_offset += 1;
+        }
+    }
+}
+
+/// Which link is at the given position (relative to the galley)?
+fn link_at(galley: &Galley, links: &[LabelLink], pos: Vec2) -> Option<usize> {
+    let pos = pos.to_pos2();
+    let mut found = None;
+    for_each_glyph(galley, |byte_offset, row_index, glyph| {
+        let row_rect = galley.rows[row_index].rect;
+        let rect = Rect::from_x_y_ranges(glyph.pos.x..=glyph.max_x(), row_rect.y_range());
+        if found.is_none() && rect.contains(pos) {
+            found = links
+                .iter()
+                .position(|link| link.byte_range.contains(&byte_offset));
+        }
+    });
+    found
+}
+
+fn paint_link_underline(
+    painter: &Painter,
+    pos: Pos2,
+    galley: &Galley,
+    byte_range: &Range<usize>,
+    stroke: Stroke,
+) {
+    // One line segment per row:
+    let mut segment: Option<(usize, Rect)> = None;
+
+    let paint = |rect: Rect| {
+        let y = rect.bottom();
+        painter.line_segment(
+            [pos + vec2(rect.left(), y), pos + vec2(rect.right(), y)],
+            stroke,
+        );
+    };
+
+    for_each_glyph(galley, |byte_offset, row_index, glyph| {
+        if byte_range.contains(&byte_offset) {
+            let rect = glyph.logical_rect();
+            segment = match segment {
+                Some((segment_row, segment_rect)) if segment_row == row_index => {
+                    Some((row_index, segment_rect.union(rect)))
+                }
+                Some((_, segment_rect)) => {
+                    paint(segment_rect);
+                    Some((row_index, rect))
+                }
+                None => Some((row_index, rect)),
+            };
+        }
+    });
+
+    if let Some((_, rect)) = segment {
+        paint(rect);
     }
 }
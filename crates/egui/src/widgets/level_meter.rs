@@ -0,0 +1,302 @@
+use crate::*;
+
+/// A level meter, e.g. for audio levels or telemetry.
+///
+/// The level, the peak and the zone thresholds are all linear amplitudes in `0.0..=1.0`.
+/// Use [`Self::decibels`] to show them on a decibel scale, like most audio meters do.
+///
+/// See also: [`crate::ProgressBar`].
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let rms = 0.3;
+/// ui.add(
+///     egui::LevelMeter::new(rms)
+///         .decibels(-60.0)
+///         .peak_hold(1.5)
+///         .segments(20)
+///         .vertical(),
+/// );
+/// # });
+/// ```
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct LevelMeter {
+    id_source: Option<Id>,
+    level: f32,
+    peak: Option<f32>,
+    peak_hold: Option<f32>,
+    min_db: Option<f32>,
+    zones: Vec<(f32, Color32)>,
+    segments: usize,
+    vertical: bool,
+    desired_length: Option<f32>,
+    desired_thickness: Option<f32>,
+}
+
+impl LevelMeter {
+    /// A meter showing the given level, as a linear amplitude in `0.0..=1.0`.
+    pub fn new(level: f32) -> Self {
+        Self {
+            id_source: None,
+            level,
+            peak: None,
+            peak_hold: None,
+            min_db: None,
+            zones: vec![
+                (0.0, Color32::from_rgb(0, 200, 0)),
+                (0.5, Color32::from_rgb(230, 200, 0)),
+                (0.9, Color32::from_rgb(230, 0, 0)),
+            ],
+            segments: 0,
+            vertical: false,
+            desired_length: None,
+            desired_thickness: None,
+        }
+    }
+
+    /// Show a peak marker at this level.
+    #[inline]
+    pub fn peak(mut self, peak: f32) -> Self {
+        self.peak = Some(peak);
+        self
+    }
+
+    /// Show a peak marker at the highest level of the last `seconds`.
+    ///
+    /// The peak is remembered by the meter, so the level can be passed in anew each frame.
+    /// If you have several meters with a peak hold in the same [`Ui`], give each an [`Self::id_source`].
+    /// This is ignored if [`Self::peak`] is set.
+    #[inline]
+    pub fn peak_hold(mut self, seconds: f32) -> Self {
+        self.peak_hold = Some(seconds);
+        self
+    }
+
+    /// A source for the unique [`Id`] under which the [`Self::peak_hold`] is remembered,
+    /// e.g. `.id_source("left_channel")` or `.id_source(channel_index)`.
+    #[inline]
+    pub fn id_source(mut self, id_source: impl std::hash::Hash) -> Self {
+        self.id_source = Some(Id::new(id_source));
+        self
+    }
+
+    /// Show the level on a decibel scale from `min_db` (e.g. `-60.0`) to `0.0` dB,
+    /// where `0.0` dB is an amplitude of `1.0`.
+    ///
+    /// Default: a linear scale from `0.0` to `1.0`.
+    #[inline]
+    pub fn decibels(mut self, min_db: f32) -> Self {
+        self.min_db = Some(min_db.min(-f32::EPSILON));
+        self
+    }
+
+    /// The colors of the meter, as `(level, color)` pairs sorted by level.
+    /// Each color is used from its level up to the level of the next pair.
+    ///
+    /// Default: green, yellow from `0.5` (about -6 dB), red from `0.9` (about -1 dB).
+    #[inline]
+    pub fn zones(mut self, zones: impl Into<Vec<(f32, Color32)>>) -> Self {
+        self.zones = zones.into();
+        self
+    }
+
+    /// Split the meter into this many separate segments, like the LEDs of a hardware meter.
+    ///
+    /// Default: `0` (a continuous bar).
+    #[inline]
+    pub fn segments(mut self, segments: usize) -> Self {
+        self.segments = segments;
+        self
+    }
+
+    /// Make the meter vertical, growing upwards. Default: horizontal, growing to the right.
+    #[inline]
+    pub fn vertical(mut self) -> Self {
+        self.vertical = true;
+        self
+    }
+
+    /// The length of the meter. Default: the style's `slider_width`.
+    #[inline]
+    pub fn desired_length(mut self, desired_length: f32) -> Self {
+        self.desired_length = Some(desired_length);
+        self
+    }
+
+    /// The thickness of the meter. Default: half the style's `interact_size.y`.
+    #[inline]
+    pub fn desired_thickness(mut self, desired_thickness: f32) -> Self {
+        self.desired_thickness = Some(desired_thickness);
+        self
+    }
+
+    /// Where along the meter the level is, in `0.0..=1.0`.
+    fn fraction(&self, level: f32) -> f32 {
+        if let Some(min_db) = self.min_db {
+            if level <= 0.0 {
+                0.0
+            } else {
+                remap_clamp(20.0 * level.log10(), min_db..=0.0, 0.0..=1.0)
+            }
+        } else {
+            level.clamp(0.0, 1.0)
+        }
+    }
+
+    /// The color of the zone that the given fraction of the meter is in.
+    fn zone_color(&self, fraction: f32) -> Color32 {
+        self.zones
+            .iter()
+            .take_while(|(level, _)| self.fraction(*level) <= fraction)
+            .last()
+            .or(self.zones.first())
+            .map_or(Color32::GRAY, |(_, color)| *color)
+    }
+
+    /// The part of the meter between the two fractions.
+    fn sub_rect(&self, rect: Rect, from: f32, to: f32) -> Rect {
+        if self.vertical {
+            Rect::from_x_y_ranges(
+                rect.x_range(),
+                lerp(rect.bottom()..=rect.top(), to)..=lerp(rect.bottom()..=rect.top(), from),
+            )
+        } else {
+            Rect::from_x_y_ranges(
+                lerp(rect.left()..=rect.right(), from)..=lerp(rect.left()..=rect.right(), to),
+                rect.y_range(),
+            )
+        }
+    }
+}
+
+/// The remembered peak of a [`LevelMeter`] with [`LevelMeter::peak_hold`].
+#[derive(Clone, Copy)]
+struct PeakHold {
+    peak: f32,
+    time: f64,
+}
+
+impl Widget for LevelMeter {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let length = self
+            .desired_length
+            .unwrap_or_else(|| ui.spacing().slider_width);
+        let thickness = self
+            .desired_thickness
+            .unwrap_or_else(|| ui.spacing().interact_size.y / 2.0);
+        let desired_size = if self.vertical {
+            vec2(thickness, length)
+        } else {
+            vec2(length, thickness)
+        };
+        let (rect, response) = ui.allocate_exact_size(desired_size, Sense::hover());
+
+        let peak = self.peak.or_else(|| {
+            let hold = self.peak_hold?;
+            let now = ui.input(|i| i.time);
+            let id_source = self.id_source.unwrap_or_else(|| Id::new("level_meter"));
+            let id = ui.make_persistent_id(id_source).with("peak_hold");
+            let mut state = ui.data(|d| d.get_temp::<PeakHold>(id)).unwrap_or(PeakHold {
+                peak: self.level,
+                time: now,
+            });
+            if self.level >= state.peak || now - state.time > hold as f64 {
+                state = PeakHold {
+                    peak: self.level,
+                    time: now,
+                };
+            }
+            ui.data_mut(|d| d.insert_temp(id, state));
+            if state.peak != self.level {
+                // Let go of the peak in time, even if the level stays the same:
+                let remaining = hold as f64 - (now - state.time);
+                ui.ctx()
+                    .request_repaint_after(std::time::Duration::from_secs_f64(remaining.max(0.0)));
+            }
+            Some(state.peak)
+        });
+
+        if ui.is_rect_visible(rect) {
+            let painter = ui.painter();
+            painter.rect_filled(rect, 0.0, ui.visuals().extreme_bg_color);
+
+            let level = self.fraction(self.level);
+            if self.segments == 0 {
+                for (i, (zone_level, color)) in self.zones.iter().enumerate() {
+                    let from = if i == 0 {
+                        0.0
+                    } else {
+                        self.fraction(*zone_level)
+                    };
+                    let to = self
+                        .zones
+                        .get(i + 1)
+                        .map_or(1.0, |(next_level, _)| self.fraction(*next_level))
+                        .min(level);
+                    if from < to {
+                        painter.rect_filled(self.sub_rect(rect, from, to), 0.0, *color);
+                    }
+                }
+            } else {
+                let gap = 1.0 / length;
+                for i in 0..self.segments {
+                    let from = i as f32 / self.segments as f32;
+                    let to = (i + 1) as f32 / self.segments as f32 - gap;
+                    let color = self.zone_color(from);
+                    let color = if from < level {
+                        color
+                    } else {
+                        color.gamma_multiply(0.15)
+                    };
+                    painter.rect_filled(self.sub_rect(rect, from, to), 0.0, color);
+                }
+            }
+
+            if let Some(peak) = peak {
+                let peak = self.fraction(peak);
+                let marker_width = 2.0 / length;
+                let from = (peak - marker_width).at_least(0.0);
+                painter.rect_filled(
+                    self.sub_rect(rect, from, from + marker_width),
+                    0.0,
+                    self.zone_color(peak),
+                );
+            }
+        }
+
+        response
+    }
+}
+
+#[test]
+fn peak_hold_only_repaints_while_the_peak_is_held() {
+    let ctx = Context::default();
+    let mut time = 0.0;
+    // Was a repaint requested by the meter in the previous frame?
+    let mut run = |level: f32| {
+        time += 1.0;
+        let input = RawInput {
+            time: Some(time),
+            ..Default::default()
+        };
+        let mut repaint_requested = false;
+        let _ = ctx.run(input, |ctx| {
+            repaint_requested = ctx
+                .repaint_causes()
+                .iter()
+                .any(|cause| cause.file.ends_with("level_meter.rs"));
+            crate::CentralPanel::default().show(ctx, |ui| {
+                ui.add(LevelMeter::new(level).peak_hold(1.5).id_source("meter"));
+            });
+        });
+        repaint_requested
+    };
+
+    run(0.5);
+    assert!(!run(0.5));
+    assert!(!run(0.8)); // A new peak
+    assert!(!run(0.2)); // The peak of 0.8 is held…
+    assert!(run(0.2)); // …so the meter asked for a repaint, and now lets go of it.
+    assert!(!run(0.2));
+    assert!(!run(0.2));
+}
@@ -6,30 +6,38 @@
 
 use crate::*;
 
+mod badge;
 mod button;
 pub mod color_picker;
 pub(crate) mod drag_value;
+mod gauge;
 mod hyperlink;
 mod image;
 mod label;
+mod level_meter;
 mod progress_bar;
 mod selected_label;
 mod separator;
 mod slider;
 mod spinner;
 pub mod text_edit;
+mod validated_field;
 
+pub use badge::{Badge, Chip};
 pub use button::*;
 pub use drag_value::DragValue;
+pub use gauge::Gauge;
 pub use hyperlink::*;
 pub use image::{paint_texture_at, Image, ImageFit, ImageOptions, ImageSize, ImageSource};
 pub use label::*;
+pub use level_meter::LevelMeter;
 pub use progress_bar::ProgressBar;
 pub use selected_label::SelectableLabel;
 pub use separator::Separator;
 pub use slider::*;
 pub use spinner::*;
 pub use text_edit::{TextBuffer, TextEdit};
+pub use validated_field::{NumberField, ValidatedField};
 
 // ----------------------------------------------------------------------------
 
@@ -100,7 +108,10 @@ pub fn reset_button<T: Default + PartialEq>(ui: &mut Ui, value: &mut T) {
 /// The button is only enabled if the value does not already have its original value.
 pub fn reset_button_with<T: PartialEq>(ui: &mut Ui, value: &mut T, reset_value: T) {
     if ui
-        .add_enabled(*value != reset_value, Button::new("Reset"))
+        .add_enabled(
+            *value != reset_value,
+            Button::new(ui.ctx().translate("Reset")),
+        )
         .clicked()
     {
         *value = reset_value;
@@ -113,7 +124,7 @@ pub fn stroke_ui(ui: &mut crate::Ui, stroke: &mut epaint::Stroke, text: &str) {
     let epaint::Stroke { width, color } = stroke;
     ui.horizontal(|ui| {
         ui.add(DragValue::new(width).speed(0.1).clamp_range(0.0..=5.0))
-            .on_hover_text("Width");
+            .on_hover_text(ui.ctx().translate("Width"));
         ui.color_edit_button_srgba(color);
         ui.label(text);
 
@@ -134,7 +145,7 @@ pub(crate) fn shadow_ui(ui: &mut Ui, shadow: &mut epaint::Shadow, text: &str) {
                 .speed(1.0)
                 .clamp_range(0.0..=100.0),
         )
-        .on_hover_text("Extrusion");
+        .on_hover_text(ui.ctx().translate("Extrusion"));
         ui.color_edit_button_srgba(color);
     });
 }
@@ -57,6 +57,10 @@ pub enum SliderOrientation {
 /// The slider consists of three parts: a slider, a value display, and an optional text.
 /// The user can click the value display to edit its value. It can be turned off with `.show_value(false)`.
 ///
+/// When the slider has keyboard focus, the arrow keys move it by a small step (see [`Slider::step_by`]),
+/// page up and page down by a tenth of the range, and home and end go to the start and end of the range.
+/// Pressing enter moves the focus to the value display, so the value can be typed in.
+///
 /// ```
 /// # egui::__run_test_ui(|ui| {
 /// # let mut my_f32: f32 = 0.0;
@@ -603,6 +607,9 @@ impl<'a> Slider<'a> {
 
         let mut decrement = 0usize;
         let mut increment = 0usize;
+        let mut page_decrement = 0usize;
+        let mut page_increment = 0usize;
+        let mut jump_to = None;
 
         if response.has_focus() {
             ui.ctx().memory_mut(|m| {
@@ -615,16 +622,17 @@ impl<'a> Slider<'a> {
                 );
             });
 
-            let (dec_key, inc_key) = match self.orientation {
-                SliderOrientation::Horizontal => (Key::ArrowLeft, Key::ArrowRight),
-                // Note that this is for moving the slider position,
-                // so up = decrement y coordinate:
-                SliderOrientation::Vertical => (Key::ArrowUp, Key::ArrowDown),
-            };
-
             ui.input(|input| {
-                decrement += input.num_presses(dec_key);
-                increment += input.num_presses(inc_key);
+                decrement += input.num_presses(Key::ArrowLeft) + input.num_presses(Key::ArrowDown);
+                increment += input.num_presses(Key::ArrowRight) + input.num_presses(Key::ArrowUp);
+                page_decrement += input.num_presses(Key::PageDown);
+                page_increment += input.num_presses(Key::PageUp);
+                if input.key_pressed(Key::Home) {
+                    jump_to = Some(*self.range.start());
+                }
+                if input.key_pressed(Key::End) {
+                    jump_to = Some(*self.range.end());
+                }
             });
         }
 
@@ -638,26 +646,50 @@ impl<'a> Slider<'a> {
         }
 
         let kb_step = increment as f32 - decrement as f32;
+        let page_step = page_increment as f32 - page_decrement as f32;
 
-        if kb_step != 0.0 {
-            let ui_point_per_step = 1.0; // move this many ui points for each kb_step
+        if kb_step != 0.0 || page_step != 0.0 {
             let prev_value = self.get_value();
-            let prev_position = self.position_from_value(prev_value, position_range);
-            let new_position = prev_position + ui_point_per_step * kb_step;
             let new_value = match self.step {
-                Some(step) => prev_value + (kb_step as f64 * step),
-                None if self.smart_aim => {
-                    let aim_radius = 0.49 * ui_point_per_step; // Chosen so we don't include `prev_value` in the search.
-                    emath::smart_aim::best_in_range_f64(
-                        self.value_from_position(new_position - aim_radius, position_range),
-                        self.value_from_position(new_position + aim_radius, position_range),
-                    )
+                Some(step) => {
+                    // A page is a tenth of the range, rounded to whole steps:
+                    let steps_per_page = ((self.range.end() - self.range.start()) / (10.0 * step))
+                        .abs()
+                        .round();
+                    let steps_per_page = if steps_per_page.is_finite() {
+                        steps_per_page.at_least(1.0)
+                    } else {
+                        10.0
+                    };
+                    prev_value + (kb_step as f64 + page_step as f64 * steps_per_page) * step
+                }
+                None => {
+                    let ui_point_per_step = 1.0; // move this many ui points for each kb_step
+                    let ui_points_per_page = position_range.span().abs() / 10.0;
+                    // The position range of a vertical slider is flipped:
+                    let direction = position_range.span().signum();
+                    let prev_position = self.position_from_value(prev_value, position_range);
+                    let new_position = prev_position
+                        + direction
+                            * (ui_point_per_step * kb_step + ui_points_per_page * page_step);
+                    if self.smart_aim {
+                        let aim_radius = 0.49 * ui_point_per_step; // Chosen so we don't include `prev_value` in the search.
+                        emath::smart_aim::best_in_range_f64(
+                            self.value_from_position(new_position - aim_radius, position_range),
+                            self.value_from_position(new_position + aim_radius, position_range),
+                        )
+                    } else {
+                        self.value_from_position(new_position, position_range)
+                    }
                 }
-                _ => self.value_from_position(new_position, position_range),
             };
             self.set_value(new_value);
         }
 
+        if let Some(jump_to) = jump_to {
+            self.set_value(jump_to);
+        }
+
         #[cfg(feature = "accesskit")]
         {
             use accesskit::{Action, ActionData};
@@ -735,6 +767,7 @@ impl<'a> Slider<'a> {
                     ui.painter().add(epaint::RectShape {
                         fill: visuals.bg_fill,
                         stroke: visuals.fg_stroke,
+                        stroke_kind: Default::default(),
                         rect,
                         rounding: visuals.rounding,
                         fill_texture_id: Default::default(),
@@ -807,6 +840,8 @@ impl<'a> Slider<'a> {
             input.num_presses(Key::ArrowUp) as i32 + input.num_presses(Key::ArrowRight) as i32
                 - input.num_presses(Key::ArrowDown) as i32
                 - input.num_presses(Key::ArrowLeft) as i32
+                + input.num_presses(Key::PageUp) as i32
+                - input.num_presses(Key::PageDown) as i32
         });
 
         let any_change = change != 0;
@@ -861,6 +896,10 @@ impl<'a> Slider<'a> {
         let mut response = self.allocate_slider_space(ui, thickness);
         self.slider_ui(ui, &response);
 
+        // Pressing enter on the slider lets you type in the value:
+        let edit_value =
+            self.show_value && response.has_focus() && ui.input(|i| i.key_pressed(Key::Enter));
+
         let value = self.get_value();
         response.changed = value != old_value;
         response.widget_info(|| WidgetInfo::slider(value, self.text.text()));
@@ -890,6 +929,10 @@ impl<'a> Slider<'a> {
                 .handle_shape
                 .unwrap_or_else(|| ui.style().visuals.handle_shape);
             let position_range = self.position_range(&response.rect, &handle_shape);
+            if edit_value {
+                // The [`DragValue`] will get the next auto id:
+                ui.memory_mut(|mem| mem.request_focus(ui.next_auto_id()));
+            }
             let value_response = self.value_ui(ui, position_range);
             if value_response.gained_focus()
                 || value_response.has_focus()
@@ -668,6 +668,10 @@ impl<'t> TextEdit<'t> {
             false
         };
 
+        if response.changed || selection_changed || response.gained_focus() {
+            state.last_interaction_time = ui.input(|i| i.time);
+        }
+
         if ui.is_rect_visible(rect) {
             painter.galley(text_draw_pos, galley.clone());
 
@@ -688,14 +692,17 @@ impl<'t> TextEdit<'t> {
                     paint_cursor_selection(ui, &painter, text_draw_pos, &galley, &cursor_range);
 
                     if text.is_mutable() {
-                        let cursor_pos = paint_cursor_end(
-                            ui,
+                        let cursor_pos = text_cursor_rect(
                             row_height,
-                            &painter,
                             text_draw_pos,
                             &galley,
                             &cursor_range.primary,
                         );
+                        if !interactive
+                            || is_text_cursor_blinked_on(ui, state.last_interaction_time)
+                        {
+                            paint_text_cursor(ui, &painter, cursor_pos);
+                        }
 
                         let is_fully_visible = ui.clip_rect().contains_rect(rect); // TODO: remove this HACK workaround for https://github.com/emilk/egui/issues/1531
                         if (response.changed || selection_changed) && !is_fully_visible {
@@ -1175,11 +1182,44 @@ fn paint_cursor_end(
     galley: &Galley,
     cursor: &Cursor,
 ) -> Rect {
-    let stroke = ui.visuals().text_cursor;
+    let cursor_pos = text_cursor_rect(row_height, pos, galley, cursor);
+    paint_text_cursor(ui, painter, cursor_pos);
+    cursor_pos
+}
 
+/// Where to paint the text cursor.
+fn text_cursor_rect(row_height: f32, pos: Pos2, galley: &Galley, cursor: &Cursor) -> Rect {
     let mut cursor_pos = galley.pos_from_cursor(cursor).translate(pos.to_vec2());
     cursor_pos.max.y = cursor_pos.max.y.at_least(cursor_pos.min.y + row_height); // Handle completely empty galleys
-    cursor_pos = cursor_pos.expand(1.5); // slightly above/below row
+    cursor_pos.expand(1.5) // slightly above/below row
+}
+
+/// Is the blinking text cursor in its visible phase?
+///
+/// Schedules a repaint for when it should next toggle,
+/// so the cursor blinks without continuous repainting.
+fn is_text_cursor_blinked_on(ui: &Ui, last_interaction_time: f64) -> bool {
+    let visuals = ui.visuals();
+    let on_duration = visuals.text_cursor_on_duration as f64;
+    let off_duration = visuals.text_cursor_off_duration as f64;
+    if !visuals.text_cursor_blink || on_duration <= 0.0 || off_duration <= 0.0 {
+        return true;
+    }
+
+    let period = on_duration + off_duration;
+    let time_in_period = (ui.input(|i| i.time) - last_interaction_time).max(0.0) % period;
+    let (is_on, time_until_toggle) = if time_in_period < on_duration {
+        (true, on_duration - time_in_period)
+    } else {
+        (false, period - time_in_period)
+    };
+    ui.ctx()
+        .request_repaint_after(std::time::Duration::from_secs_f64(time_until_toggle));
+    is_on
+}
+
+fn paint_text_cursor(ui: &Ui, painter: &Painter, cursor_pos: Rect) {
+    let stroke = ui.visuals().text_cursor;
 
     let top = cursor_pos.center_top();
     let bottom = cursor_pos.center_bottom();
@@ -1199,8 +1239,6 @@ fn paint_cursor_end(
             (width, stroke.color),
         );
     }
-
-    cursor_pos
 }
 
 // ----------------------------------------------------------------------------
@@ -1401,10 +1439,12 @@ fn on_key_press(
         }
 
         Key::P | Key::N | Key::B | Key::F | Key::A | Key::E
-            if cfg!(target_os = "macos") && modifiers.ctrl && !modifiers.shift =>
+            if cfg!(target_os = "macos") && modifiers.ctrl =>
         {
             move_single_cursor(&mut cursor_range.primary, galley, key, modifiers);
-            cursor_range.secondary = cursor_range.primary;
+            if !modifiers.shift {
+                cursor_range.secondary = cursor_range.primary;
+            }
             None
         }
 
@@ -1413,17 +1453,21 @@ fn on_key_press(
 }
 
 fn move_single_cursor(cursor: &mut Cursor, galley: &Galley, key: Key, modifiers: &Modifiers) {
-    if cfg!(target_os = "macos") && modifiers.ctrl && !modifiers.shift {
-        match key {
-            Key::A => *cursor = galley.cursor_begin_of_row(cursor),
-            Key::E => *cursor = galley.cursor_end_of_row(cursor),
-            Key::P => *cursor = galley.cursor_up_one_row(cursor),
-            Key::N => *cursor = galley.cursor_down_one_row(cursor),
-            Key::B => *cursor = galley.cursor_left_one_character(cursor),
-            Key::F => *cursor = galley.cursor_right_one_character(cursor),
-            _ => (),
+    if cfg!(target_os = "macos") && modifiers.ctrl {
+        // Emacs-style bindings. Arrow keys fall through to the normal handling below.
+        let emacs_cursor = match key {
+            Key::A => Some(galley.cursor_begin_of_row(cursor)),
+            Key::E => Some(galley.cursor_end_of_row(cursor)),
+            Key::P => Some(galley.cursor_up_one_row(cursor)),
+            Key::N => Some(galley.cursor_down_one_row(cursor)),
+            Key::B => Some(galley.cursor_left_one_character(cursor)),
+            Key::F => Some(galley.cursor_right_one_character(cursor)),
+            _ => None,
+        };
+        if let Some(emacs_cursor) = emacs_cursor {
+            *cursor = emacs_cursor;
+            return;
         }
-        return;
     }
     match key {
         Key::ArrowLeft => {
@@ -1447,24 +1491,30 @@ fn move_single_cursor(cursor: &mut Cursor, galley: &Galley, key: Key, modifiers:
             }
         }
         Key::ArrowUp => {
-            if modifiers.command {
-                // mac and windows behavior
+            if modifiers.mac_cmd {
+                // mac behavior
                 *cursor = Cursor::default();
+            } else if modifiers.alt || modifiers.ctrl {
+                // alt on mac, ctrl on windows
+                *cursor = galley.cursor_begin_of_paragraph(cursor);
             } else {
                 *cursor = galley.cursor_up_one_row(cursor);
             }
         }
         Key::ArrowDown => {
-            if modifiers.command {
-                // mac and windows behavior
+            if modifiers.mac_cmd {
+                // mac behavior
                 *cursor = galley.end();
+            } else if modifiers.alt || modifiers.ctrl {
+                // alt on mac, ctrl on windows
+                *cursor = galley.cursor_end_of_paragraph(cursor);
             } else {
                 *cursor = galley.cursor_down_one_row(cursor);
             }
         }
 
         Key::Home => {
-            if modifiers.ctrl {
+            if modifiers.ctrl || modifiers.mac_cmd {
                 // windows behavior
                 *cursor = Cursor::default();
             } else {
@@ -1472,7 +1522,7 @@ fn move_single_cursor(cursor: &mut Cursor, galley: &Galley, key: Key, modifiers:
             }
         }
         Key::End => {
-            if modifiers.ctrl {
+            if modifiers.ctrl || modifiers.mac_cmd {
                 // windows behavior
                 *cursor = galley.end();
             } else {
@@ -1625,7 +1675,7 @@ fn next_line_boundary_char_index(it: impl Iterator<Item = char>, mut index: usiz
 }
 
 fn is_word_char(c: char) -> bool {
-    c.is_ascii_alphanumeric() || c == '_'
+    c.is_alphanumeric() || c == '_'
 }
 
 fn is_linebreak(c: char) -> bool {
@@ -1,6 +1,24 @@
 use std::sync::Arc;
 
+use epaint::text::cursor::CCursor;
+
+use super::CCursorRange;
+
 /// The output from a [`TextEdit`](crate::TextEdit).
+///
+/// You can use this to change the selection or move the caret programmatically,
+/// e.g. to select all text when the [`TextEdit`](crate::TextEdit) gains focus:
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let mut text = String::from("Hello");
+/// let mut output = egui::TextEdit::singleline(&mut text).show(ui);
+/// if output.response.gained_focus() {
+///     output.select_all();
+///     output.store(ui.ctx());
+/// }
+/// # });
+/// ```
 pub struct TextEditOutput {
     /// The interaction response.
     pub response: crate::Response,
@@ -21,4 +39,49 @@ pub struct TextEditOutput {
     pub cursor_range: Option<super::CursorRange>,
 }
 
-// TODO(emilk): add `output.paint` and `output.store` and split out that code from `TextEdit::show`.
+impl TextEditOutput {
+    /// Select all the text.
+    ///
+    /// Call [`Self::store`] afterwards for the change to take effect.
+    pub fn select_all(&mut self) {
+        let end = self.galley.end().ccursor;
+        self.set_ccursor_range(Some(CCursorRange::two(CCursor::new(0), end)));
+    }
+
+    /// Select the given range of characters (NOT bytes!).
+    ///
+    /// The caret ends up at `char_range.end`.
+    /// This is useful for e.g. highlighting a search match.
+    ///
+    /// Call [`Self::store`] afterwards for the change to take effect.
+    pub fn select_char_range(&mut self, char_range: std::ops::Range<usize>) {
+        self.set_ccursor_range(Some(CCursorRange::two(
+            CCursor::new(char_range.start),
+            CCursor::new(char_range.end),
+        )));
+    }
+
+    /// Place the caret before the character with the given index (NOT byte index!), clearing any selection.
+    ///
+    /// Call [`Self::store`] afterwards for the change to take effect.
+    pub fn set_caret(&mut self, char_index: usize) {
+        self.set_ccursor_range(Some(CCursorRange::one(CCursor::new(char_index))));
+    }
+
+    /// Set the selection, or clear it with `None`.
+    ///
+    /// This also updates [`Self::cursor_range`].
+    ///
+    /// Call [`Self::store`] afterwards for the change to take effect.
+    pub fn set_ccursor_range(&mut self, ccursor_range: Option<CCursorRange>) {
+        self.state.set_ccursor_range(ccursor_range);
+        self.cursor_range = self.state.cursor_range(&self.galley);
+    }
+
+    /// Store the (possibly modified) [`Self::state`], so that it is used the next frame.
+    pub fn store(self, ctx: &crate::Context) {
+        self.state.store(ctx, self.response.id);
+    }
+}
+
+// TODO(emilk): add `output.paint` and split out that code from `TextEdit::show`.
@@ -51,6 +51,10 @@ pub struct TextEditState {
     // Visual offset when editing singleline text bigger than the width.
     #[cfg_attr(feature = "serde", serde(skip))]
     pub(crate) singleline_offset: f32,
+
+    /// When the text or cursor last changed. The cursor stays visible for a while after this.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) last_interaction_time: f64,
 }
 
 impl TextEditState {
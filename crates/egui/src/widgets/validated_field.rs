@@ -0,0 +1,269 @@
+use std::ops::RangeInclusive;
+
+use crate::*;
+
+type Parser<'a, T> = Box<dyn 'a + Fn(&str) -> Result<T, String>>;
+type Formatter<'a, T> = Box<dyn 'a + Fn(&T) -> String>;
+
+/// A single line [`TextEdit`] for a value that has to be parsed from the text, e.g. a number or an email address.
+///
+/// While the field has keyboard focus, every edit of the text is parsed.
+/// If it parses, the value is updated right away.
+/// If it doesn't, the frame of the field is painted red and the error is shown in a tooltip.
+///
+/// When the field loses focus, the text is replaced by the formatted value,
+/// so any text that didn't parse is reverted.
+///
+/// The returned [`Response::changed`] is only `true` when the value was changed.
+///
+/// See also: [`NumberField`].
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let mut email = String::from("a@b.com");
+/// ui.add(egui::ValidatedField::new(&mut email, |text| {
+///     if text.contains('@') {
+///         Ok(text.to_owned())
+///     } else {
+///         Err("Expected an email address".to_owned())
+///     }
+/// }));
+/// # });
+/// ```
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct ValidatedField<'a, T> {
+    value: &'a mut T,
+    parser: Parser<'a, T>,
+    formatter: Formatter<'a, T>,
+    id: Option<Id>,
+    desired_width: Option<f32>,
+    hint_text: WidgetText,
+}
+
+impl<'a, T: ToString> ValidatedField<'a, T> {
+    /// The text is parsed with `parser`, which returns either the new value or an error to show.
+    ///
+    /// The value is shown using its [`ToString`] implementation, see [`Self::formatter`].
+    pub fn new(value: &'a mut T, parser: impl 'a + Fn(&str) -> Result<T, String>) -> Self {
+        Self::with_formatter(value, parser, |value: &T| value.to_string())
+    }
+}
+
+impl<'a, T> ValidatedField<'a, T> {
+    /// Like [`Self::new`], but for values that can't be turned into text with [`ToString`].
+    pub fn with_formatter(
+        value: &'a mut T,
+        parser: impl 'a + Fn(&str) -> Result<T, String>,
+        formatter: impl 'a + Fn(&T) -> String,
+    ) -> Self {
+        Self {
+            value,
+            parser: Box::new(parser),
+            formatter: Box::new(formatter),
+            id: None,
+            desired_width: None,
+            hint_text: Default::default(),
+        }
+    }
+
+    /// How the value is shown when the field is not being edited.
+    #[inline]
+    pub fn formatter(mut self, formatter: impl 'a + Fn(&T) -> String) -> Self {
+        self.formatter = Box::new(formatter);
+        self
+    }
+
+    /// Use this [`Id`] for the underlying [`TextEdit`].
+    #[inline]
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// See [`TextEdit::desired_width`].
+    #[inline]
+    pub fn desired_width(mut self, desired_width: f32) -> Self {
+        self.desired_width = Some(desired_width);
+        self
+    }
+
+    /// See [`TextEdit::hint_text`].
+    #[inline]
+    pub fn hint_text(mut self, hint_text: impl Into<WidgetText>) -> Self {
+        self.hint_text = hint_text.into();
+        self
+    }
+}
+
+impl<'a, T> Widget for ValidatedField<'a, T> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Self {
+            value,
+            parser,
+            formatter,
+            id,
+            desired_width,
+            hint_text,
+        } = self;
+
+        let id = id.unwrap_or_else(|| ui.next_auto_id());
+        // The text being edited is only remembered while the field has focus:
+        let text_id = id.with("__validated_text");
+
+        let mut text = if ui.memory(|mem| mem.has_focus(id)) {
+            ui.data(|d| d.get_temp::<String>(text_id))
+        } else {
+            None
+        }
+        .unwrap_or_else(|| formatter(value));
+
+        let mut text_edit = TextEdit::singleline(&mut text).id(id).hint_text(hint_text);
+        if let Some(desired_width) = desired_width {
+            text_edit = text_edit.desired_width(desired_width);
+        }
+        let mut response = ui.add(text_edit);
+
+        let text_changed = response.changed;
+        response.changed = false;
+
+        let error = match parser(&text) {
+            Ok(new_value) => {
+                if text_changed {
+                    *value = new_value;
+                    response.mark_changed();
+                }
+                None
+            }
+            Err(error) => Some(error),
+        };
+
+        if response.has_focus() {
+            ui.data_mut(|d| d.insert_temp(text_id, text));
+
+            if let Some(error) = error {
+                ui.painter().rect_stroke(
+                    response.rect.expand(1.0),
+                    ui.visuals().widgets.inactive.rounding,
+                    Stroke::new(1.0, ui.visuals().error_fg_color),
+                );
+                crate::containers::show_tooltip_for(
+                    ui.ctx(),
+                    id.with("__validation_error"),
+                    &response.rect,
+                    |ui| {
+                        ui.colored_label(ui.visuals().error_fg_color, error);
+                    },
+                );
+            }
+        } else {
+            // Revert any text that didn't parse:
+            ui.data_mut(|d| d.remove::<String>(text_id));
+        }
+
+        response
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// A [`ValidatedField`] for a number, where the number can be typed in as text.
+///
+/// Text that isn't a number is shown as an error and reverted when the field loses focus.
+/// Numbers outside of [`Self::clamp_range`] are clamped.
+///
+/// See also: [`DragValue`], [`Ui::number_field`].
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let mut percent: u8 = 50;
+/// ui.add(egui::NumberField::new(&mut percent).clamp_range(0..=100));
+/// # });
+/// ```
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct NumberField<'a, Num: emath::Numeric> {
+    value: &'a mut Num,
+    clamp_range: RangeInclusive<f64>,
+    id: Option<Id>,
+    desired_width: Option<f32>,
+}
+
+impl<'a, Num: emath::Numeric> NumberField<'a, Num> {
+    pub fn new(value: &'a mut Num) -> Self {
+        Self {
+            value,
+            clamp_range: Num::MIN.to_f64()..=Num::MAX.to_f64(),
+            id: None,
+            desired_width: None,
+        }
+    }
+
+    /// Clamp the typed in numbers to this range.
+    #[inline]
+    pub fn clamp_range<N: emath::Numeric>(mut self, clamp_range: RangeInclusive<N>) -> Self {
+        self.clamp_range = clamp_range.start().to_f64()..=clamp_range.end().to_f64();
+        self
+    }
+
+    /// Use this [`Id`] for the underlying [`TextEdit`].
+    #[inline]
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// See [`TextEdit::desired_width`].
+    ///
+    /// Default: the style's `interact_size.x`, wide enough for most numbers.
+    #[inline]
+    pub fn desired_width(mut self, desired_width: f32) -> Self {
+        self.desired_width = Some(desired_width);
+        self
+    }
+}
+
+impl<'a, Num: emath::Numeric> Widget for NumberField<'a, Num> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Self {
+            value,
+            clamp_range,
+            id,
+            desired_width,
+        } = self;
+
+        let number_format = ui.style().number_format;
+        let not_a_number = ui.ctx().translate("Expected a number");
+        let not_a_whole_number = ui.ctx().translate("Expected a whole number");
+        let parser = move |text: &str| {
+            let number = number_format
+                .parse(text)
+                .ok_or_else(|| not_a_number.clone())?;
+            if number.is_nan() {
+                Err(not_a_number.clone())
+            } else if Num::INTEGRAL && number.fract() != 0.0 {
+                Err(not_a_whole_number.clone())
+            } else {
+                let (min, max) = (*clamp_range.start(), *clamp_range.end());
+                Ok(Num::from_f64(number.clamp(min.min(max), max.max(min))))
+            }
+        };
+        let formatter = move |value: &Num| {
+            let value = value.to_f64();
+            let text = if Num::INTEGRAL {
+                format!("{value:.0}")
+            } else if value as f32 as f64 == value {
+                // Avoid showing an `f32` as e.g. `0.10000000149011612`:
+                (value as f32).to_string()
+            } else {
+                value.to_string()
+            };
+            number_format.format(&text)
+        };
+
+        let mut field = ValidatedField::with_formatter(value, parser, formatter)
+            .desired_width(desired_width.unwrap_or_else(|| ui.spacing().interact_size.x));
+        if let Some(id) = id {
+            field = field.id(id);
+        }
+        field.ui(ui)
+    }
+}
@@ -63,6 +63,15 @@ pub fn criterion_benchmark(c: &mut Criterion) {
                         ui.label("the quick brown fox jumps over the lazy dog".to_owned());
                     });
                 });
+                c.bench_function("label fast path", |b| {
+                    b.iter(|| {
+                        ui.add(
+                            egui::Label::new("the quick brown fox jumps over the lazy dog")
+                                .hoverable(false)
+                                .wrap(false),
+                        );
+                    });
+                });
             });
         });
     }
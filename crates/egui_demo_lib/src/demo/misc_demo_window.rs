@@ -205,6 +205,12 @@ fn label_ui(ui: &mut egui::Ui) {
         )
         .truncate(true),
     );
+    ui.add(
+        egui::Label::new(
+            "Or elide the middle of the text, which is useful for paths: /home/user/documents/projects/egui/crates/egui_demo_lib/src/demo/misc_demo_window.rs",
+        )
+        .elision_position(egui::text::ElisionPosition::Middle),
+    );
 }
 
 // ----------------------------------------------------------------------------
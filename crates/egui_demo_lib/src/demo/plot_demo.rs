@@ -5,8 +5,8 @@ use egui::*;
 
 use egui_plot::{
     Arrows, AxisHints, Bar, BarChart, BoxElem, BoxPlot, BoxSpread, CoordinatesFormatter, Corner,
-    GridInput, GridMark, HLine, Legend, Line, LineStyle, MarkerShape, Plot, PlotImage, PlotPoint,
-    PlotPoints, PlotResponse, Points, Polygon, Text, VLine,
+    GridInput, GridMark, HLine, Heatmap, Legend, Line, LineStyle, MarkerShape, Plot, PlotImage,
+    PlotPoint, PlotPoints, PlotResponse, Points, Polygon, Text, VLine,
 };
 
 // ----------------------------------------------------------------------------
@@ -436,6 +436,7 @@ impl LegendDemo {
             );
             ui.end_row();
         });
+        ui.label("Click a legend entry to hide it, double-click it to show only that entry.");
         let legend_plot = Plot::new("legend_demo")
             .y_axis_width(2)
             .legend(config.clone())
@@ -737,6 +738,17 @@ impl ItemsDemo {
             5.0 * vec2(texture.aspect_ratio(), 1.0),
         );
 
+        let heatmap = {
+            let size = 16;
+            let values: Vec<f64> = (0..size * size)
+                .map(|i| {
+                    let (row, col) = ((i / size) as f64, (i % size) as f64);
+                    (0.4 * row).sin() * (0.4 * col).cos()
+                })
+                .collect();
+            Heatmap::new(values, size).position([4.0, -8.0], [8.0, -4.0])
+        };
+
         let plot = Plot::new("items_demo")
             .legend(Legend::default().position(Corner::RightBottom))
             .show_x(false)
@@ -755,6 +767,7 @@ impl ItemsDemo {
             plot_ui.text(Text::new(PlotPoint::new(3.0, 3.0), "much color").name("Text"));
             plot_ui.text(Text::new(PlotPoint::new(2.5, -2.0), "such plot").name("Text"));
             plot_ui.image(image.name("Image"));
+            plot_ui.heatmap(heatmap.name("Heatmap"));
             plot_ui.arrows(arrows.name("Arrows"));
         })
         .response
@@ -10,6 +10,7 @@ pub struct WindowOptions {
     resizable: bool,
     constrain: bool,
     scroll2: Vec2b,
+    auto_shrink: Vec2b,
     disabled_time: f64,
 
     anchored: bool,
@@ -27,6 +28,7 @@ impl Default for WindowOptions {
             resizable: true,
             constrain: true,
             scroll2: Vec2b::TRUE,
+            auto_shrink: Vec2b::FALSE,
             disabled_time: f64::NEG_INFINITY,
             anchored: false,
             anchor: egui::Align2::RIGHT_TOP,
@@ -49,6 +51,7 @@ impl super::Demo for WindowOptions {
             resizable,
             constrain,
             scroll2,
+            auto_shrink,
             disabled_time,
             anchored,
             anchor,
@@ -68,6 +71,7 @@ impl super::Demo for WindowOptions {
             .collapsible(collapsible)
             .title_bar(title_bar)
             .scroll2(scroll2)
+            .auto_shrink(auto_shrink)
             .enabled(enabled);
         if closable {
             window = window.open(open);
@@ -89,6 +93,7 @@ impl super::View for WindowOptions {
             resizable,
             constrain,
             scroll2,
+            auto_shrink,
             disabled_time: _,
             anchored,
             anchor,
@@ -110,6 +115,10 @@ impl super::View for WindowOptions {
                         .on_hover_text("Constrain window to the screen");
                     ui.checkbox(&mut scroll2[0], "hscroll");
                     ui.checkbox(&mut scroll2[1], "vscroll");
+                    ui.checkbox(&mut auto_shrink[0], "shrink width")
+                        .on_hover_text("Shrink back when the contents get narrower");
+                    ui.checkbox(&mut auto_shrink[1], "shrink height")
+                        .on_hover_text("Shrink back when the contents get shorter");
                 });
             });
             ui.group(|ui| {
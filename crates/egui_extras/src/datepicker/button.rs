@@ -1,6 +1,6 @@
 use super::popup::DatePickerPopup;
 use chrono::NaiveDate;
-use egui::{Area, Button, Frame, InnerResponse, Key, Order, RichText, Ui, Widget};
+use egui::{Area, Button, Frame, InnerResponse, Key, RichText, Ui, Widget};
 
 #[derive(Default, Clone, serde::Deserialize, serde::Serialize)]
 pub(crate) struct DatePickerButtonState {
@@ -121,9 +121,8 @@ impl<'a> Widget for DatePickerButton<'a> {
                 inner: saved,
                 response: area_response,
             } = Area::new(ui.make_persistent_id(self.id_source))
-                .order(Order::Foreground)
+                .popup_of(ui)
                 .fixed_pos(pos)
-                .constrain_to(ui.ctx().screen_rect())
                 .show(ui.ctx(), |ui| {
                     let frame = Frame::popup(ui.style());
                     frame
@@ -14,6 +14,12 @@
 #[cfg(feature = "chrono")]
 mod datepicker;
 
+#[cfg(feature = "markdown")]
+pub mod markdown;
+
+#[cfg(feature = "svg_export")]
+pub mod svg_export;
+
 pub mod syntax_highlighting;
 
 #[doc(hidden)]
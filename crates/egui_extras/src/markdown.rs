@@ -0,0 +1,377 @@
+//! Show a small subset of [Markdown](https://commonmark.org/), e.g. for help panels and changelogs.
+//!
+//! Supported:
+//! * `# Headings` (all six levels)
+//! * `**strong**`, `*emphasis*` and `` `inline code` ``
+//! * Code fences, with syntax highlighting (see [`crate::syntax_highlighting`])
+//! * Bullet lists (`-`, `*`, `+`) and numbered lists (`1.`), with indentation
+//! * `[Links](https://github.com/emilk/egui)`
+//! * `---` separators
+//!
+//! Everything else is shown as plain text.
+
+use std::ops::Range;
+
+use egui::text::{LayoutJob, TextFormat};
+
+use crate::syntax_highlighting::{code_view_ui, CodeTheme};
+
+/// Show some Markdown.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// egui_extras::markdown::markdown_ui(
+///     ui,
+///     "# Changelog\n\
+///      * Added **markdown** support, see [the docs](https://docs.rs/egui_extras).\n\
+///      * Fixed `a bug`.",
+/// );
+/// # });
+/// ```
+pub fn markdown_ui(ui: &mut egui::Ui, markdown: &str) {
+    ui.vertical(|ui| {
+        for (i, block) in parse_blocks(markdown).iter().enumerate() {
+            if i > 0 && matches!(block, Block::Heading { .. }) {
+                ui.add_space(ui.spacing().item_spacing.y);
+            }
+            block_ui(ui, block);
+        }
+    });
+}
+
+// ----------------------------------------------------------------------------
+
+#[derive(Clone, Debug, PartialEq)]
+enum Block<'a> {
+    /// `# Heading` has level 1.
+    Heading {
+        level: usize,
+        text: &'a str,
+    },
+
+    /// One or more lines of text, joined with spaces.
+    Paragraph(String),
+
+    ListItem {
+        /// Number of leading spaces.
+        indent: usize,
+
+        /// `None` for bullet points.
+        number: Option<&'a str>,
+
+        text: &'a str,
+    },
+
+    CodeBlock {
+        language: &'a str,
+        code: String,
+    },
+
+    Separator,
+}
+
+fn parse_blocks(markdown: &str) -> Vec<Block<'_>> {
+    let mut blocks = vec![];
+    let mut lines = markdown.lines().peekable();
+
+    while let Some(line) = lines.next() {
+        let trimmed = line.trim();
+
+        if let Some(language) = trimmed.strip_prefix("```") {
+            let mut code = String::new();
+            for line in lines.by_ref() {
+                if line.trim_start().starts_with("```") {
+                    break;
+                }
+                code += line;
+                code += "\n";
+            }
+            blocks.push(Block::CodeBlock {
+                language: language.trim(),
+                code,
+            });
+        } else if trimmed.is_empty() {
+            // Paragraph break
+        } else if let Some(block) = parse_single_line_block(line) {
+            blocks.push(block);
+        } else {
+            let mut paragraph = trimmed.to_owned();
+            while let Some(next) = lines.peek() {
+                let next = next.trim();
+                if next.is_empty()
+                    || next.starts_with("```")
+                    || parse_single_line_block(next).is_some()
+                {
+                    break;
+                }
+                paragraph += " ";
+                paragraph += next;
+                lines.next();
+            }
+            blocks.push(Block::Paragraph(paragraph));
+        }
+    }
+
+    blocks
+}
+
+/// Headings, separators and list items.
+fn parse_single_line_block(line: &str) -> Option<Block<'_>> {
+    let trimmed = line.trim_start();
+    let indent = line.len() - trimmed.len();
+
+    let level = trimmed.chars().take_while(|&c| c == '#').count();
+    if (1..=6).contains(&level) {
+        if let Some(text) = trimmed[level..].strip_prefix(' ') {
+            return Some(Block::Heading {
+                level,
+                text: text.trim(),
+            });
+        }
+    }
+
+    let no_spaces = trimmed.replace(' ', "");
+    let first = no_spaces.chars().next();
+    if no_spaces.len() >= 3
+        && matches!(first, Some('-' | '*' | '_'))
+        && no_spaces.chars().all(|c| Some(c) == first)
+    {
+        return Some(Block::Separator);
+    }
+
+    for bullet in ["- ", "* ", "+ "] {
+        if let Some(text) = trimmed.strip_prefix(bullet) {
+            return Some(Block::ListItem {
+                indent,
+                number: None,
+                text: text.trim(),
+            });
+        }
+    }
+
+    let digits = trimmed.chars().take_while(char::is_ascii_digit).count();
+    if digits > 0 {
+        if let Some(text) = trimmed[digits..].strip_prefix(". ") {
+            return Some(Block::ListItem {
+                indent,
+                number: Some(&trimmed[..digits]),
+                text: text.trim(),
+            });
+        }
+    }
+
+    None
+}
+
+fn block_ui(ui: &mut egui::Ui, block: &Block<'_>) {
+    match block {
+        Block::Heading { level, text } => {
+            let style = ui.style();
+            let heading_size = egui::TextStyle::Heading.resolve(style).size;
+            let body_size = egui::TextStyle::Body.resolve(style).size;
+            let t = (*level as f32 - 1.0) / 3.0;
+            let size = egui::lerp(heading_size..=body_size, t.min(1.0));
+            let format = TextFormat {
+                font_id: egui::FontId::proportional(size),
+                color: ui.visuals().strong_text_color(),
+                ..Default::default()
+            };
+            inline_label_ui(ui, text, format);
+        }
+        Block::Paragraph(text) => {
+            let format = TextFormat {
+                font_id: egui::TextStyle::Body.resolve(ui.style()),
+                color: ui.visuals().text_color(),
+                ..Default::default()
+            };
+            inline_label_ui(ui, text, format);
+        }
+        Block::ListItem {
+            indent,
+            number,
+            text,
+        } => {
+            let format = TextFormat {
+                font_id: egui::TextStyle::Body.resolve(ui.style()),
+                color: ui.visuals().text_color(),
+                ..Default::default()
+            };
+            let row_height = ui.text_style_height(&egui::TextStyle::Body);
+            ui.horizontal_top(|ui| {
+                ui.add_space(*indent as f32 * row_height / 4.0);
+                if let Some(number) = number {
+                    ui.label(format!("{number}."));
+                } else {
+                    ui.label("•");
+                }
+                inline_label_ui(ui, text, format);
+            });
+        }
+        Block::CodeBlock { language, code } => {
+            let theme = CodeTheme::from_memory(ui.ctx());
+            code_view_ui(ui, &theme, code.trim_end_matches('\n'), language);
+        }
+        Block::Separator => {
+            ui.separator();
+        }
+    }
+}
+
+/// Show text with inline formatting and links in a wrapping [`egui::Label`].
+fn inline_label_ui(ui: &mut egui::Ui, text: &str, format: TextFormat) {
+    let code_format = TextFormat {
+        font_id: egui::TextStyle::Monospace.resolve(ui.style()),
+        background: ui.visuals().code_bg_color,
+        ..format.clone()
+    };
+    let strong_color = ui.visuals().strong_text_color();
+
+    let (job, links) = layout_inline(text, &format, &code_format, strong_color);
+
+    let mut label = egui::Label::new(job).wrap(true);
+    for (byte_range, url) in links {
+        label = label.link_to(byte_range, url);
+    }
+    ui.add(label);
+}
+
+/// Lay out `**strong**`, `*emphasis*`, `` `code` `` and `[links](url)`.
+///
+/// Returns the job and the byte ranges of the links in it.
+fn layout_inline(
+    text: &str,
+    format: &TextFormat,
+    code_format: &TextFormat,
+    strong_color: egui::Color32,
+) -> (LayoutJob, Vec<(Range<usize>, String)>) {
+    let mut job = LayoutJob::default();
+    let mut links = vec![];
+
+    let mut strong = false;
+    let mut emphasis = false;
+    let mut plain = String::new();
+
+    let current_format = |strong: bool, emphasis: bool| {
+        let mut format = format.clone();
+        if strong {
+            format.color = strong_color;
+        }
+        format.italics = emphasis;
+        format
+    };
+
+    let flush = |job: &mut LayoutJob, plain: &mut String, format: TextFormat| {
+        job.append(plain, 0.0, format);
+        plain.clear();
+    };
+
+    let mut rest = text;
+    while let Some(c) = rest.chars().next() {
+        if c == '\\' && rest.len() > 1 {
+            // Escaped character:
+            let escaped = rest[1..].chars().next().unwrap_or_default();
+            plain.push(escaped);
+            rest = &rest[1 + escaped.len_utf8()..];
+        } else if c == '`' {
+            if let Some(end) = rest[1..].find('`') {
+                flush(&mut job, &mut plain, current_format(strong, emphasis));
+                job.append(&rest[1..1 + end], 0.0, code_format.clone());
+                rest = &rest[end + 2..];
+            } else {
+                plain.push(c);
+                rest = &rest[1..];
+            }
+        } else if let Some(marker) = ["**", "__"].into_iter().find(|m| rest.starts_with(m)) {
+            if strong || rest[2..].contains(marker) {
+                flush(&mut job, &mut plain, current_format(strong, emphasis));
+                strong = !strong;
+            } else {
+                plain += marker;
+            }
+            rest = &rest[2..];
+        } else if c == '*' || (c == '_' && !is_inside_word(text, rest)) {
+            if emphasis || rest[1..].contains(c) {
+                flush(&mut job, &mut plain, current_format(strong, emphasis));
+                emphasis = !emphasis;
+            } else {
+                plain.push(c);
+            }
+            rest = &rest[1..];
+        } else if let Some((link_text, url, len)) = parse_link(rest) {
+            flush(&mut job, &mut plain, current_format(strong, emphasis));
+            let start = job.text.len();
+            job.append(link_text, 0.0, current_format(strong, emphasis));
+            links.push((start..job.text.len(), url.to_owned()));
+            rest = &rest[len..];
+        } else {
+            plain.push(c);
+            rest = &rest[c.len_utf8()..];
+        }
+    }
+    job.append(&plain, 0.0, current_format(strong, emphasis));
+
+    (job, links)
+}
+
+/// Is the start of `rest` in the middle of a word in `text`, like the `_` in `snake_case`?
+fn is_inside_word(text: &str, rest: &str) -> bool {
+    let index = text.len() - rest.len();
+    let before = text[..index].chars().next_back();
+    let after = rest.chars().nth(1);
+    before.map_or(false, char::is_alphanumeric) && after.map_or(false, char::is_alphanumeric)
+}
+
+/// Parses `[text](url)`, returning the text, the url and the length in bytes.
+fn parse_link(rest: &str) -> Option<(&str, &str, usize)> {
+    let inner = rest.strip_prefix('[')?;
+    let text_end = inner.find("](")?;
+    let url_start = text_end + 2;
+    let url_end = url_start + inner[url_start..].find(')')?;
+    let link_text = &inner[..text_end];
+    let url = &inner[url_start..url_end];
+    Some((link_text, url, 1 + url_end + 1))
+}
+
+#[test]
+fn test_parse_blocks() {
+    let markdown =
+        "# Title\nSome text\ncontinued.\n\n* one\n  2. two\n---\n```rs\nfn main() {}\n```";
+    assert_eq!(
+        parse_blocks(markdown),
+        vec![
+            Block::Heading {
+                level: 1,
+                text: "Title"
+            },
+            Block::Paragraph("Some text continued.".to_owned()),
+            Block::ListItem {
+                indent: 0,
+                number: None,
+                text: "one"
+            },
+            Block::ListItem {
+                indent: 2,
+                number: Some("2"),
+                text: "two"
+            },
+            Block::Separator,
+            Block::CodeBlock {
+                language: "rs",
+                code: "fn main() {}\n".to_owned()
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_layout_inline() {
+    let format = TextFormat::default();
+    let (job, links) = layout_inline(
+        "Some **strong** `code` in snake_case, see [here](https://example.com).",
+        &format,
+        &format,
+        egui::Color32::RED,
+    );
+    assert_eq!(job.text, "Some strong code in snake_case, see here.");
+    assert_eq!(links, vec![(36..40, "https://example.com".to_owned())]);
+}
@@ -0,0 +1,573 @@
+//! Export the shapes of a frame as an [SVG](https://developer.mozilla.org/en-US/docs/Web/SVG) document,
+//! e.g. for crisp documentation screenshots or for printing.
+//!
+//! Use [`egui::Context::capture_shapes`] to get the shapes of a frame:
+//!
+//! ```
+//! let ctx = egui::Context::default();
+//! let _ = ctx.run(Default::default(), |ctx| {
+//!     ctx.capture_shapes();
+//!     egui::CentralPanel::default().show(ctx, |ui| {
+//!         ui.heading("Hello SVG!");
+//!     });
+//! });
+//!
+//! let shapes: Vec<egui::epaint::ClippedShape> = ctx
+//!     .take_captured_shapes()
+//!     .unwrap_or_default()
+//!     .into_iter()
+//!     .flat_map(|layer| layer.shapes)
+//!     .collect();
+//! let svg = egui_extras::svg_export::shapes_to_svg(&shapes, ctx.screen_rect());
+//! assert!(svg.starts_with("<svg"));
+//! ```
+//!
+//! Supported are rectangles, circles, lines, paths, bézier curves, text and untextured meshes.
+//! Images and paint callbacks are not exported.
+//!
+//! Each triangle of a mesh becomes a polygon with the average color of its vertices,
+//! so color gradients within a triangle are lost.
+
+use std::fmt::Write as _;
+
+use egui::{
+    epaint::{ClippedShape, Mesh, PathShape, RectShape, StrokeKind, TextShape},
+    Color32, Pos2, Rect, Shape, Stroke, TextureId,
+};
+
+/// Convert the shapes into an SVG document covering `rect` (usually [`egui::Context::screen_rect`]).
+///
+/// The shapes are painted in order, so later shapes are on top of earlier ones.
+pub fn shapes_to_svg(shapes: &[ClippedShape], rect: Rect) -> String {
+    let mut svg = String::new();
+    writeln!(
+        svg,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{}" height="{}" viewBox="{} {} {} {}">"#,
+        num(rect.width()),
+        num(rect.height()),
+        num(rect.min.x),
+        num(rect.min.y),
+        num(rect.width()),
+        num(rect.height()),
+    )
+    .ok();
+
+    // Consecutive shapes with the same clip rectangle share a group:
+    let mut clip_rects: Vec<Rect> = vec![];
+    let mut current_clip_rect = None;
+
+    for ClippedShape { clip_rect, shape } in shapes {
+        if current_clip_rect != Some(*clip_rect) {
+            if current_clip_rect.is_some() {
+                svg += "</g>\n";
+            }
+            let clip_index = clip_rects.len();
+            clip_rects.push(*clip_rect);
+            writeln!(svg, r#"<g clip-path="url(#clip{clip_index})">"#).ok();
+            current_clip_rect = Some(*clip_rect);
+        }
+        shape_to_svg(&mut svg, shape);
+    }
+    if current_clip_rect.is_some() {
+        svg += "</g>\n";
+    }
+
+    if !clip_rects.is_empty() {
+        svg += "<defs>\n";
+        for (i, clip_rect) in clip_rects.iter().enumerate() {
+            let clip_rect = clip_rect.intersect(rect);
+            writeln!(
+                svg,
+                r#"<clipPath id="clip{i}"><rect x="{}" y="{}" width="{}" height="{}"/></clipPath>"#,
+                num(clip_rect.min.x),
+                num(clip_rect.min.y),
+                num(clip_rect.width().max(0.0)),
+                num(clip_rect.height().max(0.0)),
+            )
+            .ok();
+        }
+        svg += "</defs>\n";
+    }
+
+    svg += "</svg>\n";
+    svg
+}
+
+fn shape_to_svg(svg: &mut String, shape: &Shape) {
+    match shape {
+        Shape::Noop | Shape::Callback(_) => {}
+        Shape::Vec(shapes) => {
+            for shape in shapes {
+                shape_to_svg(svg, shape);
+            }
+        }
+        Shape::Circle(circle) => {
+            writeln!(
+                svg,
+                r#"<circle cx="{}" cy="{}" r="{}"{}{}/>"#,
+                num(circle.center.x),
+                num(circle.center.y),
+                num(circle.radius),
+                fill(circle.fill),
+                stroke(circle.stroke),
+            )
+            .ok();
+        }
+        Shape::LineSegment { points, stroke: s } => {
+            writeln!(
+                svg,
+                r#"<line x1="{}" y1="{}" x2="{}" y2="{}"{}/>"#,
+                num(points[0].x),
+                num(points[0].y),
+                num(points[1].x),
+                num(points[1].y),
+                stroke(*s),
+            )
+            .ok();
+        }
+        Shape::Path(path) => path_to_svg(svg, path),
+        Shape::Rect(rect) => rect_to_svg(svg, rect),
+        Shape::Text(text) => text_to_svg(svg, text),
+        Shape::Mesh(mesh) => mesh_to_svg(svg, mesh),
+        Shape::QuadraticBezier(bezier) => {
+            let [a, b, c] = bezier.points;
+            writeln!(
+                svg,
+                r#"<path d="M{} Q{} {}{}"{}{}/>"#,
+                pos(a),
+                pos(b),
+                pos(c),
+                if bezier.closed { " Z" } else { "" },
+                fill(bezier.fill),
+                stroke(bezier.stroke),
+            )
+            .ok();
+        }
+        Shape::CubicBezier(bezier) => {
+            let [a, b, c, d] = bezier.points;
+            writeln!(
+                svg,
+                r#"<path d="M{} C{} {} {}{}"{}{}/>"#,
+                pos(a),
+                pos(b),
+                pos(c),
+                pos(d),
+                if bezier.closed { " Z" } else { "" },
+                fill(bezier.fill),
+                stroke(bezier.stroke),
+            )
+            .ok();
+        }
+    }
+}
+
+fn path_to_svg(svg: &mut String, path: &PathShape) {
+    let Some((first, rest)) = path.points.split_first() else {
+        return;
+    };
+    let path_data = |first: Pos2, rest: &mut dyn Iterator<Item = Pos2>| {
+        let mut d = format!("M{}", pos(first));
+        for point in rest {
+            write!(d, " L{}", pos(point)).ok();
+        }
+        if path.closed {
+            d += " Z";
+        }
+        d
+    };
+    let d = path_data(*first, &mut rest.iter().copied());
+
+    if !path.closed {
+        let fill = fill(Color32::TRANSPARENT);
+        writeln!(svg, r#"<path d="{d}"{fill}{}/>"#, stroke(path.stroke)).ok();
+        return;
+    }
+
+    let offset = stroke_offset(path.stroke_kind, path.stroke.width);
+    if offset == 0.0 || path.stroke.is_empty() || path.points.len() < 3 {
+        writeln!(
+            svg,
+            r#"<path d="{d}"{}{}/>"#,
+            fill(path.fill),
+            stroke(path.stroke)
+        )
+        .ok();
+    } else {
+        // SVG strokes are centered on the outline, so stroke an offset outline instead:
+        writeln!(svg, r#"<path d="{d}"{}/>"#, fill(path.fill)).ok();
+        let offset_points = offset_closed(&path.points, offset);
+        let d = path_data(offset_points[0], &mut offset_points[1..].iter().copied());
+        writeln!(
+            svg,
+            r#"<path d="{d}"{}{}/>"#,
+            fill(Color32::TRANSPARENT),
+            stroke(path.stroke)
+        )
+        .ok();
+    }
+}
+
+/// How far to move a closed outline outwards, so that a stroke centered on it ends up as the given [`StrokeKind`].
+fn stroke_offset(stroke_kind: StrokeKind, stroke_width: f32) -> f32 {
+    stroke_kind.outer_extent(stroke_width) - stroke_width / 2.0
+}
+
+/// Move each point of a closed polygon outwards by `distance`, with mitered corners
+/// (like the tessellator does).
+fn offset_closed(points: &[Pos2], distance: f32) -> Vec<Pos2> {
+    let n = points.len();
+    let signed_area: f32 = (0..n)
+        .map(|i| {
+            let (a, b) = (points[(i + n - 1) % n], points[i]);
+            a.x * b.y - b.x * a.y
+        })
+        .sum();
+    // The normals point outwards for clockwise polygons:
+    let distance = if signed_area < 0.0 {
+        -distance
+    } else {
+        distance
+    };
+
+    let edge_normal = |a: Pos2, b: Pos2| (b - a).normalized().rot90();
+    (0..n)
+        .map(|i| {
+            let (prev, point, next) = (points[(i + n - 1) % n], points[i], points[(i + 1) % n]);
+            let normal = (edge_normal(prev, point) + edge_normal(point, next)) / 2.0;
+            let length_sq = normal.length_sq();
+            if length_sq == 0.0 {
+                point
+            } else {
+                point + distance * normal / length_sq
+            }
+        })
+        .collect()
+}
+
+fn rect_to_svg(svg: &mut String, rect_shape: &RectShape) {
+    if rect_shape.fill_texture_id != TextureId::default() {
+        return; // An image
+    }
+
+    let RectShape {
+        rect,
+        rounding,
+        fill: fill_color,
+        stroke: rect_stroke,
+        stroke_kind,
+        ..
+    } = *rect_shape;
+
+    let offset = stroke_offset(stroke_kind, rect_stroke.width);
+    if offset == 0.0 || rect_stroke.is_empty() {
+        let style = format!("{}{}", fill(fill_color), stroke(rect_stroke));
+        rounded_rect_to_svg(svg, rect, rounding, 0.0, &style);
+    } else {
+        // SVG strokes are centered on the outline, so stroke an expanded or shrunk outline instead:
+        rounded_rect_to_svg(svg, rect, rounding, 0.0, &fill(fill_color));
+        let style = format!("{}{}", fill(Color32::TRANSPARENT), stroke(rect_stroke));
+        rounded_rect_to_svg(svg, rect, rounding, offset, &style);
+    }
+}
+
+/// The rectangle is expanded by `offset`, and so are its rounded corners.
+fn rounded_rect_to_svg(
+    svg: &mut String,
+    rect: Rect,
+    rounding: egui::Rounding,
+    offset: f32,
+    style: &str,
+) {
+    let rect = rect.expand(offset);
+    let max_radius = 0.5 * rect.width().min(rect.height());
+    let radius = |r: f32| {
+        if r > 0.0 {
+            (r + offset).min(max_radius).max(0.0)
+        } else {
+            0.0 // Sharp corners stay sharp
+        }
+    };
+    let (nw, ne, sw, se) = (
+        radius(rounding.nw),
+        radius(rounding.ne),
+        radius(rounding.sw),
+        radius(rounding.se),
+    );
+
+    if nw == ne && nw == sw && nw == se {
+        writeln!(
+            svg,
+            r#"<rect x="{}" y="{}" width="{}" height="{}" rx="{}"{style}/>"#,
+            num(rect.min.x),
+            num(rect.min.y),
+            num(rect.width()),
+            num(rect.height()),
+            num(nw),
+        )
+        .ok();
+    } else {
+        // Different rounding of each corner:
+        let arc = |d: &mut String, r: f32, to: Pos2| {
+            write!(d, " A{} {} 0 0 1 {}", num(r), num(r), pos(to)).ok();
+        };
+        let mut d = format!("M{}", pos(rect.left_top() + egui::vec2(nw, 0.0)));
+        write!(d, " L{}", pos(rect.right_top() - egui::vec2(ne, 0.0))).ok();
+        arc(&mut d, ne, rect.right_top() + egui::vec2(0.0, ne));
+        write!(d, " L{}", pos(rect.right_bottom() - egui::vec2(0.0, se))).ok();
+        arc(&mut d, se, rect.right_bottom() - egui::vec2(se, 0.0));
+        write!(d, " L{}", pos(rect.left_bottom() + egui::vec2(sw, 0.0))).ok();
+        arc(&mut d, sw, rect.left_bottom() - egui::vec2(0.0, sw));
+        write!(d, " L{}", pos(rect.left_top() + egui::vec2(0.0, nw))).ok();
+        arc(&mut d, nw, rect.left_top() + egui::vec2(nw, 0.0));
+        writeln!(svg, r#"<path d="{d} Z"{style}/>"#).ok();
+    }
+}
+
+fn text_to_svg(svg: &mut String, text_shape: &TextShape) {
+    let galley = &text_shape.galley;
+    let origin = text_shape.pos;
+
+    if text_shape.angle == 0.0 {
+        svg.push_str("<g>\n");
+    } else {
+        writeln!(
+            svg,
+            r#"<g transform="rotate({} {})">"#,
+            num(text_shape.angle.to_degrees()),
+            pos(origin).replace(',', " "),
+        )
+        .ok();
+    }
+
+    for row in &galley.rows {
+        // Each run of glyphs with the same format becomes one <text> element:
+        let mut glyphs = row.glyphs.iter().peekable();
+        while let Some(first) = glyphs.next() {
+            let mut run = vec![first];
+            while let Some(next) = glyphs.next_if(|g| g.section_index == first.section_index) {
+                run.push(next);
+            }
+
+            let format = &galley.job.sections[first.section_index as usize].format;
+            let color = text_shape.override_text_color.unwrap_or(format.color);
+
+            if format.background != Color32::TRANSPARENT {
+                let left = origin.x + first.pos.x;
+                let right = origin.x + run.last().map_or(first.pos.x, |g| g.max_x());
+                writeln!(
+                    svg,
+                    r#"<rect x="{}" y="{}" width="{}" height="{}"{}/>"#,
+                    num(left),
+                    num(origin.y + row.rect.min.y),
+                    num(right - left),
+                    num(row.rect.height()),
+                    fill(format.background),
+                )
+                .ok();
+            }
+
+            let text: String = run.iter().map(|g| g.chr).collect();
+            if text.trim().is_empty() {
+                continue;
+            }
+            let xs: Vec<String> = run.iter().map(|g| num(origin.x + g.pos.x)).collect();
+            let family = match format.font_id.family {
+                egui::FontFamily::Monospace => "monospace",
+                _ => "sans-serif",
+            };
+            writeln!(
+                svg,
+                r#"<text x="{}" y="{}" font-family="{family}" font-size="{}"{}{} xml:space="preserve">{}</text>"#,
+                xs.join(" "),
+                num(origin.y + first.pos.y),
+                num(format.font_id.size),
+                if format.italics {
+                    r#" font-style="italic""#
+                } else {
+                    ""
+                },
+                fill(color),
+                escape(&text),
+            )
+            .ok();
+        }
+    }
+
+    svg.push_str("</g>\n");
+}
+
+fn mesh_to_svg(svg: &mut String, mesh: &Mesh) {
+    if mesh.texture_id != TextureId::default() {
+        return; // An image
+    }
+    for triangle in mesh.indices.chunks_exact(3) {
+        let [a, b, c] = [0, 1, 2].map(|i| &mesh.vertices[triangle[i] as usize]);
+        // SVG has no per-vertex colors, so use the average color of the triangle:
+        let color = Color32::from(
+            (egui::Rgba::from(a.color) + egui::Rgba::from(b.color) + egui::Rgba::from(c.color))
+                * (1.0 / 3.0),
+        );
+        if color == Color32::TRANSPARENT {
+            continue; // E.g. the outer edge of the feathering
+        }
+        writeln!(
+            svg,
+            r#"<polygon points="{} {} {}"{}/>"#,
+            pos(a.pos),
+            pos(b.pos),
+            pos(c.pos),
+            fill(color),
+        )
+        .ok();
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+fn num(value: f32) -> String {
+    let rounded = (value * 100.0).round() / 100.0;
+    format!("{rounded}")
+}
+
+fn pos(pos: Pos2) -> String {
+    format!("{},{}", num(pos.x), num(pos.y))
+}
+
+/// `#rrggbb` and an opacity in `0..=1`.
+fn color(color: Color32) -> (String, Option<f32>) {
+    let [r, g, b, a] = color.to_srgba_unmultiplied();
+    let hex = format!("#{r:02x}{g:02x}{b:02x}");
+    let opacity = (a < 255).then_some(a as f32 / 255.0);
+    (hex, opacity)
+}
+
+fn fill(fill: Color32) -> String {
+    if fill == Color32::TRANSPARENT {
+        return r#" fill="none""#.to_owned();
+    }
+    let (hex, opacity) = color(fill);
+    match opacity {
+        Some(opacity) => format!(r#" fill="{hex}" fill-opacity="{}""#, num(opacity)),
+        None => format!(r#" fill="{hex}""#),
+    }
+}
+
+fn stroke(stroke: Stroke) -> String {
+    if stroke.is_empty() {
+        return String::new();
+    }
+    let (hex, opacity) = color(stroke.color);
+    let mut s = format!(r#" stroke="{hex}" stroke-width="{}""#, num(stroke.width));
+    if let Some(opacity) = opacity {
+        write!(s, r#" stroke-opacity="{}""#, num(opacity)).ok();
+    }
+    s
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+#[test]
+fn test_shapes_to_svg() {
+    let rect = Rect::from_min_size(Pos2::ZERO, egui::vec2(100.0, 50.0));
+    let shapes = [
+        ClippedShape {
+            clip_rect: rect,
+            shape: Shape::rect_filled(rect, 4.0, Color32::RED),
+        },
+        ClippedShape {
+            clip_rect: rect,
+            shape: Shape::circle_stroke(rect.center(), 10.0, (2.0, Color32::WHITE)),
+        },
+    ];
+    let svg = shapes_to_svg(&shapes, rect);
+    assert!(svg.contains(r##"<rect x="0" y="0" width="100" height="50" rx="4" fill="#ff0000"/>"##));
+    assert!(svg.contains(
+        r##"<circle cx="50" cy="25" r="10" fill="none" stroke="#ffffff" stroke-width="2"/>"##
+    ));
+    assert_eq!(svg.matches("<g clip-path").count(), 1);
+}
+
+#[test]
+fn test_stroke_kind_to_svg() {
+    let rect = Rect::from_min_size(Pos2::new(10.0, 10.0), egui::vec2(20.0, 20.0));
+    let stroke = Stroke::new(2.0, Color32::WHITE);
+    let to_svg = |shape: Shape| {
+        let clip_rect = Rect::from_min_size(Pos2::ZERO, egui::vec2(100.0, 100.0));
+        shapes_to_svg(&[ClippedShape { clip_rect, shape }], clip_rect)
+    };
+
+    for (stroke_kind, expected) in [
+        (
+            StrokeKind::Inside,
+            r#"<rect x="11" y="11" width="18" height="18" rx="0" fill="none""#,
+        ),
+        (
+            StrokeKind::Middle,
+            r#"<rect x="10" y="10" width="20" height="20" rx="0" fill="none""#,
+        ),
+        (
+            StrokeKind::Outside,
+            r#"<rect x="9" y="9" width="22" height="22" rx="0" fill="none""#,
+        ),
+    ] {
+        let svg = to_svg(
+            RectShape::stroke(rect, 0.0, stroke)
+                .with_stroke_kind(stroke_kind)
+                .into(),
+        );
+        assert!(svg.contains(expected), "{stroke_kind:?}: {svg}");
+    }
+
+    let points = vec![
+        rect.left_top(),
+        rect.right_top(),
+        rect.right_bottom(),
+        rect.left_bottom(),
+    ];
+    for (stroke_kind, expected) in [
+        (
+            StrokeKind::Inside,
+            r#"<path d="M11,11 L29,11 L29,29 L11,29 Z" fill="none""#,
+        ),
+        (
+            StrokeKind::Middle,
+            r#"<path d="M10,10 L30,10 L30,30 L10,30 Z" fill="none""#,
+        ),
+        (
+            StrokeKind::Outside,
+            r#"<path d="M9,9 L31,9 L31,31 L9,31 Z" fill="none""#,
+        ),
+    ] {
+        let path = PathShape::closed_line(points.clone(), stroke).with_stroke_kind(stroke_kind);
+        let svg = to_svg(path.into());
+        assert!(svg.contains(expected), "{stroke_kind:?}: {svg}");
+    }
+}
+
+#[test]
+fn test_mesh_to_svg() {
+    let mut mesh = Mesh::default();
+    mesh.colored_vertex(Pos2::new(0.0, 0.0), Color32::RED);
+    mesh.colored_vertex(Pos2::new(10.0, 0.0), Color32::RED);
+    mesh.colored_vertex(Pos2::new(0.0, 10.0), Color32::RED);
+    mesh.colored_vertex(Pos2::new(10.0, 10.0), Color32::BLUE);
+    mesh.add_triangle(0, 1, 2);
+    mesh.add_triangle(1, 2, 3);
+
+    let rect = Rect::from_min_size(Pos2::ZERO, egui::vec2(10.0, 10.0));
+    let svg = shapes_to_svg(
+        &[ClippedShape {
+            clip_rect: rect,
+            shape: mesh.into(),
+        }],
+        rect,
+    );
+    // Each triangle gets its own color:
+    assert!(svg.contains(r##"<polygon points="0,0 10,0 0,10" fill="#ff0000"/>"##));
+    assert!(!svg.contains(r##"<polygon points="10,0 0,10 10,10" fill="#ff0000"/>"##));
+    assert_eq!(svg.matches("<polygon").count(), 2);
+}
@@ -753,30 +753,17 @@ fn set_clip_rect(
     pixels_per_point: f32,
     clip_rect: Rect,
 ) {
-    // Transform clip rect to physical pixels:
-    let clip_min_x = pixels_per_point * clip_rect.min.x;
-    let clip_min_y = pixels_per_point * clip_rect.min.y;
-    let clip_max_x = pixels_per_point * clip_rect.max.x;
-    let clip_max_y = pixels_per_point * clip_rect.max.y;
-
-    // Round to integer:
-    let clip_min_x = clip_min_x.round() as i32;
-    let clip_min_y = clip_min_y.round() as i32;
-    let clip_max_x = clip_max_x.round() as i32;
-    let clip_max_y = clip_max_y.round() as i32;
-
-    // Clamp:
-    let clip_min_x = clip_min_x.clamp(0, width_px as i32);
-    let clip_min_y = clip_min_y.clamp(0, height_px as i32);
-    let clip_max_x = clip_max_x.clamp(clip_min_x, width_px as i32);
-    let clip_max_y = clip_max_y.clamp(clip_min_y, height_px as i32);
-
+    let rect = egui::epaint::ScissorRect::from_clip_rect(
+        clip_rect,
+        pixels_per_point,
+        [width_px, height_px],
+    );
     unsafe {
         gl.scissor(
-            clip_min_x,
-            height_px as i32 - clip_max_y,
-            clip_max_x - clip_min_x,
-            clip_max_y - clip_min_y,
+            rect.x as i32,
+            (height_px - (rect.y + rect.height)) as i32,
+            rect.width as i32,
+            rect.height as i32,
         );
     }
 }
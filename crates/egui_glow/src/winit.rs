@@ -13,6 +13,7 @@ pub struct EguiGlow {
     pub painter: crate::Painter,
 
     viewport_info: egui::ViewportInfo,
+    screenshot_requested: bool,
 
     // output from the last update:
     shapes: Vec<egui::epaint::ClippedShape>,
@@ -47,6 +48,7 @@ impl EguiGlow {
             egui_winit,
             painter,
             viewport_info: Default::default(),
+            screenshot_requested: false,
             shapes: Default::default(),
             pixels_per_point,
             textures_delta: Default::default(),
@@ -65,26 +67,25 @@ impl EguiGlow {
             platform_output,
             textures_delta,
             shapes,
+            shape_layers: _,
+            blur_behind: _,
             pixels_per_point,
             viewport_output,
+            unchanged: _,
         } = self.egui_ctx.run(raw_input, run_ui);
 
         if viewport_output.len() > 1 {
             log::warn!("Multiple viewports not yet supported by EguiGlow");
         }
         for (_, ViewportOutput { commands, .. }) in viewport_output {
-            let mut screenshot_requested = false;
             egui_winit::process_viewport_commands(
                 &self.egui_ctx,
                 &mut self.viewport_info,
                 commands,
                 window,
                 true,
-                &mut screenshot_requested,
+                &mut self.screenshot_requested,
             );
-            if screenshot_requested {
-                log::warn!("Screenshot not yet supported by EguiGlow");
-            }
         }
 
         self.egui_winit
@@ -110,6 +111,17 @@ impl EguiGlow {
         self.painter
             .paint_primitives(dimensions, pixels_per_point, &clipped_primitives);
 
+        if std::mem::take(&mut self.screenshot_requested) {
+            let screenshot = self.painter.read_screen_rgba(dimensions);
+            self.egui_winit
+                .egui_input_mut()
+                .events
+                .push(egui::Event::Screenshot {
+                    viewport_id: ViewportId::ROOT,
+                    image: screenshot.into(),
+                });
+        }
+
         for id in textures_delta.free.drain(..) {
             self.painter.free_texture(id);
         }
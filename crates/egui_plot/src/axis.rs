@@ -177,6 +177,116 @@ impl AxisHints {
     }
 }
 
+// ----------------------------------------------------------------------------
+
+/// A tick formatter that shows values with an SI prefix and a unit, e.g. `1.5 kHz`.
+///
+/// All ticks on the axis use the same prefix, chosen from the largest visible value.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// egui_plot::Plot::new("spectrum")
+///     .x_axis_formatter(egui_plot::si_axis_formatter("Hz"))
+///     .show(ui, |plot_ui| {});
+/// # });
+/// ```
+pub fn si_axis_formatter(
+    unit: impl Into<String>,
+) -> impl Fn(f64, usize, &RangeInclusive<f64>) -> String {
+    const PREFIXES: [(f64, &str); 9] = [
+        (1e12, "T"),
+        (1e9, "G"),
+        (1e6, "M"),
+        (1e3, "k"),
+        (1.0, ""),
+        (1e-3, "m"),
+        (1e-6, "µ"),
+        (1e-9, "n"),
+        (1e-12, "p"),
+    ];
+
+    let unit = unit.into();
+    move |tick, max_digits, range| {
+        let magnitude = range.start().abs().max(range.end().abs());
+        let (factor, prefix) = PREFIXES
+            .iter()
+            .find(|(factor, _)| magnitude >= *factor)
+            .unwrap_or(&PREFIXES[PREFIXES.len() - 1]);
+
+        // Enough decimals to tell the ticks apart:
+        let span = (range.end() - range.start()).abs() / factor;
+        let decimals = if span > 0.0 {
+            (2.0 - span.log10().floor()).clamp(0.0, max_digits as f64) as usize
+        } else {
+            0
+        };
+
+        let value = round_to_decimals(tick / factor, decimals);
+        format!("{value} {prefix}{unit}")
+    }
+}
+
+/// A tick formatter for time, given as seconds since the Unix epoch (1970-01-01 00:00:00 UTC).
+///
+/// Depending on how much time is visible, ticks show dates, hours and minutes, or seconds.
+/// All times are in UTC.
+///
+/// Use together with [`crate::time_grid_spacer`] to get the ticks at round times.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// egui_plot::Plot::new("time_series")
+///     .x_axis_formatter(egui_plot::time_axis_formatter())
+///     .x_grid_spacer(egui_plot::time_grid_spacer())
+///     .show(ui, |plot_ui| {});
+/// # });
+/// ```
+pub fn time_axis_formatter() -> impl Fn(f64, usize, &RangeInclusive<f64>) -> String {
+    |tick, _max_digits, range| {
+        const DAY: f64 = 24.0 * 60.0 * 60.0;
+        let span = (range.end() - range.start()).abs();
+
+        let days = (tick / DAY).floor();
+        let (year, month, day) = civil_from_days(days as i64);
+        let seconds_of_day = tick - days * DAY;
+
+        if span >= 60.0 * DAY || seconds_of_day == 0.0 {
+            format!("{year}-{month:02}-{day:02}")
+        } else {
+            let hours = (seconds_of_day / 3600.0).floor();
+            let minutes = ((seconds_of_day - hours * 3600.0) / 60.0).floor();
+            let seconds = seconds_of_day - hours * 3600.0 - minutes * 60.0;
+            if span >= 2.0 * 60.0 && seconds.round() == 0.0 {
+                format!("{hours:02}:{minutes:02}")
+            } else if span >= 2.0 {
+                format!("{hours:02}:{minutes:02}:{:02}", seconds.round())
+            } else {
+                format!("{hours:02}:{minutes:02}:{seconds:06.3}")
+            }
+        }
+    }
+}
+
+/// Year, month (1-12) and day (1-31) from days since 1970-01-01,
+/// using the proleptic Gregorian calendar.
+///
+/// See <https://howardhinnant.github.io/date_algorithms.html#civil_from_days>.
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+// ----------------------------------------------------------------------------
+
 #[derive(Clone)]
 pub(super) struct AxisWidget {
     pub(super) range: RangeInclusive<f64>,
@@ -0,0 +1,429 @@
+use std::ops::RangeInclusive;
+
+use epaint::Mesh;
+
+use crate::*;
+
+use super::{
+    ClosestElem, Cursor, LabelFormatter, PlotBounds, PlotConfig, PlotGeometry, PlotItem,
+    PlotTransform,
+};
+
+/// Maps values in the range `0.0..=1.0` to colors, e.g. for a [`Heatmap`].
+///
+/// The colors are evenly spaced, and interpolated linearly in between.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ColorMap {
+    colors: Vec<Color32>,
+}
+
+impl Default for ColorMap {
+    fn default() -> Self {
+        Self::viridis()
+    }
+}
+
+impl ColorMap {
+    /// A color map going through the given colors, evenly spaced.
+    pub fn new(colors: impl Into<Vec<Color32>>) -> Self {
+        Self {
+            colors: colors.into(),
+        }
+    }
+
+    /// From black to white.
+    pub fn grayscale() -> Self {
+        Self::new([Color32::BLACK, Color32::WHITE])
+    }
+
+    /// The perceptually uniform "viridis" color map, from dark blue via green to yellow.
+    pub fn viridis() -> Self {
+        Self::from_hex(&[
+            0x440154, 0x482878, 0x3E4A89, 0x31688E, 0x26828E, 0x1F9E89, 0x35B779, 0x6DCD59,
+            0xB4DE2C, 0xFDE725,
+        ])
+    }
+
+    /// The perceptually uniform "inferno" color map, from black via red to light yellow.
+    pub fn inferno() -> Self {
+        Self::from_hex(&[
+            0x000004, 0x1B0C41, 0x4A0C6B, 0x781C6D, 0xA52C60, 0xCF4446, 0xED6925, 0xFB9B06,
+            0xF7D13D, 0xFCFFA4,
+        ])
+    }
+
+    fn from_hex(colors: &[u32]) -> Self {
+        Self::new(
+            colors
+                .iter()
+                .map(|&hex| {
+                    let [_, r, g, b] = hex.to_be_bytes();
+                    Color32::from_rgb(r, g, b)
+                })
+                .collect::<Vec<_>>(),
+        )
+    }
+
+    /// The color at `t`, which is clamped to `0.0..=1.0`.
+    pub fn color_at(&self, t: f32) -> Color32 {
+        match self.colors.as_slice() {
+            [] => Color32::TRANSPARENT,
+            [color] => *color,
+            colors => {
+                let t = t.clamp(0.0, 1.0) * (colors.len() - 1) as f32;
+                let index = (t.floor() as usize).min(colors.len() - 2);
+                let (a, b) = (colors[index], colors[index + 1]);
+                let t = t - index as f32;
+                let lerp_u8 = |a: u8, b: u8| lerp(a as f32..=b as f32, t).round() as u8;
+                Color32::from_rgba_premultiplied(
+                    lerp_u8(a.r(), b.r()),
+                    lerp_u8(a.g(), b.g()),
+                    lerp_u8(a.b(), b.b()),
+                    lerp_u8(a.a(), b.a()),
+                )
+            }
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// A grid of values shown as colored cells, e.g. a matrix or a spectrogram.
+///
+/// The values are mapped to colors with a [`ColorMap`].
+/// Non-finite values (e.g. `NaN`) are not painted.
+///
+/// To show an existing texture in plot coordinates, use [`PlotImage`] instead.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui_plot::{ColorMap, Heatmap, Plot};
+///
+/// // Three columns and two rows, starting with the top row:
+/// let values = vec![0.0, 1.0, 2.0, 3.0, 4.0, 5.0];
+/// let heatmap = Heatmap::new(values, 3)
+///     .position([0.0, 0.0], [30.0, 20.0])
+///     .color_map(ColorMap::inferno())
+///     .colorbar(true);
+///
+/// Plot::new("heatmap").show(ui, |plot_ui| plot_ui.heatmap(heatmap));
+/// # });
+/// ```
+pub struct Heatmap {
+    pub(super) values: Vec<f64>,
+    pub(super) cols: usize,
+    pub(super) rows: usize,
+    pub(super) min: PlotPoint,
+    pub(super) max: PlotPoint,
+    pub(super) value_range: Option<RangeInclusive<f64>>,
+    pub(super) color_map: ColorMap,
+    pub(super) colorbar: bool,
+    pub(super) name: String,
+    pub(super) highlight: bool,
+}
+
+impl Heatmap {
+    /// Create a heatmap from values given row by row, starting with the top row.
+    ///
+    /// The number of rows is `values.len() / cols`. Any values left over are ignored.
+    pub fn new(values: impl Into<Vec<f64>>, cols: usize) -> Self {
+        let values = values.into();
+        let rows = if cols == 0 { 0 } else { values.len() / cols };
+        Self {
+            values,
+            cols,
+            rows,
+            min: PlotPoint::new(0.0, 0.0),
+            max: PlotPoint::new(cols as f64, rows as f64),
+            value_range: None,
+            color_map: ColorMap::default(),
+            colorbar: false,
+            name: Default::default(),
+            highlight: false,
+        }
+    }
+
+    /// The left bottom and the right top corner of the heatmap, in plot coordinates.
+    ///
+    /// Default: from `[0, 0]` to `[cols, rows]`, i.e. each cell is one unit wide and tall.
+    #[inline]
+    pub fn position(mut self, min: impl Into<PlotPoint>, max: impl Into<PlotPoint>) -> Self {
+        self.min = min.into();
+        self.max = max.into();
+        self
+    }
+
+    /// The values that map to the start and the end of the color map.
+    /// Values outside this range get the color at the closest end.
+    ///
+    /// Default: the smallest and the largest finite value.
+    #[inline]
+    pub fn range(mut self, range: RangeInclusive<f64>) -> Self {
+        self.value_range = Some(range);
+        self
+    }
+
+    /// Which colors to use. Default: [`ColorMap::viridis`].
+    #[inline]
+    pub fn color_map(mut self, color_map: ColorMap) -> Self {
+        self.color_map = color_map;
+        self
+    }
+
+    /// Show a colorbar at the right edge of the plot, showing which colors the values map to.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn colorbar(mut self, colorbar: bool) -> Self {
+        self.colorbar = colorbar;
+        self
+    }
+
+    /// Highlight this heatmap in the plot.
+    #[inline]
+    pub fn highlight(mut self, highlight: bool) -> Self {
+        self.highlight = highlight;
+        self
+    }
+
+    /// Name of this heatmap.
+    ///
+    /// This name will show up in the plot legend, if legends are turned on.
+    ///
+    /// Multiple plot items may share the same name, in which case they will also share an entry in
+    /// the legend.
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    fn value_range(&self) -> RangeInclusive<f64> {
+        if let Some(range) = &self.value_range {
+            return range.clone();
+        }
+        let (min, max) = self
+            .values
+            .iter()
+            .copied()
+            .filter(|value| value.is_finite())
+            .fold((f64::INFINITY, -f64::INFINITY), |(min, max), value| {
+                (min.min(value), max.max(value))
+            });
+        if min <= max {
+            min..=max
+        } else {
+            0.0..=1.0
+        }
+    }
+
+    fn value_color(&self, value: f64, range: &RangeInclusive<f64>) -> Color32 {
+        let span = range.end() - range.start();
+        let t = if span == 0.0 {
+            0.5
+        } else {
+            (value - range.start()) / span
+        };
+        self.color_map.color_at(t as f32)
+    }
+
+    /// The corners of the cell in plot coordinates.
+    fn cell_bounds(&self, row: usize, col: usize) -> (PlotPoint, PlotPoint) {
+        let cell_width = (self.max.x - self.min.x) / self.cols as f64;
+        let cell_height = (self.max.y - self.min.y) / self.rows as f64;
+        let left = self.min.x + col as f64 * cell_width;
+        let top = self.max.y - row as f64 * cell_height;
+        (
+            PlotPoint::new(left, top - cell_height),
+            PlotPoint::new(left + cell_width, top),
+        )
+    }
+
+    fn colorbar_shapes(&self, ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        const WIDTH: f32 = 12.0;
+        const MARGIN: f32 = 8.0;
+        const SEGMENTS: usize = 32;
+
+        let frame = transform.frame();
+        let height = (frame.height() / 2.0).at_least(WIDTH);
+        let bar_rect = Rect::from_min_size(
+            pos2(
+                frame.right() - MARGIN - WIDTH,
+                frame.center().y - height / 2.0,
+            ),
+            vec2(WIDTH, height),
+        );
+
+        let mut mesh = Mesh::default();
+        let segment_height = height / SEGMENTS as f32;
+        for i in 0..SEGMENTS {
+            let t = (i as f32 + 0.5) / SEGMENTS as f32;
+            let segment = Rect::from_min_size(
+                pos2(
+                    bar_rect.left(),
+                    bar_rect.bottom() - (i + 1) as f32 * segment_height,
+                ),
+                vec2(WIDTH, segment_height),
+            );
+            mesh.add_colored_rect(segment, self.color_map.color_at(t));
+        }
+        shapes.push(Shape::mesh(mesh));
+        shapes.push(Shape::rect_stroke(
+            bar_rect,
+            0.0,
+            ui.visuals().widgets.noninteractive.bg_stroke,
+        ));
+
+        let range = self.value_range();
+        let font_id = TextStyle::Small.resolve(ui.style());
+        let text_color = ui.visuals().text_color();
+        ui.fonts(|f| {
+            for (value, y) in [
+                (*range.start(), bar_rect.bottom()),
+                (*range.end(), bar_rect.top()),
+            ] {
+                shapes.push(Shape::text(
+                    f,
+                    pos2(bar_rect.left() - 3.0, y),
+                    Align2::RIGHT_CENTER,
+                    emath::format_with_decimals_in_range(value, 0..=3),
+                    font_id.clone(),
+                    text_color,
+                ));
+            }
+        });
+    }
+}
+
+impl PlotItem for Heatmap {
+    fn shapes(&self, ui: &mut Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        if self.rows == 0 {
+            return;
+        }
+
+        let range = self.value_range();
+        let frame = *transform.frame();
+        let mut mesh = Mesh::default();
+        for row in 0..self.rows {
+            for col in 0..self.cols {
+                let value = self.values[row * self.cols + col];
+                if !value.is_finite() {
+                    continue;
+                }
+                let (min, max) = self.cell_bounds(row, col);
+                let rect = transform.rect_from_values(&min, &max);
+                if rect.intersects(frame) {
+                    mesh.add_colored_rect(rect, self.value_color(value, &range));
+                }
+            }
+        }
+        shapes.push(Shape::mesh(mesh));
+
+        if self.highlight {
+            shapes.push(Shape::rect_stroke(
+                transform.rect_from_values(&self.min, &self.max),
+                0.0,
+                Stroke::new(1.0, ui.visuals().strong_text_color()),
+            ));
+        }
+
+        if self.colorbar {
+            self.colorbar_shapes(ui, transform, shapes);
+        }
+    }
+
+    fn initialize(&mut self, _x_range: RangeInclusive<f64>) {}
+
+    fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    fn color(&self) -> Color32 {
+        Color32::TRANSPARENT
+    }
+
+    fn highlight(&mut self) {
+        self.highlight = true;
+    }
+
+    fn highlighted(&self) -> bool {
+        self.highlight
+    }
+
+    fn geometry(&self) -> PlotGeometry<'_> {
+        PlotGeometry::Rects
+    }
+
+    fn bounds(&self) -> PlotBounds {
+        let mut bounds = PlotBounds::NOTHING;
+        bounds.extend_with(&self.min);
+        bounds.extend_with(&self.max);
+        bounds
+    }
+
+    fn find_closest(&self, point: Pos2, transform: &PlotTransform) -> Option<ClosestElem> {
+        if self.rows == 0 {
+            return None;
+        }
+
+        let value = transform.value_from_position(point);
+        let col = (value.x - self.min.x) / (self.max.x - self.min.x) * self.cols as f64;
+        let row = (self.max.y - value.y) / (self.max.y - self.min.y) * self.rows as f64;
+        if !(0.0..self.cols as f64).contains(&col) || !(0.0..self.rows as f64).contains(&row) {
+            return None;
+        }
+
+        Some(ClosestElem {
+            index: row as usize * self.cols + col as usize,
+            dist_sq: 0.0,
+        })
+    }
+
+    fn on_hover(
+        &self,
+        elem: ClosestElem,
+        shapes: &mut Vec<Shape>,
+        cursors: &mut Vec<Cursor>,
+        plot: &PlotConfig<'_>,
+        _: &LabelFormatter,
+    ) {
+        let (row, col) = (elem.index / self.cols, elem.index % self.cols);
+        let (min, max) = self.cell_bounds(row, col);
+        let rect = plot.transform.rect_from_values(&min, &max);
+        shapes.push(Shape::rect_stroke(
+            rect,
+            0.0,
+            Stroke::new(1.0, plot.ui.visuals().strong_text_color()),
+        ));
+
+        let center = PlotPoint::new((min.x + max.x) / 2.0, (min.y + max.y) / 2.0);
+        if plot.show_x {
+            cursors.push(Cursor::Vertical { x: center.x });
+        }
+        if plot.show_y {
+            cursors.push(Cursor::Horizontal { y: center.y });
+        }
+
+        let mut text = self.name.clone();
+        if !text.is_empty() {
+            text.push('\n');
+        }
+        text += &format!(
+            "[{row}, {col}] = {}",
+            emath::format_with_decimals_in_range(self.values[elem.index], 0..=3)
+        );
+
+        let font_id = TextStyle::Body.resolve(plot.ui.style());
+        plot.ui.fonts(|f| {
+            shapes.push(Shape::text(
+                f,
+                rect.right_top() + vec2(3.0, -2.0),
+                Align2::LEFT_BOTTOM,
+                text,
+                font_id,
+                plot.ui.visuals().text_color(),
+            ));
+        });
+    }
+}
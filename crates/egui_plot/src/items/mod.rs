@@ -13,10 +13,12 @@ use values::{ClosestElem, PlotGeometry};
 
 pub use bar::Bar;
 pub use box_elem::{BoxElem, BoxSpread};
+pub use heatmap::{ColorMap, Heatmap};
 pub use values::{LineStyle, MarkerShape, Orientation, PlotPoint, PlotPoints};
 
 mod bar;
 mod box_elem;
+mod heatmap;
 mod rect_elem;
 mod values;
 
@@ -27,6 +27,14 @@ impl Corner {
 }
 
 /// The configuration for a plot legend.
+///
+/// The legend is interactive:
+/// * Click an entry to hide or show the items with that name.
+/// * Double-click an entry to show only the items with that name,
+///   or to show all items again if it was already the only one shown.
+/// * Hover an entry to highlight the items with that name.
+///
+/// Which items are hidden is stored in the plot memory, so it persists between frames.
 #[derive(Clone, PartialEq)]
 pub struct Legend {
     pub text_style: TextStyle,
@@ -248,14 +256,44 @@ impl Widget for &mut LegendWidget {
                 .multiply_with_opacity(config.background_alpha);
                 background_frame
                     .show(ui, |ui| {
-                        entries
+                        let mut focus_on_item = None;
+
+                        let response_union = entries
                             .iter_mut()
-                            .map(|(name, entry)| entry.ui(ui, name.clone(), &config.text_style))
+                            .map(|(name, entry)| {
+                                let response = entry.ui(ui, name.clone(), &config.text_style);
+                                if response.double_clicked_by(PointerButton::Primary) {
+                                    focus_on_item = Some(name.clone());
+                                }
+                                response
+                            })
                             .reduce(|r1, r2| r1.union(r2))
-                            .unwrap()
+                            .unwrap();
+
+                        if let Some(focus_on_item) = focus_on_item {
+                            handle_focus_on_legend_item(&focus_on_item, entries);
+                        }
+
+                        response_union
                     })
                     .inner
             })
             .inner
     }
 }
+
+/// Show only the focused item, or show all items if the focused item was already the only one shown.
+fn handle_focus_on_legend_item(
+    clicked_entry_name: &str,
+    entries: &mut BTreeMap<String, LegendEntry>,
+) {
+    // If all other items are already hidden, show everything again:
+    let only_this_one_shown = entries
+        .iter()
+        .filter(|(name, _)| name.as_str() != clicked_entry_name)
+        .all(|(_, entry)| !entry.checked);
+
+    for (name, entry) in entries.iter_mut() {
+        entry.checked = only_this_one_shown || name == clicked_entry_name;
+    }
+}
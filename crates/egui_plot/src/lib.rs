@@ -17,15 +17,17 @@ use legend::LegendWidget;
 use egui::*;
 
 pub use items::{
-    Arrows, Bar, BarChart, BoxElem, BoxPlot, BoxSpread, HLine, Line, LineStyle, MarkerShape,
-    Orientation, PlotImage, PlotPoint, PlotPoints, Points, Polygon, Text, VLine,
+    Arrows, Bar, BarChart, BoxElem, BoxPlot, BoxSpread, ColorMap, HLine, Heatmap, Line, LineStyle,
+    MarkerShape, Orientation, PlotImage, PlotPoint, PlotPoints, Points, Polygon, Text, VLine,
 };
 pub use legend::{Corner, Legend};
-pub use transform::{PlotBounds, PlotTransform};
+pub use transform::{AxisScale, PlotBounds, PlotTransform};
 
 use items::{horizontal_line, rulers_color, vertical_line};
 
-pub use axis::{Axis, AxisHints, HPlacement, Placement, VPlacement};
+pub use axis::{
+    si_axis_formatter, time_axis_formatter, Axis, AxisHints, HPlacement, Placement, VPlacement,
+};
 
 mod axis;
 mod items;
@@ -209,6 +211,8 @@ pub struct Plot {
     show_axes: Vec2b,
     show_grid: Vec2b,
     grid_spacers: [GridSpacer; 2],
+    grid_spacing: Rangef,
+    axis_scales: [AxisScale; 2],
     sharp_grid_lines: bool,
     clamp_grid: bool,
 }
@@ -251,6 +255,8 @@ impl Plot {
             show_axes: true.into(),
             show_grid: true.into(),
             grid_spacers: [log_grid_spacer(10), log_grid_spacer(10)],
+            grid_spacing: Rangef::new(MIN_LINE_SPACING_IN_POINTS as f32, 300.0),
+            axis_scales: Default::default(),
             sharp_grid_lines: true,
             clamp_grid: false,
         }
@@ -473,6 +479,50 @@ impl Plot {
         self
     }
 
+    /// Set when the grid lines start showing, in points between neighboring lines.
+    ///
+    /// Grid lines that are closer together than the minimum are hidden.
+    /// As they get further apart they fade in, until they are fully opaque at the maximum.
+    ///
+    /// Default: `6.0..=300.0`.
+    #[inline]
+    pub fn grid_spacing(mut self, grid_spacing: impl Into<Rangef>) -> Self {
+        self.grid_spacing = grid_spacing.into();
+        self
+    }
+
+    /// Set how values along the X axis are mapped to positions, e.g. [`AxisScale::Log10`].
+    ///
+    /// For a logarithmic axis this also sets a fitting grid spacer ([`log10_axis_grid_spacer`]),
+    /// so call [`Self::x_grid_spacer`] after this if you want to use your own.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// use egui_plot::{AxisScale, Line, Plot};
+    /// let line = Line::new(vec![[1.0, 10.0], [10.0, 1000.0], [100.0, 100_000.0]]);
+    /// Plot::new("log_log")
+    ///     .x_axis_scale(AxisScale::Log10)
+    ///     .y_axis_scale(AxisScale::Log10)
+    ///     .show(ui, |plot_ui| plot_ui.line(line));
+    /// # });
+    /// ```
+    #[inline]
+    pub fn x_axis_scale(mut self, scale: AxisScale) -> Self {
+        self.axis_scales[0] = scale;
+        self.grid_spacers[0] = default_grid_spacer(scale);
+        self
+    }
+
+    /// Set how values along the Y axis are mapped to positions, e.g. [`AxisScale::Log10`].
+    ///
+    /// See [`Self::x_axis_scale`] for explanation.
+    #[inline]
+    pub fn y_axis_scale(mut self, scale: AxisScale) -> Self {
+        self.axis_scales[1] = scale;
+        self.grid_spacers[1] = default_grid_spacer(scale);
+        self
+    }
+
     /// Clamp the grid to only be visible at the range of data where we have values.
     ///
     /// Default: `false`.
@@ -736,6 +786,8 @@ impl Plot {
 
             clamp_grid,
             grid_spacers,
+            grid_spacing,
+            axis_scales,
             sharp_grid_lines,
         } = self;
 
@@ -1018,15 +1070,16 @@ impl Plot {
             }
 
             if auto_x {
-                bounds.add_relative_margin_x(margin_fraction);
+                bounds.add_relative_margin_x(margin_fraction, axis_scales[0]);
             }
 
             if auto_y {
-                bounds.add_relative_margin_y(margin_fraction);
+                bounds.add_relative_margin_y(margin_fraction, axis_scales[1]);
             }
         }
 
         let mut transform = PlotTransform::new(rect, bounds, center_axis.x, center_axis.y);
+        transform.set_scales(axis_scales);
 
         // Enforce aspect ratio
         if let Some(data_aspect) = data_aspect {
@@ -1142,7 +1195,7 @@ impl Plot {
         let x_steps = Arc::new({
             let input = GridInput {
                 bounds: (bounds.min[0], bounds.max[0]),
-                base_step_size: transform.dvalue_dpos()[0] * MIN_LINE_SPACING_IN_POINTS * 2.0,
+                base_step_size: transform.dvalue_dpos()[0] * grid_spacing.min as f64 * 2.0,
             };
             (grid_spacers[0])(input)
         });
@@ -1150,7 +1203,7 @@ impl Plot {
         let y_steps = Arc::new({
             let input = GridInput {
                 bounds: (bounds.min[1], bounds.max[1]),
-                base_step_size: transform.dvalue_dpos()[1] * MIN_LINE_SPACING_IN_POINTS * 2.0,
+                base_step_size: transform.dvalue_dpos()[1] * grid_spacing.min as f64 * 2.0,
             };
             (grid_spacers[1])(input)
         });
@@ -1184,6 +1237,7 @@ impl Plot {
             draw_cursor_y: linked_cursors.as_ref().map_or(false, |group| group.1.y),
             draw_cursors,
             grid_spacers,
+            grid_spacing,
             sharp_grid_lines,
             clamp_grid,
         };
@@ -1507,6 +1561,11 @@ impl PlotUi {
         self.items.push(Box::new(image));
     }
 
+    /// Add a heatmap.
+    pub fn heatmap(&mut self, heatmap: Heatmap) {
+        self.items.push(Box::new(heatmap));
+    }
+
     /// Add a horizontal line.
     /// Can be useful e.g. to show min/max bounds or similar.
     /// Always fills the full width of the plot.
@@ -1569,6 +1628,8 @@ pub struct GridInput {
     ///
     /// Computed as the ratio between the diagram's bounds (in plot coordinates) and the viewport
     /// (in frame/window coordinates), scaled up to represent the minimal possible step.
+    ///
+    /// For an [`AxisScale::Log10`] axis, this is in decades, as is the [`GridMark::step_size`].
     pub base_step_size: f64,
 }
 
@@ -1627,6 +1688,135 @@ pub fn uniform_grid_spacer(spacer: impl Fn(GridInput) -> [f64; 3] + 'static) ->
     Box::new(get_marks)
 }
 
+/// Grid marks for an [`AxisScale::Log10`] axis.
+///
+/// There is one mark per decade (1, 10, 100, …), and fainter marks at 2, 3, …, 9 times each decade.
+/// When zoomed out over many decades, only some decades are marked.
+/// When zoomed in to less than a decade, the marks are spaced uniformly, like with [`log_grid_spacer`].
+pub fn log10_axis_grid_spacer() -> GridSpacer {
+    let get_marks = |input: GridInput| -> Vec<GridMark> {
+        let (min, max) = input.bounds;
+        if !(0.0 < min && min < max) {
+            return vec![];
+        }
+        let (min_exponent, max_exponent) = (min.log10(), max.log10());
+        let decades = max_exponent - min_exponent;
+
+        if decades < 1.0 {
+            // Uniform marks, with the step sizes converted to decades:
+            let decades_per_value = decades / (max - min);
+            let smallest_visible_unit = next_power(input.base_step_size / decades_per_value, 10.0);
+            let step_sizes = [
+                smallest_visible_unit,
+                smallest_visible_unit * 10.0,
+                smallest_visible_unit * 100.0,
+            ];
+            return generate_marks(step_sizes, input.bounds)
+                .into_iter()
+                .map(|mark| GridMark {
+                    value: mark.value,
+                    step_size: mark.step_size * decades_per_value,
+                })
+                .collect();
+        }
+
+        // One mark per decade, or per 10 decades, …
+        let smallest_visible_decade = next_power(input.base_step_size, 10.0).max(1.0);
+        let step_sizes = [
+            smallest_visible_decade,
+            smallest_visible_decade * 10.0,
+            smallest_visible_decade * 100.0,
+        ];
+        let mut marks: Vec<GridMark> = generate_marks(step_sizes, (min_exponent, max_exponent))
+            .into_iter()
+            .map(|mark| GridMark {
+                value: 10.0_f64.powf(mark.value),
+                step_size: mark.step_size,
+            })
+            .collect();
+
+        // … and the marks in between, where the `step_size` is the distance to the next mark:
+        if smallest_visible_decade <= 1.0 {
+            for exponent in (min_exponent.floor() as i32)..=(max_exponent.ceil() as i32) {
+                let decade = 10.0_f64.powi(exponent);
+                for multiple in 2..=9 {
+                    let value = multiple as f64 * decade;
+                    if min <= value && value <= max {
+                        let step_size = ((multiple + 1) as f64 / multiple as f64).log10();
+                        marks.push(GridMark { value, step_size });
+                    }
+                }
+            }
+        }
+
+        marks
+    };
+
+    Box::new(get_marks)
+}
+
+/// Grid marks for an axis showing time in seconds, e.g. with [`time_axis_formatter`].
+///
+/// The marks are at whole seconds, minutes, hours, days, weeks, (30-day) months and (365-day) years.
+pub fn time_grid_spacer() -> GridSpacer {
+    const MINUTE: f64 = 60.0;
+    const HOUR: f64 = 60.0 * MINUTE;
+    const DAY: f64 = 24.0 * HOUR;
+    const YEAR: f64 = 365.0 * DAY;
+    const STEP_SIZES: [f64; 20] = [
+        1.0,
+        5.0,
+        15.0,
+        30.0,
+        MINUTE,
+        5.0 * MINUTE,
+        15.0 * MINUTE,
+        30.0 * MINUTE,
+        HOUR,
+        3.0 * HOUR,
+        6.0 * HOUR,
+        12.0 * HOUR,
+        DAY,
+        7.0 * DAY,
+        30.0 * DAY,
+        YEAR,
+        10.0 * YEAR,
+        100.0 * YEAR,
+        1_000.0 * YEAR,
+        10_000.0 * YEAR,
+    ];
+
+    let get_marks = |input: GridInput| -> Vec<GridMark> {
+        let base_step_size = input.base_step_size.abs();
+        let step_sizes = if base_step_size < 1.0 {
+            // Fractions of a second:
+            let smallest_visible_unit = next_power(base_step_size, 10.0);
+            [
+                smallest_visible_unit,
+                smallest_visible_unit * 10.0,
+                smallest_visible_unit * 100.0,
+            ]
+        } else {
+            let i = STEP_SIZES
+                .iter()
+                .position(|&step_size| step_size >= base_step_size)
+                .unwrap_or(STEP_SIZES.len() - 1)
+                .min(STEP_SIZES.len() - 3);
+            [STEP_SIZES[i], STEP_SIZES[i + 1], STEP_SIZES[i + 2]]
+        };
+        generate_marks(step_sizes, input.bounds)
+    };
+
+    Box::new(get_marks)
+}
+
+fn default_grid_spacer(scale: AxisScale) -> GridSpacer {
+    match scale {
+        AxisScale::Linear => log_grid_spacer(10),
+        AxisScale::Log10 => log10_axis_grid_spacer(),
+    }
+}
+
 // ----------------------------------------------------------------------------
 
 struct PreparedPlot {
@@ -1639,6 +1829,7 @@ struct PreparedPlot {
     transform: PlotTransform,
     show_grid: Vec2b,
     grid_spacers: [GridSpacer; 2],
+    grid_spacing: Rangef,
     draw_cursor_x: bool,
     draw_cursor_y: bool,
     draw_cursors: Vec<Cursor>,
@@ -1738,6 +1929,7 @@ impl PreparedPlot {
             transform,
             // axis_formatters,
             grid_spacers,
+            grid_spacing,
             clamp_grid,
             ..
         } = self;
@@ -1750,7 +1942,7 @@ impl PreparedPlot {
 
         let input = GridInput {
             bounds: (bounds.min[iaxis], bounds.max[iaxis]),
-            base_step_size: transform.dvalue_dpos()[iaxis] * MIN_LINE_SPACING_IN_POINTS,
+            base_step_size: transform.dvalue_dpos()[iaxis] * grid_spacing.min as f64,
         };
         let steps = (grid_spacers[iaxis])(input);
 
@@ -1790,10 +1982,10 @@ impl PreparedPlot {
             let pos_in_gui = transform.position_from_point(&value);
             let spacing_in_points = (transform.dpos_dvalue()[iaxis] * step.step_size).abs() as f32;
 
-            if spacing_in_points > MIN_LINE_SPACING_IN_POINTS as f32 {
+            if spacing_in_points > grid_spacing.min {
                 let line_strength = remap_clamp(
                     spacing_in_points,
-                    MIN_LINE_SPACING_IN_POINTS as f32..=300.0,
+                    grid_spacing.min..=grid_spacing.max,
                     0.0..=1.0,
                 );
 
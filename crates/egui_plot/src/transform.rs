@@ -3,6 +3,42 @@ use std::ops::RangeInclusive;
 use super::PlotPoint;
 use crate::*;
 
+/// How the values along an axis are mapped to screen positions.
+///
+/// Set with [`crate::Plot::x_axis_scale`] and [`crate::Plot::y_axis_scale`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum AxisScale {
+    /// Equal distances on screen are equal differences in value.
+    #[default]
+    Linear,
+
+    /// Equal distances on screen are equal ratios of values, i.e. each decade (1, 10, 100, …) gets the same space.
+    ///
+    /// Values less than or equal to zero can't be shown on a logarithmic axis.
+    Log10,
+}
+
+impl AxisScale {
+    /// From a plot value to the linear space the axis is laid out in.
+    #[inline]
+    pub fn forward(self, value: f64) -> f64 {
+        match self {
+            Self::Linear => value,
+            Self::Log10 => value.max(f64::MIN_POSITIVE).log10(),
+        }
+    }
+
+    /// Inverse of [`Self::forward`].
+    #[inline]
+    pub fn inverse(self, value: f64) -> f64 {
+        match self {
+            Self::Linear => value,
+            Self::Log10 => 10.0_f64.powf(value),
+        }
+    }
+}
+
 /// 2D bounding box of f64 precision.
 /// The range of data values we show.
 #[derive(Clone, Copy, PartialEq, Debug)]
@@ -150,14 +186,20 @@ impl PlotBounds {
         self.translate_y(delta.y as f64);
     }
 
-    pub(crate) fn add_relative_margin_x(&mut self, margin_fraction: Vec2) {
-        let width = self.width().max(0.0);
-        self.expand_x(margin_fraction.x as f64 * width);
+    pub(crate) fn add_relative_margin_x(&mut self, margin_fraction: Vec2, scale: AxisScale) {
+        self.add_relative_margin(0, margin_fraction.x as f64, scale);
     }
 
-    pub(crate) fn add_relative_margin_y(&mut self, margin_fraction: Vec2) {
-        let height = self.height().max(0.0);
-        self.expand_y(margin_fraction.y as f64 * height);
+    pub(crate) fn add_relative_margin_y(&mut self, margin_fraction: Vec2, scale: AxisScale) {
+        self.add_relative_margin(1, margin_fraction.y as f64, scale);
+    }
+
+    fn add_relative_margin(&mut self, axis: usize, margin_fraction: f64, scale: AxisScale) {
+        let min = scale.forward(self.min[axis]);
+        let max = scale.forward(self.max[axis]);
+        let margin = margin_fraction * (max - min).max(0.0);
+        self.min[axis] = scale.inverse(min - margin);
+        self.max[axis] = scale.inverse(max + margin);
     }
 
     pub(crate) fn range_x(&self) -> RangeInclusive<f64> {
@@ -196,6 +238,10 @@ pub struct PlotTransform {
 
     /// Whether to always center the y-range of the bounds.
     y_centered: bool,
+
+    /// How values are mapped to positions along the x and y axes.
+    #[cfg_attr(feature = "serde", serde(default))]
+    scales: [AxisScale; 2],
 }
 
 impl PlotTransform {
@@ -221,9 +267,70 @@ impl PlotTransform {
             bounds,
             x_centered,
             y_centered,
+            scales: Default::default(),
         }
     }
 
+    /// Set how values are mapped to positions along the x and y axes.
+    ///
+    /// Makes sure the bounds can be shown on a logarithmic axis.
+    pub(crate) fn set_scales(&mut self, scales: [AxisScale; 2]) {
+        self.scales = scales;
+        for (axis, scale) in scales.into_iter().enumerate() {
+            if scale == AxisScale::Log10 {
+                let max = self.bounds.max[axis];
+                let max = if max > 0.0 && max.is_finite() {
+                    max
+                } else {
+                    1.0
+                };
+                let min = self.bounds.min[axis];
+                // Three decades is a reasonable default if we don't know better:
+                let min = if 0.0 < min && min < max {
+                    min
+                } else {
+                    max / 1000.0
+                };
+                self.bounds.min[axis] = min;
+                self.bounds.max[axis] = max;
+            }
+        }
+    }
+
+    /// How values are mapped to positions along the x and y axes.
+    pub fn scales(&self) -> [AxisScale; 2] {
+        self.scales
+    }
+
+    /// The bounds, in the linear space the axes are laid out in (see [`AxisScale::forward`]).
+    fn scaled_bounds(&self) -> PlotBounds {
+        let [x_scale, y_scale] = self.scales;
+        PlotBounds::from_min_max(
+            [
+                x_scale.forward(self.bounds.min[0]),
+                y_scale.forward(self.bounds.min[1]),
+            ],
+            [
+                x_scale.forward(self.bounds.max[0]),
+                y_scale.forward(self.bounds.max[1]),
+            ],
+        )
+    }
+
+    fn set_scaled_bounds(&mut self, scaled: PlotBounds) {
+        let [x_scale, y_scale] = self.scales;
+        self.bounds = PlotBounds::from_min_max(
+            [
+                x_scale.inverse(scaled.min[0]),
+                y_scale.inverse(scaled.min[1]),
+            ],
+            [
+                x_scale.inverse(scaled.max[0]),
+                y_scale.inverse(scaled.max[1]),
+            ],
+        );
+    }
+
     /// ui-space rectangle.
     pub fn frame(&self) -> &Rect {
         &self.frame
@@ -247,36 +354,44 @@ impl PlotTransform {
         }
         delta_pos.x *= self.dvalue_dpos()[0] as f32;
         delta_pos.y *= self.dvalue_dpos()[1] as f32;
-        self.bounds.translate(delta_pos);
+        let mut scaled = self.scaled_bounds();
+        scaled.translate(delta_pos);
+        self.set_scaled_bounds(scaled);
     }
 
     /// Zoom by a relative factor with the given screen position as center.
     pub(crate) fn zoom(&mut self, zoom_factor: Vec2, center: Pos2) {
         let center = self.value_from_position(center);
+        let center = [
+            self.scales[0].forward(center.x),
+            self.scales[1].forward(center.y),
+        ];
 
-        let mut new_bounds = self.bounds;
-        new_bounds.min[0] = center.x + (new_bounds.min[0] - center.x) / (zoom_factor.x as f64);
-        new_bounds.max[0] = center.x + (new_bounds.max[0] - center.x) / (zoom_factor.x as f64);
-        new_bounds.min[1] = center.y + (new_bounds.min[1] - center.y) / (zoom_factor.y as f64);
-        new_bounds.max[1] = center.y + (new_bounds.max[1] - center.y) / (zoom_factor.y as f64);
+        let mut new_bounds = self.scaled_bounds();
+        new_bounds.min[0] = center[0] + (new_bounds.min[0] - center[0]) / (zoom_factor.x as f64);
+        new_bounds.max[0] = center[0] + (new_bounds.max[0] - center[0]) / (zoom_factor.x as f64);
+        new_bounds.min[1] = center[1] + (new_bounds.min[1] - center[1]) / (zoom_factor.y as f64);
+        new_bounds.max[1] = center[1] + (new_bounds.max[1] - center[1]) / (zoom_factor.y as f64);
 
         if new_bounds.is_valid() {
-            self.bounds = new_bounds;
+            self.set_scaled_bounds(new_bounds);
         }
     }
 
     pub fn position_from_point_x(&self, value: f64) -> f32 {
+        let scale = self.scales[0];
         remap(
-            value,
-            self.bounds.min[0]..=self.bounds.max[0],
+            scale.forward(value),
+            scale.forward(self.bounds.min[0])..=scale.forward(self.bounds.max[0]),
             (self.frame.left() as f64)..=(self.frame.right() as f64),
         ) as f32
     }
 
     pub fn position_from_point_y(&self, value: f64) -> f32 {
+        let scale = self.scales[1];
         remap(
-            value,
-            self.bounds.min[1]..=self.bounds.max[1],
+            scale.forward(value),
+            scale.forward(self.bounds.min[1])..=scale.forward(self.bounds.max[1]),
             (self.frame.bottom() as f64)..=(self.frame.top() as f64), // negated y axis!
         ) as f32
     }
@@ -291,17 +406,18 @@ impl PlotTransform {
 
     /// Plot point from screen/ui position.
     pub fn value_from_position(&self, pos: Pos2) -> PlotPoint {
+        let scaled = self.scaled_bounds();
         let x = remap(
             pos.x as f64,
             (self.frame.left() as f64)..=(self.frame.right() as f64),
-            self.bounds.min[0]..=self.bounds.max[0],
+            scaled.min[0]..=scaled.max[0],
         );
         let y = remap(
             pos.y as f64,
             (self.frame.bottom() as f64)..=(self.frame.top() as f64), // negated y axis!
-            self.bounds.min[1]..=self.bounds.max[1],
+            scaled.min[1]..=scaled.max[1],
         );
-        PlotPoint::new(x, y)
+        PlotPoint::new(self.scales[0].inverse(x), self.scales[1].inverse(y))
     }
 
     /// Transform a rectangle of plot values to a screen-coordinate rectangle.
@@ -319,13 +435,17 @@ impl PlotTransform {
     }
 
     /// delta position / delta value
+    ///
+    /// For a [`AxisScale::Log10`] axis this is per decade (see [`AxisScale::forward`]).
     pub fn dpos_dvalue_x(&self) -> f64 {
-        self.frame.width() as f64 / self.bounds.width()
+        self.frame.width() as f64 / self.scaled_bounds().width()
     }
 
     /// delta position / delta value
+    ///
+    /// For a [`AxisScale::Log10`] axis this is per decade (see [`AxisScale::forward`]).
     pub fn dpos_dvalue_y(&self) -> f64 {
-        -self.frame.height() as f64 / self.bounds.height() // negated y axis!
+        -self.frame.height() as f64 / self.scaled_bounds().height() // negated y axis!
     }
 
     /// delta position / delta value
@@ -23,6 +23,24 @@ pub struct CubicBezierShape {
     pub stroke: Stroke,
 }
 
+impl std::hash::Hash for CubicBezierShape {
+    #[inline]
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        let Self {
+            points,
+            closed,
+            fill,
+            stroke,
+        } = self;
+        for point in points {
+            crate::pos2_hash(state, *point);
+        }
+        closed.hash(state);
+        fill.hash(state);
+        stroke.hash(state);
+    }
+}
+
 impl CubicBezierShape {
     /// Creates a cubic Bézier curve based on 4 points and stroke.
     ///
@@ -70,6 +88,7 @@ impl CubicBezierShape {
                 closed: self.closed,
                 fill: self.fill,
                 stroke: self.stroke,
+                stroke_kind: Default::default(),
             };
             pathshapes.push(pathshape);
         }
@@ -386,6 +405,24 @@ pub struct QuadraticBezierShape {
     pub stroke: Stroke,
 }
 
+impl std::hash::Hash for QuadraticBezierShape {
+    #[inline]
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        let Self {
+            points,
+            closed,
+            fill,
+            stroke,
+        } = self;
+        for point in points {
+            crate::pos2_hash(state, *point);
+        }
+        closed.hash(state);
+        fill.hash(state);
+        stroke.hash(state);
+    }
+}
+
 impl QuadraticBezierShape {
     /// Create a new quadratic Bézier shape based on the 3 points and stroke.
     ///
@@ -429,6 +466,7 @@ impl QuadraticBezierShape {
             closed: self.closed,
             fill: self.fill,
             stroke: self.stroke,
+            stroke_kind: Default::default(),
         }
     }
 
@@ -32,6 +32,8 @@ pub mod mutex;
 mod shadow;
 mod shape;
 pub mod shape_transform;
+#[cfg(feature = "software_rasterizer")]
+pub mod software_rasterizer;
 pub mod stats;
 mod stroke;
 pub mod tessellator;
@@ -51,7 +53,7 @@ pub use {
         TextShape,
     },
     stats::PaintStats,
-    stroke::Stroke,
+    stroke::{Stroke, StrokeKind},
     tessellator::{tessellate_shapes, TessellationOptions, Tessellator},
     text::{FontFamily, FontId, Fonts, Galley},
     texture_atlas::TextureAtlas,
@@ -111,9 +113,19 @@ pub struct ClippedShape {
     pub shape: Shape,
 }
 
+impl std::hash::Hash for ClippedShape {
+    #[inline]
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        let Self { clip_rect, shape } = self;
+        rect_hash(state, *clip_rect);
+        shape.hash(state);
+    }
+}
+
 /// A [`Mesh`] or [`PaintCallback`] within a clip rectangle.
 ///
 /// Everything is using logical points.
+/// Use [`Self::scissor_rect`] to get the clip rectangle in physical pixels.
 #[derive(Clone, Debug)]
 pub struct ClippedPrimitive {
     /// Clip / scissor rectangle.
@@ -124,6 +136,68 @@ pub struct ClippedPrimitive {
     pub primitive: Primitive,
 }
 
+impl ClippedPrimitive {
+    /// The [`Self::clip_rect`] in physical pixels, for setting the scissor rectangle of a render pass.
+    ///
+    /// `screen_size_in_pixels` is the size of the render target, which the result is clamped to.
+    #[inline]
+    pub fn scissor_rect(
+        &self,
+        pixels_per_point: f32,
+        screen_size_in_pixels: [u32; 2],
+    ) -> ScissorRect {
+        ScissorRect::from_clip_rect(self.clip_rect, pixels_per_point, screen_size_in_pixels)
+    }
+}
+
+/// A clip rectangle in physical pixels, with the origin in the top left corner.
+///
+/// See [`ClippedPrimitive::scissor_rect`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ScissorRect {
+    pub x: u32,
+    pub y: u32,
+    pub width: u32,
+    pub height: u32,
+}
+
+impl ScissorRect {
+    /// Transform a clip rectangle in logical points to physical pixels,
+    /// rounded to whole pixels and clamped to the render target.
+    pub fn from_clip_rect(
+        clip_rect: emath::Rect,
+        pixels_per_point: f32,
+        screen_size_in_pixels: [u32; 2],
+    ) -> Self {
+        let [width_px, height_px] = screen_size_in_pixels;
+
+        // Transform clip rect to physical pixels:
+        let clip_min_x = pixels_per_point * clip_rect.min.x;
+        let clip_min_y = pixels_per_point * clip_rect.min.y;
+        let clip_max_x = pixels_per_point * clip_rect.max.x;
+        let clip_max_y = pixels_per_point * clip_rect.max.y;
+
+        // Round to integer:
+        let clip_min_x = clip_min_x.round() as u32;
+        let clip_min_y = clip_min_y.round() as u32;
+        let clip_max_x = clip_max_x.round() as u32;
+        let clip_max_y = clip_max_y.round() as u32;
+
+        // Clamp:
+        let clip_min_x = clip_min_x.clamp(0, width_px);
+        let clip_min_y = clip_min_y.clamp(0, height_px);
+        let clip_max_x = clip_max_x.clamp(clip_min_x, width_px);
+        let clip_max_y = clip_max_y.clamp(clip_min_y, height_px);
+
+        Self {
+            x: clip_min_x,
+            y: clip_min_y,
+            width: clip_max_x - clip_min_x,
+            height: clip_max_y - clip_min_y,
+        }
+    }
+}
+
 /// A rendering primitive - either a [`Mesh`] or a [`PaintCallback`].
 #[derive(Clone, Debug)]
 pub enum Primitive {
@@ -161,6 +235,18 @@ pub(crate) fn f32_hash<H: std::hash::Hasher>(state: &mut H, f: f32) {
     }
 }
 
+#[inline(always)]
+pub(crate) fn pos2_hash<H: std::hash::Hasher>(state: &mut H, pos: emath::Pos2) {
+    f32_hash(state, pos.x);
+    f32_hash(state, pos.y);
+}
+
+#[inline(always)]
+pub(crate) fn rect_hash<H: std::hash::Hasher>(state: &mut H, rect: emath::Rect) {
+    pos2_hash(state, rect.min);
+    pos2_hash(state, rect.max);
+}
+
 #[inline(always)]
 pub(crate) fn f64_hash<H: std::hash::Hasher>(state: &mut H, f: f64) {
     if f == 0.0 {
@@ -42,8 +42,18 @@ pub struct Vertex {
     pub uv: Pos2, // 64 bit
 }
 
+impl std::hash::Hash for Vertex {
+    #[inline]
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        let Self { pos, uv, color } = *self;
+        crate::pos2_hash(state, pos);
+        crate::pos2_hash(state, uv);
+        color.hash(state);
+    }
+}
+
 /// Textured triangles in two dimensions.
-#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct Mesh {
     /// Draw as triangles (i.e. the length is always multiple of three).
@@ -52,6 +52,18 @@ impl Shadow {
     }
 
     pub fn tessellate(&self, rect: Rect, rounding: impl Into<Rounding>) -> Mesh {
+        self.tessellate_with_circle_detail(rect, rounding, 1.0)
+    }
+
+    /// Like [`Self::tessellate`], but with fewer segments for the rounded corners if `circle_detail < 1.0`.
+    ///
+    /// See [`crate::TessellationOptions::circle_detail`].
+    pub fn tessellate_with_circle_detail(
+        &self,
+        rect: Rect,
+        rounding: impl Into<Rounding>,
+        circle_detail: f32,
+    ) -> Mesh {
         // tessellator.clip_rect = clip_rect; // TODO(emilk): culling
 
         let Self { extrusion, color } = *self;
@@ -75,6 +87,7 @@ impl Shadow {
             TessellationOptions {
                 feathering: true,
                 feathering_size_in_pixels: extrusion * pixels_per_point,
+                circle_detail,
                 ..Default::default()
             },
             font_tex_size,
@@ -4,7 +4,7 @@ use std::{any::Any, sync::Arc};
 
 use crate::{
     text::{FontId, Fonts, Galley},
-    Color32, Mesh, Stroke, TextureId,
+    Color32, Mesh, Stroke, StrokeKind, TextureId,
 };
 use emath::*;
 
@@ -60,6 +60,45 @@ pub enum Shape {
     Callback(PaintCallback),
 }
 
+impl std::hash::Hash for Shape {
+    /// Hashes everything that affects how the shape is painted.
+    ///
+    /// [`Shape::Text`] is hashed via the row meshes of its galley,
+    /// so changes to the glyph vertices (e.g. from [`Shape::transform`] or an opacity change) are noticed.
+    /// [`Shape::Callback`] is hashed via the pointer to its callback.
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            Self::Noop => {}
+            Self::Vec(shapes) => shapes.hash(state),
+            Self::Circle(circle) => circle.hash(state),
+            Self::LineSegment { points, stroke } => {
+                crate::pos2_hash(state, points[0]);
+                crate::pos2_hash(state, points[1]);
+                stroke.hash(state);
+            }
+            Self::Path(path) => path.hash(state),
+            Self::Rect(rect) => rect.hash(state),
+            Self::Text(text) => text.hash(state),
+            Self::Mesh(mesh) => mesh.hash(state),
+            Self::QuadraticBezier(bezier) => bezier.hash(state),
+            Self::CubicBezier(bezier) => bezier.hash(state),
+            Self::Callback(callback) => callback.hash(state),
+        }
+    }
+}
+
+#[test]
+fn shape_hash_depends_on_content() {
+    let hash = |shape: &Shape| crate::util::hash(shape);
+    let a = Shape::circle_filled(pos2(1.0, 2.0), 3.0, Color32::RED);
+    let b = Shape::circle_filled(pos2(1.0, 2.0), 3.0, Color32::RED);
+    let c = Shape::circle_filled(pos2(1.0, 2.0), 3.5, Color32::RED);
+    assert_eq!(hash(&a), hash(&b));
+    assert_ne!(hash(&a), hash(&c));
+    assert_ne!(hash(&a), hash(&Shape::Vec(vec![a.clone()])));
+}
+
 #[test]
 fn shape_impl_send_sync() {
     fn assert_send_sync<T: Send + Sync>() {}
@@ -335,6 +374,84 @@ impl Shape {
             }
         }
     }
+
+    /// Scale and translate the shape, in-place.
+    ///
+    /// Stroke widths, circle radii and rounding are scaled too.
+    pub fn transform(&mut self, transform: TSTransform) {
+        match self {
+            Shape::Noop => {}
+            Shape::Vec(shapes) => {
+                for shape in shapes {
+                    shape.transform(transform);
+                }
+            }
+            Shape::Circle(circle_shape) => {
+                circle_shape.center = transform * circle_shape.center;
+                circle_shape.radius *= transform.scaling;
+                circle_shape.stroke.width *= transform.scaling;
+            }
+            Shape::LineSegment { points, stroke } => {
+                for p in points {
+                    *p = transform * *p;
+                }
+                stroke.width *= transform.scaling;
+            }
+            Shape::Path(path_shape) => {
+                for p in &mut path_shape.points {
+                    *p = transform * *p;
+                }
+                path_shape.stroke.width *= transform.scaling;
+            }
+            Shape::Rect(rect_shape) => {
+                rect_shape.rect = transform * rect_shape.rect;
+                rect_shape.stroke.width *= transform.scaling;
+                let Rounding { nw, ne, sw, se } = &mut rect_shape.rounding;
+                for r in [nw, ne, sw, se] {
+                    *r *= transform.scaling;
+                }
+            }
+            Shape::Text(text_shape) => {
+                text_shape.pos = transform * text_shape.pos;
+                text_shape.underline.width *= transform.scaling;
+
+                // Scale the glyphs, relative to `pos`:
+                if transform.scaling != 1.0 {
+                    let scale = TSTransform::from_scaling(transform.scaling);
+                    let galley = Arc::make_mut(&mut text_shape.galley);
+                    for row in &mut galley.rows {
+                        row.rect = scale * row.rect;
+                        row.visuals.mesh_bounds = scale * row.visuals.mesh_bounds;
+                        for v in &mut row.visuals.mesh.vertices {
+                            v.pos = scale * v.pos;
+                        }
+                    }
+                    galley.mesh_bounds = scale * galley.mesh_bounds;
+                    galley.rect = scale * galley.rect;
+                }
+            }
+            Shape::Mesh(mesh) => {
+                for v in &mut mesh.vertices {
+                    v.pos = transform * v.pos;
+                }
+            }
+            Shape::QuadraticBezier(bezier_shape) => {
+                for p in &mut bezier_shape.points {
+                    *p = transform * *p;
+                }
+                bezier_shape.stroke.width *= transform.scaling;
+            }
+            Shape::CubicBezier(cubic_curve) => {
+                for p in &mut cubic_curve.points {
+                    *p = transform * *p;
+                }
+                cubic_curve.stroke.width *= transform.scaling;
+            }
+            Shape::Callback(shape) => {
+                shape.rect = transform * shape.rect;
+            }
+        }
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -349,6 +466,22 @@ pub struct CircleShape {
     pub stroke: Stroke,
 }
 
+impl std::hash::Hash for CircleShape {
+    #[inline]
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        let Self {
+            center,
+            radius,
+            fill,
+            stroke,
+        } = *self;
+        crate::pos2_hash(state, center);
+        crate::f32_hash(state, radius);
+        fill.hash(state);
+        stroke.hash(state);
+    }
+}
+
 impl CircleShape {
     #[inline]
     pub fn filled(center: Pos2, radius: f32, fill_color: impl Into<Color32>) -> Self {
@@ -408,10 +541,36 @@ pub struct PathShape {
 
     /// Color and thickness of the line.
     pub stroke: Stroke,
+
+    /// Where to put the stroke of a closed path, relative to the points.
+    ///
+    /// Open paths are always stroked in the [`StrokeKind::Middle`].
+    pub stroke_kind: StrokeKind,
     // TODO(emilk): Add texture support either by supplying uv for each point,
     // or by some transform from points to uv (e.g. a callback or a linear transform matrix).
 }
 
+impl std::hash::Hash for PathShape {
+    #[inline]
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        let Self {
+            points,
+            closed,
+            fill,
+            stroke,
+            stroke_kind,
+        } = self;
+        points.len().hash(state);
+        for point in points {
+            crate::pos2_hash(state, *point);
+        }
+        closed.hash(state);
+        fill.hash(state);
+        stroke.hash(state);
+        stroke_kind.hash(state);
+    }
+}
+
 impl PathShape {
     /// A line through many points.
     ///
@@ -423,6 +582,7 @@ impl PathShape {
             closed: false,
             fill: Default::default(),
             stroke: stroke.into(),
+            stroke_kind: Default::default(),
         }
     }
 
@@ -434,6 +594,7 @@ impl PathShape {
             closed: true,
             fill: Default::default(),
             stroke: stroke.into(),
+            stroke_kind: Default::default(),
         }
     }
 
@@ -451,16 +612,29 @@ impl PathShape {
             closed: true,
             fill: fill.into(),
             stroke: stroke.into(),
+            stroke_kind: Default::default(),
         }
     }
 
+    /// Put the stroke inside, in the middle of, or outside the outline of a closed path.
+    #[inline]
+    pub fn with_stroke_kind(mut self, stroke_kind: StrokeKind) -> Self {
+        self.stroke_kind = stroke_kind;
+        self
+    }
+
     /// The visual bounding rectangle (includes stroke width)
     #[inline]
     pub fn visual_bounding_rect(&self) -> Rect {
         if self.fill == Color32::TRANSPARENT && self.stroke.is_empty() {
             Rect::NOTHING
         } else {
-            Rect::from_points(&self.points).expand(self.stroke.width / 2.0)
+            let stroke_kind = if self.closed {
+                self.stroke_kind
+            } else {
+                StrokeKind::Middle
+            };
+            Rect::from_points(&self.points).expand(stroke_kind.outer_extent(self.stroke.width))
         }
     }
 }
@@ -489,6 +663,12 @@ pub struct RectShape {
     /// The thickness and color of the outline.
     pub stroke: Stroke,
 
+    /// Where to put the stroke, relative to the edges of [`Self::rect`].
+    ///
+    /// Use [`StrokeKind::Inside`] to keep the stroke within the rectangle,
+    /// e.g. so it isn't cut off by a clip rect of the same size.
+    pub stroke_kind: StrokeKind,
+
     /// If the rect should be filled with a texture, which one?
     ///
     /// The texture is multiplied with [`Self::fill`].
@@ -503,6 +683,28 @@ pub struct RectShape {
     pub uv: Rect,
 }
 
+impl std::hash::Hash for RectShape {
+    #[inline]
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        let Self {
+            rect,
+            rounding,
+            fill,
+            stroke,
+            stroke_kind,
+            fill_texture_id,
+            uv,
+        } = *self;
+        crate::rect_hash(state, rect);
+        rounding.hash(state);
+        fill.hash(state);
+        stroke.hash(state);
+        stroke_kind.hash(state);
+        fill_texture_id.hash(state);
+        crate::rect_hash(state, uv);
+    }
+}
+
 impl RectShape {
     #[inline]
     pub fn new(
@@ -516,6 +718,7 @@ impl RectShape {
             rounding: rounding.into(),
             fill: fill_color.into(),
             stroke: stroke.into(),
+            stroke_kind: Default::default(),
             fill_texture_id: Default::default(),
             uv: Rect::ZERO,
         }
@@ -532,6 +735,7 @@ impl RectShape {
             rounding: rounding.into(),
             fill: fill_color.into(),
             stroke: Default::default(),
+            stroke_kind: Default::default(),
             fill_texture_id: Default::default(),
             uv: Rect::ZERO,
         }
@@ -544,18 +748,27 @@ impl RectShape {
             rounding: rounding.into(),
             fill: Default::default(),
             stroke: stroke.into(),
+            stroke_kind: Default::default(),
             fill_texture_id: Default::default(),
             uv: Rect::ZERO,
         }
     }
 
+    /// Put the stroke inside, in the middle of, or outside the edges of the rectangle.
+    #[inline]
+    pub fn with_stroke_kind(mut self, stroke_kind: StrokeKind) -> Self {
+        self.stroke_kind = stroke_kind;
+        self
+    }
+
     /// The visual bounding rectangle (includes stroke width)
     #[inline]
     pub fn visual_bounding_rect(&self) -> Rect {
         if self.fill == Color32::TRANSPARENT && self.stroke.is_empty() {
             Rect::NOTHING
         } else {
-            self.rect.expand(self.stroke.width / 2.0)
+            self.rect
+                .expand(self.stroke_kind.outer_extent(self.stroke.width))
         }
     }
 }
@@ -584,6 +797,17 @@ pub struct Rounding {
     pub se: f32,
 }
 
+impl std::hash::Hash for Rounding {
+    #[inline]
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        let Self { nw, ne, sw, se } = *self;
+        crate::f32_hash(state, nw);
+        crate::f32_hash(state, ne);
+        crate::f32_hash(state, sw);
+        crate::f32_hash(state, se);
+    }
+}
+
 impl Default for Rounding {
     #[inline]
     fn default() -> Self {
@@ -690,6 +914,26 @@ pub struct TextShape {
     pub angle: f32,
 }
 
+impl std::hash::Hash for TextShape {
+    #[inline]
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        let Self {
+            pos,
+            galley,
+            underline,
+            override_text_color,
+            angle,
+        } = self;
+        crate::pos2_hash(state, *pos);
+        for row in &galley.rows {
+            row.visuals.mesh.hash(state);
+        }
+        underline.hash(state);
+        override_text_color.hash(state);
+        crate::f32_hash(state, *angle);
+    }
+}
+
 impl TextShape {
     #[inline]
     pub fn new(pos: Pos2, galley: Arc<Galley>) -> Self {
@@ -931,6 +1175,14 @@ impl std::cmp::PartialEq for PaintCallback {
     }
 }
 
+impl std::hash::Hash for PaintCallback {
+    #[inline]
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        crate::rect_hash(state, self.rect);
+        Arc::as_ptr(&self.callback).cast::<()>().hash(state);
+    }
+}
+
 impl From<PaintCallback> for Shape {
     #[inline(always)]
     fn from(shape: PaintCallback) -> Self {
@@ -0,0 +1,324 @@
+//! Turn tessellated meshes into an image on the CPU, without any GPU backend.
+//!
+//! This is useful for golden-image tests that run in CI,
+//! and for rendering thumbnails of a UI offline.
+//! It is slow compared to a GPU, so don't use it for rendering an app each frame.
+
+use crate::{
+    textures::{TextureFilter, TextureOptions, TexturesDelta},
+    ClippedPrimitive, Color32, ColorImage, ImageData, ImageDelta, Mesh, Primitive, TextureId,
+    Vertex,
+};
+use emath::{Pos2, Rect};
+
+struct Texture {
+    image: ColorImage,
+    options: TextureOptions,
+}
+
+/// Paints [`ClippedPrimitive`]s into a [`ColorImage`] on the CPU.
+///
+/// Use it like you would a GPU painter: upload the textures (including the font texture),
+/// paint, and free the textures that are no longer needed.
+///
+/// ```
+/// use epaint::{software_rasterizer::SoftwareRasterizer, textures::TextureOptions, *};
+///
+/// let mut rasterizer = SoftwareRasterizer::default();
+///
+/// // Normally this comes from `egui::FullOutput::textures_delta`.
+/// // The font texture must have a white pixel at the top left:
+/// let font_image = ColorImage::new([1, 1], Color32::WHITE);
+/// rasterizer.set_texture(
+///     TextureId::default(),
+///     &ImageDelta::full(font_image, TextureOptions::LINEAR),
+/// );
+///
+/// let rect = Rect::from_min_size(pos2(2.0, 2.0), vec2(4.0, 4.0));
+/// let mut mesh = Mesh::default();
+/// mesh.add_colored_rect(rect, Color32::RED);
+/// let primitives = [ClippedPrimitive {
+///     clip_rect: Rect::EVERYTHING,
+///     primitive: Primitive::Mesh(mesh),
+/// }];
+///
+/// let image = rasterizer.paint(&primitives, [8, 8], 1.0, Color32::BLACK);
+/// assert_eq!(image[(3, 3)], Color32::RED);
+/// assert_eq!(image[(0, 0)], Color32::BLACK);
+/// ```
+#[derive(Default)]
+pub struct SoftwareRasterizer {
+    textures: ahash::HashMap<TextureId, Texture>,
+}
+
+impl SoftwareRasterizer {
+    /// Set or update a texture.
+    pub fn set_texture(&mut self, id: TextureId, delta: &ImageDelta) {
+        let image = match &delta.image {
+            ImageData::Color(image) => (**image).clone(),
+            ImageData::Font(font_image) => ColorImage {
+                size: font_image.size,
+                pixels: font_image.srgba_pixels(None).collect(),
+            },
+        };
+
+        if let Some([x, y]) = delta.pos {
+            let Some(texture) = self.textures.get_mut(&id) else {
+                crate::epaint_assert!(false, "Tried to update a texture that has not been set");
+                return;
+            };
+            let [w, h] = image.size;
+            let [texture_width, _] = texture.image.size;
+            for row in 0..h {
+                let start = (y + row) * texture_width + x;
+                texture.image.pixels[start..start + w]
+                    .copy_from_slice(&image.pixels[row * w..(row + 1) * w]);
+            }
+        } else {
+            self.textures.insert(
+                id,
+                Texture {
+                    image,
+                    options: delta.options,
+                },
+            );
+        }
+    }
+
+    /// Free a texture.
+    pub fn free_texture(&mut self, id: TextureId) {
+        self.textures.remove(&id);
+    }
+
+    /// Set all the textures in [`TexturesDelta::set`].
+    ///
+    /// Call this before [`Self::paint`], and [`Self::free_textures`] after.
+    pub fn set_textures(&mut self, textures_delta: &TexturesDelta) {
+        for (id, delta) in &textures_delta.set {
+            self.set_texture(*id, delta);
+        }
+    }
+
+    /// Free all the textures in [`TexturesDelta::free`].
+    pub fn free_textures(&mut self, textures_delta: &TexturesDelta) {
+        for id in &textures_delta.free {
+            self.free_texture(*id);
+        }
+    }
+
+    /// Paint the primitives into a new image of the given size (in pixels),
+    /// starting out filled with `clear_color`.
+    ///
+    /// Like on a GPU, colors are blended in gamma space with premultiplied alpha.
+    /// [`Primitive::Callback`] is ignored.
+    pub fn paint(
+        &self,
+        primitives: &[ClippedPrimitive],
+        size_in_pixels: [usize; 2],
+        pixels_per_point: f32,
+        clear_color: Color32,
+    ) -> ColorImage {
+        let mut image = ColorImage::new(size_in_pixels, clear_color);
+        let image_rect = Rect::from_min_size(
+            Pos2::ZERO,
+            emath::vec2(size_in_pixels[0] as f32, size_in_pixels[1] as f32),
+        );
+
+        for ClippedPrimitive {
+            clip_rect,
+            primitive,
+        } in primitives
+        {
+            let Primitive::Mesh(mesh) = primitive else {
+                continue; // Paint callbacks need a GPU
+            };
+
+            // Clip to whole pixels, like a scissor rectangle:
+            let clip_rect = Rect::from_min_max(
+                (clip_rect.min * pixels_per_point).round(),
+                (clip_rect.max * pixels_per_point).round(),
+            )
+            .intersect(image_rect);
+            if !clip_rect.is_positive() {
+                continue;
+            }
+
+            self.paint_mesh(&mut image, clip_rect, mesh, pixels_per_point);
+        }
+
+        image
+    }
+
+    fn paint_mesh(
+        &self,
+        image: &mut ColorImage,
+        clip_rect: Rect,
+        mesh: &Mesh,
+        pixels_per_point: f32,
+    ) {
+        let Some(texture) = self.textures.get(&mesh.texture_id) else {
+            crate::epaint_assert!(false, "Missing texture {:?}", mesh.texture_id);
+            return;
+        };
+
+        for indices in mesh.indices.chunks_exact(3) {
+            let triangle = [0, 1, 2].map(|i| {
+                let vertex = mesh.vertices[indices[i] as usize];
+                Vertex {
+                    pos: (vertex.pos.to_vec2() * pixels_per_point).to_pos2(),
+                    ..vertex
+                }
+            });
+            paint_triangle(image, clip_rect, &triangle, texture);
+        }
+    }
+}
+
+/// Which side of the line from `a` to `b` is `p` on, times the length of `a`-`b`.
+fn edge_function(a: Pos2, b: Pos2, p: Pos2) -> f32 {
+    (b.x - a.x) * (p.y - a.y) - (b.y - a.y) * (p.x - a.x)
+}
+
+/// Should pixels exactly on the edge `a`-`b` be painted?
+///
+/// Uses the "top-left rule", so that pixels on an edge shared by two triangles are painted exactly once.
+/// Requires the triangle to be wound so that the inside is where [`edge_function`] is positive.
+fn is_top_left(a: Pos2, b: Pos2) -> bool {
+    let inward_normal = emath::vec2(a.y - b.y, b.x - a.x);
+    inward_normal.y > 0.0 || (inward_normal.y == 0.0 && inward_normal.x > 0.0)
+}
+
+fn paint_triangle(
+    image: &mut ColorImage,
+    clip_rect: Rect,
+    triangle: &[Vertex; 3],
+    texture: &Texture,
+) {
+    let [mut a, mut b, c] = *triangle;
+    let mut area = edge_function(a.pos, b.pos, c.pos);
+    if area == 0.0 {
+        return;
+    }
+    if area < 0.0 {
+        std::mem::swap(&mut a, &mut b);
+        area = -area;
+    }
+
+    let bounding_rect = Rect::from_points(&[a.pos, b.pos, c.pos]).intersect(clip_rect);
+    if !bounding_rect.is_positive() {
+        return;
+    }
+
+    let edges = [(b.pos, c.pos), (c.pos, a.pos), (a.pos, b.pos)];
+    let top_left = edges.map(|(from, to)| is_top_left(from, to));
+
+    let [width, _] = image.size;
+    let x_range = bounding_rect.min.x.floor() as usize..bounding_rect.max.x.ceil() as usize;
+    let y_range = bounding_rect.min.y.floor() as usize..bounding_rect.max.y.ceil() as usize;
+
+    for y in y_range {
+        for x in x_range.clone() {
+            let p = Pos2::new(x as f32 + 0.5, y as f32 + 0.5);
+            if !clip_rect.contains(p) {
+                continue;
+            }
+
+            let weights = [0, 1, 2].map(|i| edge_function(edges[i].0, edges[i].1, p));
+            let inside = (0..3).all(|i| weights[i] > 0.0 || (weights[i] == 0.0 && top_left[i]));
+            if !inside {
+                continue;
+            }
+            let [wa, wb, wc] = weights.map(|w| w / area);
+
+            let uv = (wa * a.uv.to_vec2() + wb * b.uv.to_vec2() + wc * c.uv.to_vec2()).to_pos2();
+            let texel = sample(texture, uv);
+
+            let mut src = [0.0; 4];
+            for (i, value) in src.iter_mut().enumerate() {
+                let vertex_color =
+                    wa * a.color[i] as f32 + wb * b.color[i] as f32 + wc * c.color[i] as f32;
+                *value = vertex_color * texel[i] / 255.0;
+            }
+
+            let dst = &mut image.pixels[y * width + x];
+            let inv_alpha = 1.0 - src[3] / 255.0;
+            *dst = Color32::from_rgba_premultiplied(
+                blend(src[0], dst.r(), inv_alpha),
+                blend(src[1], dst.g(), inv_alpha),
+                blend(src[2], dst.b(), inv_alpha),
+                blend(src[3], dst.a(), inv_alpha),
+            );
+        }
+    }
+}
+
+fn blend(src: f32, dst: u8, inv_src_alpha: f32) -> u8 {
+    (src + dst as f32 * inv_src_alpha).round().clamp(0.0, 255.0) as u8
+}
+
+/// Sample the texture at the given normalized uv coordinates, returning premultiplied sRGBA in `0..=255`.
+fn sample(texture: &Texture, uv: Pos2) -> [f32; 4] {
+    let [w, h] = texture.image.size;
+    if w == 0 || h == 0 {
+        return [0.0; 4];
+    }
+    let texel = |x: isize, y: isize| {
+        let x = x.clamp(0, w as isize - 1) as usize;
+        let y = y.clamp(0, h as isize - 1) as usize;
+        texture.image.pixels[y * w + x].to_array().map(|c| c as f32)
+    };
+
+    let x = uv.x * w as f32 - 0.5;
+    let y = uv.y * h as f32 - 0.5;
+
+    match texture.options.magnification {
+        TextureFilter::Nearest => texel(x.round() as isize, y.round() as isize),
+        TextureFilter::Linear => {
+            let (x0, y0) = (x.floor(), y.floor());
+            let (tx, ty) = (x - x0, y - y0);
+            let (x0, y0) = (x0 as isize, y0 as isize);
+            let [top_left, top_right, bottom_left, bottom_right] = [
+                texel(x0, y0),
+                texel(x0 + 1, y0),
+                texel(x0, y0 + 1),
+                texel(x0 + 1, y0 + 1),
+            ];
+            let mut result = [0.0; 4];
+            for i in 0..4 {
+                let top = emath::lerp(top_left[i]..=top_right[i], tx);
+                let bottom = emath::lerp(bottom_left[i]..=bottom_right[i], tx);
+                result[i] = emath::lerp(top..=bottom, ty);
+            }
+            result
+        }
+    }
+}
+
+#[test]
+fn test_shared_edges_are_painted_once() {
+    let mut rasterizer = SoftwareRasterizer::default();
+    rasterizer.set_texture(
+        TextureId::default(),
+        &ImageDelta::full(
+            ColorImage::new([1, 1], Color32::WHITE),
+            TextureOptions::LINEAR,
+        ),
+    );
+
+    // Two half-transparent triangles sharing a diagonal that goes through pixel centers:
+    let mut mesh = Mesh::default();
+    let color = Color32::from_rgba_premultiplied(0, 0, 100, 100);
+    let rect = Rect::from_min_max(Pos2::new(0.5, 0.5), Pos2::new(4.5, 4.5));
+    mesh.add_colored_rect(rect, color);
+    let primitives = [ClippedPrimitive {
+        clip_rect: Rect::EVERYTHING,
+        primitive: Primitive::Mesh(mesh),
+    }];
+
+    let image = rasterizer.paint(&primitives, [5, 5], 1.0, Color32::TRANSPARENT);
+    for y in 1..4 {
+        for x in 1..4 {
+            assert_eq!(image[(x, y)], color, "pixel {x},{y}");
+        }
+    }
+}
@@ -40,6 +40,35 @@ impl Stroke {
     }
 }
 
+/// Where to put the stroke of a closed shape, e.g. a [`crate::RectShape`], relative to its outline.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum StrokeKind {
+    /// The stroke is painted just inside the outline,
+    /// so the shape covers exactly the area of the outline.
+    Inside,
+
+    /// The stroke is centered on the outline, half inside and half outside of it.
+    #[default]
+    Middle,
+
+    /// The stroke is painted just outside the outline,
+    /// so the fill is not covered by the stroke.
+    Outside,
+}
+
+impl StrokeKind {
+    /// How far outside of the outline the stroke reaches.
+    #[inline]
+    pub fn outer_extent(self, stroke_width: f32) -> f32 {
+        match self {
+            Self::Inside => 0.0,
+            Self::Middle => stroke_width / 2.0,
+            Self::Outside => stroke_width,
+        }
+    }
+}
+
 impl<Color> From<(f32, Color)> for Stroke
 where
     Color: Into<Color32>,
@@ -335,37 +335,17 @@ impl Path {
     }
 
     pub fn add_circle(&mut self, center: Pos2, radius: f32) {
-        use precomputed_vertices::*;
-
-        // These cutoffs are based on a high-dpi display. TODO(emilk): use pixels_per_point here?
-        // same cutoffs as in add_circle_quadrant
+        self.add_circle_with_detail(center, radius, 1.0);
+    }
 
-        if radius <= 2.0 {
-            self.0.extend(CIRCLE_8.iter().map(|&n| PathPoint {
-                pos: center + radius * n,
-                normal: n,
-            }));
-        } else if radius <= 5.0 {
-            self.0.extend(CIRCLE_16.iter().map(|&n| PathPoint {
-                pos: center + radius * n,
-                normal: n,
-            }));
-        } else if radius < 18.0 {
-            self.0.extend(CIRCLE_32.iter().map(|&n| PathPoint {
-                pos: center + radius * n,
-                normal: n,
-            }));
-        } else if radius < 50.0 {
-            self.0.extend(CIRCLE_64.iter().map(|&n| PathPoint {
-                pos: center + radius * n,
-                normal: n,
-            }));
-        } else {
-            self.0.extend(CIRCLE_128.iter().map(|&n| PathPoint {
-                pos: center + radius * n,
-                normal: n,
-            }));
-        }
+    /// Like [`Self::add_circle`], but `circle_detail` scales the number of segments used,
+    /// see [`TessellationOptions::circle_detail`].
+    pub fn add_circle_with_detail(&mut self, center: Pos2, radius: f32, circle_detail: f32) {
+        let vertices = path::circle_vertices(circle_detail * radius);
+        self.0.extend(vertices.iter().map(|&n| PathPoint {
+            pos: center + radius * n,
+            normal: n,
+        }));
     }
 
     pub fn add_line_segment(&mut self, points: [Pos2; 2]) {
@@ -470,6 +450,25 @@ impl Path {
         }
     }
 
+    /// Move each point of a closed path along its normal, by the given distance in points.
+    ///
+    /// A positive distance moves the points outwards, a negative one inwards.
+    /// Used to put a stroke inside or outside of the path (see [`StrokeKind`]).
+    pub fn offset_closed(&mut self, distance: f32) {
+        if distance == 0.0 {
+            return;
+        }
+        // The normals point outwards for clockwise paths:
+        let distance = if cw_signed_area(&self.0) < 0.0 {
+            -distance
+        } else {
+            distance
+        };
+        for point in &mut self.0 {
+            point.pos += distance * point.normal;
+        }
+    }
+
     /// Open-ended.
     pub fn stroke_open(&self, feathering: f32, stroke: Stroke, out: &mut Mesh) {
         stroke_path(feathering, &self.0, PathType::Open, stroke, out);
@@ -515,6 +514,19 @@ pub mod path {
 
     /// overwrites existing points
     pub fn rounded_rectangle(path: &mut Vec<Pos2>, rect: Rect, rounding: Rounding) {
+        rounded_rectangle_with_detail(path, rect, rounding, 1.0);
+    }
+
+    /// Like [`rounded_rectangle`], but `circle_detail` scales the number of segments used for the corners,
+    /// see [`super::TessellationOptions::circle_detail`].
+    ///
+    /// overwrites existing points
+    pub fn rounded_rectangle_with_detail(
+        path: &mut Vec<Pos2>,
+        rect: Rect,
+        rounding: Rounding,
+        circle_detail: f32,
+    ) {
         path.clear();
 
         let min = rect.min;
@@ -531,10 +543,11 @@ pub mod path {
             path.push(pos2(max.x, max.y)); // right bottom
             path.push(pos2(min.x, max.y)); // left bottom
         } else {
-            add_circle_quadrant(path, pos2(max.x - r.se, max.y - r.se), r.se, 0.0);
-            add_circle_quadrant(path, pos2(min.x + r.sw, max.y - r.sw), r.sw, 1.0);
-            add_circle_quadrant(path, pos2(min.x + r.nw, min.y + r.nw), r.nw, 2.0);
-            add_circle_quadrant(path, pos2(max.x - r.ne, min.y + r.ne), r.ne, 3.0);
+            let d = circle_detail;
+            add_quadrant(path, pos2(max.x - r.se, max.y - r.se), r.se, 0.0, d);
+            add_quadrant(path, pos2(min.x + r.sw, max.y - r.sw), r.sw, 1.0, d);
+            add_quadrant(path, pos2(min.x + r.nw, min.y + r.nw), r.nw, 2.0, d);
+            add_quadrant(path, pos2(max.x - r.ne, min.y + r.ne), r.ne, 3.0, d);
             path.dedup(); // We get duplicates for thin rectangles, producing visual artifats
         }
     }
@@ -558,33 +571,66 @@ pub mod path {
     //   - quadrant 3: right top
     // * angle 4 * TAU / 4 = right
     pub fn add_circle_quadrant(path: &mut Vec<Pos2>, center: Pos2, radius: f32, quadrant: f32) {
-        use super::precomputed_vertices::*;
+        add_quadrant(path, center, radius, quadrant, 1.0);
+    }
 
-        // These cutoffs are based on a high-dpi display. TODO(emilk): use pixels_per_point here?
-        // same cutoffs as in add_circle
+    /// Add the points of an arc of a circle, from the first angle to the second.
+    ///
+    /// An angle of zero is to the right of the center, and angles grow clockwise on screen
+    /// (since y points down). The arc uses as many segments per angle as [`Path::add_circle`].
+    ///
+    /// [`Path::add_circle`]: super::Path::add_circle
+    pub fn add_arc(
+        path: &mut Vec<Pos2>,
+        center: Pos2,
+        radius: f32,
+        angles: std::ops::RangeInclusive<f32>,
+    ) {
+        let (start, end) = (*angles.start(), *angles.end());
+        let circle_segments = circle_vertices(radius).len() - 1;
+        let segments = ((circle_segments as f32 * (end - start).abs() / std::f32::consts::TAU)
+            .ceil() as usize)
+            .at_least(1);
+        path.extend((0..=segments).map(|i| {
+            let angle = lerp(start..=end, i as f32 / segments as f32);
+            center + radius * Vec2::angled(angle)
+        }));
+    }
 
+    fn add_quadrant(
+        path: &mut Vec<Pos2>,
+        center: Pos2,
+        radius: f32,
+        quadrant: f32,
+        circle_detail: f32,
+    ) {
         if radius <= 0.0 {
             path.push(center);
-        } else if radius <= 2.0 {
-            let offset = quadrant as usize * 2;
-            let quadrant_vertices = &CIRCLE_8[offset..=offset + 2];
+        } else {
+            let vertices = circle_vertices(circle_detail * radius);
+            let quadrant_len = (vertices.len() - 1) / 4;
+            let offset = quadrant as usize * quadrant_len;
+            let quadrant_vertices = &vertices[offset..=offset + quadrant_len];
             path.extend(quadrant_vertices.iter().map(|&n| center + radius * n));
+        }
+    }
+
+    /// The precomputed unit circle to use for a circle of the given radius,
+    /// with the first vertex repeated at the end.
+    pub(super) fn circle_vertices(radius: f32) -> &'static [Vec2] {
+        use super::precomputed_vertices::*;
+
+        // These cutoffs are based on a high-dpi display. TODO(emilk): use pixels_per_point here?
+        if radius <= 2.0 {
+            &CIRCLE_8
         } else if radius <= 5.0 {
-            let offset = quadrant as usize * 4;
-            let quadrant_vertices = &CIRCLE_16[offset..=offset + 4];
-            path.extend(quadrant_vertices.iter().map(|&n| center + radius * n));
+            &CIRCLE_16
         } else if radius < 18.0 {
-            let offset = quadrant as usize * 8;
-            let quadrant_vertices = &CIRCLE_32[offset..=offset + 8];
-            path.extend(quadrant_vertices.iter().map(|&n| center + radius * n));
+            &CIRCLE_32
         } else if radius < 50.0 {
-            let offset = quadrant as usize * 16;
-            let quadrant_vertices = &CIRCLE_64[offset..=offset + 16];
-            path.extend(quadrant_vertices.iter().map(|&n| center + radius * n));
+            &CIRCLE_64
         } else {
-            let offset = quadrant as usize * 32;
-            let quadrant_vertices = &CIRCLE_128[offset..=offset + 32];
-            path.extend(quadrant_vertices.iter().map(|&n| center + radius * n));
+            &CIRCLE_128
         }
     }
 
@@ -640,6 +686,14 @@ pub struct TessellationOptions {
     /// This makes the text sharper on most platforms.
     pub round_text_to_pixels: bool,
 
+    /// If `true` (default), thin (at most two pixels wide) horizontal and vertical line segments
+    /// and strokes of rectangles are aligned to the pixel grid.
+    /// The fill of a rectangle is never moved.
+    ///
+    /// This makes thin lines crisp: a one pixel wide line covers exactly one row or column of pixels,
+    /// instead of being smeared out over two, which is common at fractional `pixels_per_point`.
+    pub round_line_segments_to_pixels: bool,
+
     /// Output the clip rectangles to be painted.
     pub debug_paint_clip_rects: bool,
 
@@ -649,6 +703,14 @@ pub struct TessellationOptions {
     /// If true, no clipping will be done.
     pub debug_ignore_clip_rects: bool,
 
+    /// How many segments to use for circles and rounded corners, relative to the default.
+    ///
+    /// Lower values are faster to tessellate and paint, but look more jagged.
+    /// `0.5` uses about half as many segments for a circle of the same size.
+    ///
+    /// Default: `1.0`.
+    pub circle_detail: f32,
+
     /// The maximum distance between the original curve and the flattened curve.
     pub bezier_tolerance: f32,
 
@@ -664,15 +726,22 @@ impl Default for TessellationOptions {
             coarse_tessellation_culling: true,
             prerasterized_discs: true,
             round_text_to_pixels: true,
+            round_line_segments_to_pixels: true,
             debug_paint_text_rects: false,
             debug_paint_clip_rects: false,
             debug_ignore_clip_rects: false,
+            circle_detail: 1.0,
             bezier_tolerance: 0.1,
             epsilon: 1.0e-5,
         }
     }
 }
 
+/// How far to move a closed path outwards, so that a stroke centered on it ends up as the given [`StrokeKind`].
+fn stroke_offset(stroke_kind: StrokeKind, stroke_width: f32) -> f32 {
+    stroke_kind.outer_extent(stroke_width) - stroke_width / 2.0
+}
+
 fn cw_signed_area(path: &[PathPoint]) -> f64 {
     if let Some(last) = path.last() {
         let mut previous = last.pos;
@@ -1126,6 +1195,30 @@ impl Tessellator {
         }
     }
 
+    /// Align a coordinate to the pixel grid, so that a stroke of the given width centered on it
+    /// covers whole pixels.
+    ///
+    /// Strokes an odd number of pixels wide are centered on a pixel center, the others on a pixel edge.
+    #[inline]
+    fn round_stroke_to_pixels(&self, coord: f32, stroke_width: f32) -> f32 {
+        let width_in_pixels = (stroke_width * self.pixels_per_point).round().at_least(1.0);
+        let offset = if width_in_pixels % 2.0 == 1.0 {
+            0.5
+        } else {
+            0.0
+        };
+        ((coord * self.pixels_per_point - offset).round() + offset) / self.pixels_per_point
+    }
+
+    /// Should a stroke be aligned to the pixel grid? Only thin ones are, since for those
+    /// it makes the biggest difference, and thick strokes would be moved for little gain.
+    #[inline]
+    fn should_round_stroke_to_pixels(&self, stroke: Stroke) -> bool {
+        self.options.round_line_segments_to_pixels
+            && !stroke.is_empty()
+            && stroke.width * self.pixels_per_point <= 2.0
+    }
+
     /// Tessellate a clipped shape into a list of primitives.
     pub fn tessellate_clipped_shape(
         &mut self,
@@ -1299,7 +1392,8 @@ impl Tessellator {
         }
 
         self.scratchpad_path.clear();
-        self.scratchpad_path.add_circle(center, radius);
+        self.scratchpad_path
+            .add_circle_with_detail(center, radius, self.options.circle_detail);
         self.scratchpad_path.fill(self.feathering, fill, out);
         self.scratchpad_path
             .stroke_closed(self.feathering, stroke, out);
@@ -1328,11 +1422,25 @@ impl Tessellator {
     ///
     /// * `shape`: the mesh to tessellate.
     /// * `out`: triangles are appended to this.
-    pub fn tessellate_line(&mut self, points: [Pos2; 2], stroke: Stroke, out: &mut Mesh) {
+    pub fn tessellate_line(&mut self, mut points: [Pos2; 2], stroke: Stroke, out: &mut Mesh) {
         if stroke.is_empty() {
             return;
         }
 
+        if self.should_round_stroke_to_pixels(stroke) {
+            let [a, b] = &mut points;
+            if a.x == b.x {
+                // Vertical line:
+                a.x = self.round_stroke_to_pixels(a.x, stroke.width);
+                b.x = a.x;
+            }
+            if a.y == b.y {
+                // Horizontal line:
+                a.y = self.round_stroke_to_pixels(a.y, stroke.width);
+                b.y = a.y;
+            }
+        }
+
         if self.options.coarse_tessellation_culling
             && !self
                 .clip_rect
@@ -1367,6 +1475,7 @@ impl Tessellator {
             closed,
             fill,
             stroke,
+            stroke_kind,
         } = path_shape;
 
         self.scratchpad_path.clear();
@@ -1388,6 +1497,10 @@ impl Tessellator {
         } else {
             PathType::Open
         };
+        if *closed {
+            self.scratchpad_path
+                .offset_closed(stroke_offset(*stroke_kind, stroke.width));
+        }
         self.scratchpad_path
             .stroke(self.feathering, typ, *stroke, out);
     }
@@ -1402,12 +1515,15 @@ impl Tessellator {
             rounding,
             fill,
             stroke,
+            stroke_kind,
             fill_texture_id,
             uv,
         } = *rect;
 
         if self.options.coarse_tessellation_culling
-            && !rect.expand(stroke.width).intersects(self.clip_rect)
+            && !rect
+                .expand(stroke_kind.outer_extent(stroke.width))
+                .intersects(self.clip_rect)
         {
             return;
         }
@@ -1420,6 +1536,27 @@ impl Tessellator {
         rect.min = rect.min.at_least(pos2(-1e7, -1e7));
         rect.max = rect.max.at_most(pos2(1e7, 1e7));
 
+        // The outline to stroke. The fill (and its texture coordinates) stay where they are.
+        let mut outline = rect;
+        if self.should_round_stroke_to_pixels(stroke) {
+            // Put the stroke on whole pixels:
+            let round = |coord: f32| {
+                if stroke_kind == StrokeKind::Middle {
+                    self.round_stroke_to_pixels(coord, stroke.width)
+                } else {
+                    // The stroke starts at the edge of the rectangle:
+                    (coord * self.pixels_per_point).round() / self.pixels_per_point
+                }
+            };
+            outline.min.x = round(outline.min.x);
+            outline.min.y = round(outline.min.y);
+            outline.max.x = round(outline.max.x);
+            outline.max.y = round(outline.max.y);
+        }
+
+        // What the stroke covers of a very thin rectangle:
+        let stroke_rect = outline.expand(stroke_kind.outer_extent(stroke.width));
+
         if rect.width() < self.feathering {
             // Very thin - approximate by a vertical line-segment:
             let line = [rect.center_top(), rect.center_bottom()];
@@ -1427,6 +1564,8 @@ impl Tessellator {
                 self.tessellate_line(line, Stroke::new(rect.width(), fill), out);
             }
             if !stroke.is_empty() {
+                let line = [stroke_rect.center_top(), stroke_rect.center_bottom()];
+                let stroke = Stroke::new(stroke_rect.width(), stroke.color);
                 self.tessellate_line(line, stroke, out); // back…
                 self.tessellate_line(line, stroke, out); // …and forth
             }
@@ -1437,13 +1576,20 @@ impl Tessellator {
                 self.tessellate_line(line, Stroke::new(rect.height(), fill), out);
             }
             if !stroke.is_empty() {
+                let line = [stroke_rect.left_center(), stroke_rect.right_center()];
+                let stroke = Stroke::new(stroke_rect.height(), stroke.color);
                 self.tessellate_line(line, stroke, out); // back…
                 self.tessellate_line(line, stroke, out); // …and forth
             }
         } else {
             let path = &mut self.scratchpad_path;
             path.clear();
-            path::rounded_rectangle(&mut self.scratchpad_points, rect, rounding);
+            path::rounded_rectangle_with_detail(
+                &mut self.scratchpad_points,
+                rect,
+                rounding,
+                self.options.circle_detail,
+            );
             path.add_line_loop(&self.scratchpad_points);
 
             if uv.is_positive() {
@@ -1460,6 +1606,17 @@ impl Tessellator {
                 path.fill(self.feathering, fill, out);
             }
 
+            if outline != rect {
+                path.clear();
+                path::rounded_rectangle_with_detail(
+                    &mut self.scratchpad_points,
+                    outline,
+                    rounding,
+                    self.options.circle_detail,
+                );
+                path.add_line_loop(&self.scratchpad_points);
+            }
+            path.offset_closed(stroke_offset(stroke_kind, stroke.width));
             path.stroke_closed(self.feathering, stroke, out);
         }
     }
@@ -1779,3 +1936,170 @@ fn test_tessellator() {
     );
     assert_eq!(primitives.len(), 2);
 }
+
+#[test]
+fn test_round_line_segments_to_pixels() {
+    let pixels_per_point = 1.5;
+    let mut tessellator = Tessellator::new(pixels_per_point, Default::default(), [1, 1], vec![]);
+    tessellator.set_clip_rect(Rect::EVERYTHING);
+
+    // A one pixel wide line, half way between two pixel centers:
+    let y = 10.0 / pixels_per_point;
+    let stroke = Stroke::new(1.0 / pixels_per_point, Color32::WHITE);
+    let mut mesh = Mesh::default();
+    tessellator.tessellate_line([pos2(0.0, y), pos2(10.0, y)], stroke, &mut mesh);
+
+    // The line should now be centered on a pixel center:
+    let center_y = mesh.vertices.iter().map(|v| v.pos.y).sum::<f32>() / mesh.vertices.len() as f32;
+    let center_in_pixels = center_y * pixels_per_point;
+    assert!(
+        (center_in_pixels.fract() - 0.5).abs() < 1e-3,
+        "{center_in_pixels}"
+    );
+}
+
+#[test]
+fn test_round_rect_stroke_to_pixels() {
+    let pixels_per_point = 1.5;
+    let options = TessellationOptions {
+        feathering: false,
+        ..Default::default()
+    };
+    let mut tessellator = Tessellator::new(pixels_per_point, options, [1, 1], vec![]);
+    tessellator.set_clip_rect(Rect::EVERYTHING);
+
+    let rect = Rect::from_min_max(pos2(10.1, 10.1), pos2(20.1, 20.1));
+    let bounds = |vertices: &[Vertex]| {
+        Rect::from_points(&vertices.iter().map(|v| v.pos).collect::<Vec<_>>())
+    };
+    let is_on_pixel_grid = |rect: Rect| {
+        [rect.min.x, rect.min.y, rect.max.x, rect.max.y]
+            .iter()
+            .all(|coord| {
+                let in_pixels = coord * pixels_per_point;
+                (in_pixels - in_pixels.round()).abs() < 1e-3
+            })
+    };
+
+    // A one pixel wide stroke is moved to the pixel grid, but the fill is not:
+    let hairline = Stroke::new(1.0 / pixels_per_point, Color32::WHITE);
+    let shape =
+        RectShape::new(rect, 0.0, Color32::RED, hairline).with_stroke_kind(StrokeKind::Inside);
+    let mut mesh = Mesh::default();
+    tessellator.tessellate_rect(&shape, &mut mesh);
+    assert_eq!(
+        bounds(&mesh.vertices[..4]),
+        rect,
+        "The fill should not move"
+    );
+    assert!(is_on_pixel_grid(bounds(&mesh.vertices[4..])));
+
+    // A thick stroke is left alone:
+    let thick = Stroke::new(4.0, Color32::WHITE);
+    let shape = RectShape::new(rect, 0.0, Color32::RED, thick).with_stroke_kind(StrokeKind::Inside);
+    let mut mesh = Mesh::default();
+    tessellator.tessellate_rect(&shape, &mut mesh);
+    assert_eq!(bounds(&mesh.vertices[..4]), rect);
+    assert_eq!(bounds(&mesh.vertices[4..]), rect);
+}
+
+#[test]
+fn test_add_arc() {
+    let center = pos2(10.0, 10.0);
+    let mut points = vec![];
+    path::add_arc(
+        &mut points,
+        center,
+        100.0,
+        0.0..=std::f32::consts::FRAC_PI_2,
+    );
+    // A quarter of the 128 segments of a circle this size:
+    assert_eq!(points.len(), 32 + 1);
+    assert!((points[0] - pos2(110.0, 10.0)).length() < 1e-3);
+    assert!(
+        (points[32] - pos2(10.0, 110.0)).length() < 1e-3,
+        "clockwise on screen"
+    );
+}
+
+#[test]
+fn test_rect_stroke_kind() {
+    let rect = Rect::from_min_max(pos2(10.0, 10.0), pos2(20.0, 20.0));
+    let stroke = Stroke::new(2.0, Color32::WHITE);
+
+    for (stroke_kind, expected_bounds) in [
+        (StrokeKind::Inside, rect),
+        (StrokeKind::Middle, rect.expand(1.0)),
+        (StrokeKind::Outside, rect.expand(2.0)),
+    ] {
+        let options = TessellationOptions {
+            feathering: false,
+            ..Default::default()
+        };
+        let mut tessellator = Tessellator::new(1.0, options, [1, 1], vec![]);
+        tessellator.set_clip_rect(Rect::EVERYTHING);
+
+        let shape = RectShape::stroke(rect, 0.0, stroke).with_stroke_kind(stroke_kind);
+        let mut mesh = Mesh::default();
+        tessellator.tessellate_rect(&shape, &mut mesh);
+        assert_eq!(mesh.calc_bounds(), expected_bounds, "{stroke_kind:?}");
+        assert_eq!(
+            shape.visual_bounding_rect(),
+            expected_bounds,
+            "{stroke_kind:?}"
+        );
+    }
+
+    // A very thin rectangle is approximated by a line:
+    let thin_rect = Rect::from_min_max(pos2(10.0, 10.0), pos2(10.5, 20.0));
+    for stroke_kind in [StrokeKind::Inside, StrokeKind::Middle, StrokeKind::Outside] {
+        let options = TessellationOptions {
+            round_line_segments_to_pixels: false,
+            ..Default::default()
+        };
+        let mut tessellator = Tessellator::new(1.0, options, [1, 1], vec![]);
+        tessellator.set_clip_rect(Rect::EVERYTHING);
+
+        let shape = RectShape::stroke(thin_rect, 0.0, stroke).with_stroke_kind(stroke_kind);
+        let mut mesh = Mesh::default();
+        tessellator.tessellate_rect(&shape, &mut mesh);
+        // The ends of the line are extruded by the feathering:
+        let (bounds, expected) = (mesh.calc_bounds(), shape.visual_bounding_rect());
+        assert!(
+            bounds.min.y <= expected.min.y && expected.max.y <= bounds.max.y,
+            "{stroke_kind:?}: {bounds:?}"
+        );
+        assert!(
+            expected.expand(1.0).contains_rect(bounds),
+            "{stroke_kind:?}: {bounds:?}"
+        );
+    }
+}
+
+#[test]
+fn test_circle_detail() {
+    let vertex_count = |circle_detail: f32| {
+        let options = TessellationOptions {
+            feathering: false,
+            circle_detail,
+            ..Default::default()
+        };
+        let mut tessellator = Tessellator::new(1.0, options, [1, 1], vec![]);
+        tessellator.set_clip_rect(Rect::EVERYTHING);
+        let mut mesh = Mesh::default();
+        tessellator.tessellate_circle(
+            CircleShape::filled(pos2(100.0, 100.0), 20.0, Color32::WHITE),
+            &mut mesh,
+        );
+        tessellator.tessellate_rect(
+            &RectShape::filled(
+                Rect::from_min_size(Pos2::ZERO, vec2(80.0, 40.0)),
+                20.0,
+                Color32::WHITE,
+            ),
+            &mut mesh,
+        );
+        mesh.vertices.len()
+    };
+    assert!(vertex_count(0.25) < vertex_count(1.0));
+}
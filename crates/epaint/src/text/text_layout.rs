@@ -1,11 +1,11 @@
-use std::ops::RangeInclusive;
+use std::ops::{Range, RangeInclusive};
 use std::sync::Arc;
 
 use emath::*;
 
 use crate::{text::font::Font, Color32, Mesh, Stroke, Vertex};
 
-use super::{FontsImpl, Galley, Glyph, LayoutJob, LayoutSection, Row, RowVisuals};
+use super::{ElisionPosition, FontsImpl, Galley, Glyph, LayoutJob, LayoutSection, Row, RowVisuals};
 
 // ----------------------------------------------------------------------------
 
@@ -72,6 +72,7 @@ impl Paragraph {
 pub fn layout(fonts: &mut FontsImpl, job: Arc<LayoutJob>) -> Galley {
     if job.wrap.max_rows == 0 {
         // Early-out: no text
+        let num_chars = job.text.chars().count();
         return Galley {
             job,
             rows: Default::default(),
@@ -81,6 +82,7 @@ pub fn layout(fonts: &mut FontsImpl, job: Arc<LayoutJob>) -> Galley {
             num_indices: 0,
             pixels_per_point: fonts.pixels_per_point(),
             elided: true,
+            elided_range: Some(0..num_chars),
         };
     }
 
@@ -93,6 +95,14 @@ pub fn layout(fonts: &mut FontsImpl, job: Arc<LayoutJob>) -> Galley {
 
     let point_scale = PointScale::new(fonts.pixels_per_point());
 
+    let mut middle_elided_range = None;
+    if job.wrap.elision_position == ElisionPosition::Middle
+        && job.wrap.max_rows == 1
+        && paragraphs.len() == 1
+    {
+        middle_elided_range = elide_middle(fonts, &job, &mut paragraphs[0]);
+    }
+
     let mut elided = false;
     let mut rows = rows_from_paragraphs(paragraphs, &job, &mut elided);
     if elided {
@@ -101,6 +111,15 @@ pub fn layout(fonts: &mut FontsImpl, job: Arc<LayoutJob>) -> Galley {
         }
     }
 
+    let elided_range = if let Some(range) = middle_elided_range {
+        elided = true;
+        Some(range)
+    } else if elided {
+        Some(num_shown_chars(&job, &rows)..job.text.chars().count())
+    } else {
+        None
+    };
+
     let justify = job.justify && job.wrap.max_width.is_finite();
 
     if justify || job.halign != Align::LEFT {
@@ -119,7 +138,7 @@ pub fn layout(fonts: &mut FontsImpl, job: Arc<LayoutJob>) -> Galley {
     }
 
     // Calculate the Y positions and tessellate the text:
-    galley_from_rows(point_scale, job, rows, elided)
+    galley_from_rows(point_scale, job, rows, elided, elided_range)
 }
 
 // Ignores the Y coordinate.
@@ -568,12 +587,108 @@ fn halign_and_justify_row(
     row.rect.max.x = target_max_x;
 }
 
+/// Replaces the middle of a single-row paragraph that is wider than [`super::TextWrapping::max_width`]
+/// with the overflow character, keeping as much of the start and the end of the text as fits.
+///
+/// Returns the range of characters that was hidden,
+/// or `None` if the paragraph fits, or if not even the overflow character fits.
+///
+/// Called before we have any Y coordinates.
+fn elide_middle(
+    fonts: &mut FontsImpl,
+    job: &LayoutJob,
+    paragraph: &mut Paragraph,
+) -> Option<Range<usize>> {
+    let glyphs = &paragraph.glyphs;
+    let last_glyph = glyphs.last()?;
+    if last_glyph.max_x() <= job.wrap.max_width {
+        return None;
+    }
+
+    // The overflow character gets the format of the middle of the text:
+    let section_index = glyphs[glyphs.len() / 2].section_index;
+    let overflow_glyph = job.wrap.overflow_character.map(|overflow_character| {
+        let section = &job.sections[section_index as usize];
+        let font = fonts.font(&section.format.font_id);
+        let line_height = section
+            .format
+            .line_height
+            .unwrap_or_else(|| font.row_height());
+        let (font_impl, glyph_info) = font.font_impl_and_glyph_info(overflow_character);
+        Glyph {
+            chr: overflow_character,
+            pos: pos2(0.0, f32::NAN),
+            size: vec2(glyph_info.advance_width, line_height),
+            ascent: font_impl.map_or(0.0, |font| font.ascent()), // Failure to find the font here would be weird
+            uv_rect: glyph_info.uv_rect,
+            section_index,
+        }
+    });
+    let overflow_width = overflow_glyph.map_or(0.0, |glyph| glyph.size.x);
+
+    let min_x = glyphs[0].pos.x;
+    let available_width = job.wrap.max_width - min_x - overflow_width;
+    if available_width < 0.0 {
+        return None; // Fall back to eliding the end.
+    }
+
+    // Give the start of the text half of the space, and the end whatever remains:
+    let head_len = glyphs
+        .iter()
+        .take_while(|glyph| glyph.max_x() - min_x <= available_width / 2.0)
+        .count();
+    let head_max_x = glyphs[..head_len]
+        .last()
+        .map_or(min_x, |glyph| glyph.max_x());
+    let tail_width = available_width - (head_max_x - min_x);
+    let mut tail_start = glyphs.len();
+    while head_len < tail_start && last_glyph.max_x() - glyphs[tail_start - 1].pos.x <= tail_width {
+        tail_start -= 1;
+    }
+
+    let tail_min_x = head_max_x + overflow_width;
+    let tail_offset = glyphs
+        .get(tail_start)
+        .map_or(0.0, |glyph| tail_min_x - glyph.pos.x);
+
+    let mut new_glyphs = Vec::with_capacity(head_len + 1 + glyphs.len() - tail_start);
+    new_glyphs.extend_from_slice(&glyphs[..head_len]);
+    if let Some(mut overflow_glyph) = overflow_glyph {
+        overflow_glyph.pos.x = head_max_x;
+        new_glyphs.push(overflow_glyph);
+    }
+    new_glyphs.extend(glyphs[tail_start..].iter().map(|glyph| {
+        let mut glyph = *glyph;
+        glyph.pos.x += tail_offset;
+        glyph
+    }));
+    paragraph.glyphs = new_glyphs;
+
+    Some(head_len..tail_start)
+}
+
+/// How many characters of the text are shown in rows that were elided at the end.
+fn num_shown_chars(job: &LayoutJob, rows: &[Row]) -> usize {
+    let Some((last_row, other_rows)) = rows.split_last() else {
+        return 0;
+    };
+    let overflow_character = usize::from(job.wrap.overflow_character.is_some());
+    other_rows
+        .iter()
+        .map(|row| row.char_count_including_newline())
+        .sum::<usize>()
+        + last_row
+            .char_count_excluding_newline()
+            .saturating_sub(overflow_character)
+}
+
 /// Calculate the Y positions and tessellate the text.
 fn galley_from_rows(
     point_scale: PointScale,
     job: Arc<LayoutJob>,
     mut rows: Vec<Row>,
     elided: bool,
+    elided_range: Option<Range<usize>>,
 ) -> Galley {
     let mut first_row_min_height = job.first_row_min_height;
     let mut cursor_y = 0.0;
@@ -636,6 +751,7 @@ fn galley_from_rows(
         job,
         rows,
         elided,
+        elided_range,
         rect,
         mesh_bounds,
         num_vertices,
@@ -1032,6 +1148,60 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_elided_range() {
+        let mut fonts = FontsImpl::new(1.0, 1024, FontDefinitions::default());
+        let text_format = TextFormat {
+            font_id: FontId::monospace(12.0),
+            ..Default::default()
+        };
+        let text = "verylongfilename.txt";
+        let text_width = layout(
+            &mut fonts,
+            LayoutJob::single_section(text.into(), text_format.clone()).into(),
+        )
+        .size()
+        .x;
+
+        for elision_position in [ElisionPosition::End, ElisionPosition::Middle] {
+            let mut layout_job = LayoutJob::single_section(text.into(), text_format.clone());
+            layout_job.wrap = TextWrapping {
+                elision_position,
+                ..TextWrapping::truncate_at_width(text_width)
+            };
+            let galley = layout(&mut fonts, layout_job.into());
+            assert!(!galley.elided);
+            assert_eq!(galley.elided_range, None);
+            assert_eq!(galley.rows[0].text(), text);
+        }
+
+        let max_width = text_width / 2.0;
+
+        let mut layout_job = LayoutJob::single_section(text.into(), text_format.clone());
+        layout_job.wrap = TextWrapping::truncate_at_width(max_width);
+        let galley = layout(&mut fonts, layout_job.into());
+        assert!(galley.elided);
+        assert!(galley.size().x <= max_width);
+        let row_text = galley.rows[0].text();
+        let hidden = galley.elided_range.clone().unwrap();
+        assert_eq!(hidden.end, text.len());
+        assert_eq!(row_text, format!("{}…", &text[..hidden.start]));
+
+        let mut layout_job = LayoutJob::single_section(text.into(), text_format);
+        layout_job.wrap = TextWrapping::truncate_middle_at_width(max_width);
+        let galley = layout(&mut fonts, layout_job.into());
+        assert!(galley.elided);
+        assert!(galley.size().x <= max_width);
+        let row_text = galley.rows[0].text();
+        let hidden = galley.elided_range.clone().unwrap();
+        assert!(0 < hidden.start && hidden.end < text.len());
+        assert_eq!(
+            row_text,
+            format!("{}…{}", &text[..hidden.start], &text[hidden.end..])
+        );
+        assert!(row_text.ends_with(".txt"), "{row_text:?}");
+    }
+
     #[test]
     fn test_cjk() {
         let mut fonts = FontsImpl::new(1.0, 1024, FontDefinitions::default());
@@ -364,6 +364,13 @@ pub struct TextWrapping {
     ///
     /// If not set, no character will be used (but the text will still be elided).
     pub overflow_character: Option<char>,
+
+    /// Where to elide text that doesn't fit when [`Self::max_rows`] is `1`.
+    ///
+    /// Which part of the text was hidden is returned in [`Galley::elided_range`].
+    ///
+    /// Default: [`ElisionPosition::End`].
+    pub elision_position: ElisionPosition,
 }
 
 impl std::hash::Hash for TextWrapping {
@@ -374,11 +381,13 @@ impl std::hash::Hash for TextWrapping {
             max_rows,
             break_anywhere,
             overflow_character,
+            elision_position,
         } = self;
         crate::f32_hash(state, *max_width);
         max_rows.hash(state);
         break_anywhere.hash(state);
         overflow_character.hash(state);
+        elision_position.hash(state);
     }
 }
 
@@ -389,6 +398,7 @@ impl Default for TextWrapping {
             max_rows: usize::MAX,
             break_anywhere: false,
             overflow_character: Some('…'),
+            elision_position: ElisionPosition::End,
         }
     }
 }
@@ -411,6 +421,30 @@ impl TextWrapping {
             ..Default::default()
         }
     }
+
+    /// Elide the middle of text that doesn't fit within the given width, replaced with `…`.
+    ///
+    /// Useful for file names and paths, e.g. `verylongfi…name.txt`.
+    pub fn truncate_middle_at_width(max_width: f32) -> Self {
+        Self {
+            elision_position: ElisionPosition::Middle,
+            ..Self::truncate_at_width(max_width)
+        }
+    }
+}
+
+/// Where to elide single-row text that doesn't fit, see [`TextWrapping::elision_position`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum ElisionPosition {
+    /// Keep the start of the text: `verylongfilena…`.
+    #[default]
+    End,
+
+    /// Keep the start and the end of the text: `verylongfi…name.txt`.
+    ///
+    /// This only applies to text without newlines; other text is elided at the end.
+    Middle,
 }
 
 // ----------------------------------------------------------------------------
@@ -445,6 +479,11 @@ pub struct Galley {
     /// Set to true the text was truncated due to [`TextWrapping::max_rows`].
     pub elided: bool,
 
+    /// If [`Self::elided`], this is the range of characters of `job.text` that was hidden.
+    ///
+    /// With [`ElisionPosition::End`] this runs to the end of the text.
+    pub elided_range: Option<Range<usize>>,
+
     /// Bounding rect.
     ///
     /// `rect.top()` is always 0.0.
@@ -1043,4 +1082,44 @@ impl Galley {
             column: self.rows[cursor.rcursor.row].char_count_excluding_newline(),
         })
     }
+
+    /// Move to the beginning of the paragraph (the text after the previous `\n`).
+    ///
+    /// If already there, move to the beginning of the previous paragraph.
+    pub fn cursor_begin_of_paragraph(&self, cursor: &Cursor) -> Cursor {
+        let paragraph = if cursor.pcursor.offset == 0 {
+            cursor.pcursor.paragraph.saturating_sub(1)
+        } else {
+            cursor.pcursor.paragraph
+        };
+        self.from_pcursor(PCursor {
+            paragraph,
+            offset: 0,
+            prefer_next_row: true,
+        })
+    }
+
+    /// Move to the end of the paragraph (just before the next `\n`).
+    ///
+    /// If already there, move to the end of the next paragraph.
+    pub fn cursor_end_of_paragraph(&self, cursor: &Cursor) -> Cursor {
+        let end_of_paragraph = |paragraph: usize| {
+            let cursor = self.from_pcursor(PCursor {
+                paragraph,
+                offset: usize::MAX,
+                prefer_next_row: false,
+            });
+            // Normalize the `usize::MAX` offset:
+            self.from_ccursor(cursor.ccursor)
+        };
+
+        let end = end_of_paragraph(cursor.pcursor.paragraph);
+        if end.ccursor.index == cursor.ccursor.index
+            && cursor.pcursor.paragraph < self.end().pcursor.paragraph
+        {
+            end_of_paragraph(cursor.pcursor.paragraph + 1)
+        } else {
+            end
+        }
+    }
 }